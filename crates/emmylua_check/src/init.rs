@@ -41,8 +41,13 @@ pub fn load_workspace(
     }
 
     analysis.update_config(Arc::new(emmyrc));
+    analysis.init_framework_libs(false);
 
-    let file_infos = collect_files(&workspace_folders, &analysis.emmyrc, ignore);
+    let mut file_infos = collect_files(&workspace_folders, &analysis.emmyrc, ignore.clone());
+    let addon_files = analysis.load_luals_addons(&file_infos, |libraries, emmyrc| {
+        collect_files(libraries, emmyrc, ignore)
+    });
+    file_infos.extend(addon_files);
     let files = file_infos
         .into_iter()
         .filter_map(|file| {
@@ -0,0 +1,201 @@
+---@meta
+
+-- A subset of busted 2.x and luassert: the test blocks, the common assertions,
+-- spies, stubs and mocks.
+-- Every class is `(partial)`, declare `---@class (partial) <name>` in your own
+-- definition files to add what is missing.
+
+--- Defines a test suite, suites can be nested.
+---@param name string
+---@param block fun()
+function describe(name, block) end
+
+--- Alias of `describe`.
+---@param name string
+---@param block fun()
+function context(name, block) end
+
+--- Alias of `describe`.
+---@param name string
+---@param block fun()
+function insulate(name, block) end
+
+--- Defines a suite whose environment changes leak into the enclosing suite.
+---@param name string
+---@param block fun()
+function expose(name, block) end
+
+--- Defines a test case.
+---@param name string
+---@param block? fun()
+function it(name, block) end
+
+--- Alias of `it`.
+---@param name string
+---@param block? fun()
+function test(name, block) end
+
+--- Alias of `it`.
+---@param name string
+---@param block? fun()
+function spec(name, block) end
+
+--- Defines a test case that is reported as pending.
+---@param name string
+---@param block? fun()
+function pending(name, block) end
+
+--- Runs before each test in the enclosing suite.
+---@param block fun()
+function before_each(block) end
+
+--- Runs after each test in the enclosing suite.
+---@param block fun()
+function after_each(block) end
+
+--- Runs once before the first test in the enclosing suite.
+---@param block fun()
+function setup(block) end
+
+--- Runs once after the last test in the enclosing suite.
+---@param block fun()
+function teardown(block) end
+
+--- Alias of `setup`.
+---@param block fun()
+function lazy_setup(block) end
+
+--- Alias of `teardown`.
+---@param block fun()
+function lazy_teardown(block) end
+
+--- Alias of `setup` that runs even when the suite has no tests.
+---@param block fun()
+function strict_setup(block) end
+
+--- Alias of `teardown` that runs even when the suite has no tests.
+---@param block fun()
+function strict_teardown(block) end
+
+--- Finishes an async test started with `async()`.
+function done() end
+
+--- Marks the running test as async, it finishes when `done()` is called.
+function async() end
+
+---@class (partial) luassert.chain
+---@field is luassert.chain
+---@field is_not luassert.chain
+---@field are luassert.chain
+---@field are_not luassert.chain
+---@field has luassert.chain
+---@field has_no luassert.chain
+---@field no luassert.chain
+---@field was luassert.chain
+---@field was_not luassert.chain
+---@field truthy fun(value: any, message?: string)
+---@field falsy fun(value: any, message?: string)
+---@field is_true fun(value: any, message?: string)
+---@field is_false fun(value: any, message?: string)
+---@field is_nil fun(value: any, message?: string)
+---@field equal fun(expected: any, actual: any, message?: string)
+---@field equals fun(expected: any, actual: any, message?: string)
+---@field same fun(expected: any, actual: any, message?: string)
+---@field unique fun(list: table, deep?: boolean, message?: string)
+---@field near fun(expected: number, actual: number, tolerance: number, message?: string)
+---@field matches fun(pattern: string, actual: string, init?: integer, plain?: boolean, message?: string)
+---@field error fun(fn: function, err?: any, message?: string)
+---@field has_error fun(fn: function, err?: any, message?: string)
+---@field error_matches fun(fn: function, pattern: string, init?: integer, plain?: boolean, message?: string)
+---@field called fun(times?: integer)
+---@field called_with fun(...)
+---@field returned_with fun(...)
+
+---@class (partial) luassert: luassert.chain
+---@overload fun(value: any, message?: string, ...): any
+assert = {}
+
+--- Adds a custom assertion.
+---@param namespace "assertion"|"modifier"
+---@param name string
+---@param callback fun(state: table, arguments: table, level?: integer): boolean
+---@param positive_message string
+---@param negative_message string
+function assert:register(namespace, name, callback, positive_message, negative_message) end
+
+---@class (partial) luassert.spy
+---@field calls table[]
+---@overload fun(fn?: function): luassert.spy
+local spyObject = {}
+
+--- Removes the spy and restores the original function.
+function spyObject:revert() end
+
+--- Clears the recorded calls.
+function spyObject:clear() end
+
+---@class (partial) luassert.spyModule
+---@overload fun(fn?: function): luassert.spy
+spy = {}
+
+--- Creates a new spy for `fn`.
+---@param fn? function
+---@return luassert.spy
+function spy.new(fn) end
+
+--- Replaces `object[method]` with a spy that still calls the original.
+---@param object table
+---@param method string
+---@return luassert.spy
+function spy.on(object, method) end
+
+---@class (partial) luassert.stubModule
+---@overload fun(object: table, method: string, ...): luassert.spy
+stub = {}
+
+--- Creates a new stub, optionally returning the given values.
+---@param object? table
+---@param method? string
+---@param ... any
+---@return luassert.spy
+function stub.new(object, method, ...) end
+
+---@class (partial) luassert.mockModule
+---@overload fun(object: table, dostub?: boolean): table
+mock = {}
+
+--- Creates a mock, wrapping every function of `object` in a spy or stub.
+---@param object table
+---@param dostub? boolean
+---@return table
+function mock.new(object, dostub) end
+
+--- Reverts all spies and stubs of the mock.
+---@param object table
+function mock.revert(object) end
+
+---@class (partial) luassert.match
+match = {}
+
+--- Matches any value.
+---@return any
+function match._() end
+
+--- Matches a value of the given type.
+---@param typ string
+---@return any
+function match.is_type(typ) end
+
+--- Matches a value equal to `expected`.
+---@param expected any
+---@return any
+function match.is_equal(expected) end
+
+--- Matches a value deeply equal to `expected`.
+---@param expected any
+---@return any
+function match.is_same(expected) end
+
+--- Registers a function that runs after the current test, even when it fails.
+---@param block fun()
+function finally(block) end
@@ -0,0 +1,277 @@
+---@meta
+
+-- A subset of the Defold 1.x API: the vector types and the most used functions of
+-- vmath, msg, go, factory, gui, timer and sys.
+-- Every class is `(partial)`, declare `---@class (partial) <name>` in your own
+-- definition files to add what is missing.
+
+---@class (partial) vector3
+---@field x number
+---@field y number
+---@field z number
+---@operator add(vector3): vector3
+---@operator sub(vector3): vector3
+---@operator mul(number): vector3
+---@operator unm: vector3
+
+---@class (partial) vector4
+---@field x number
+---@field y number
+---@field z number
+---@field w number
+
+---@class (partial) quaternion
+---@field x number
+---@field y number
+---@field z number
+---@field w number
+
+---@class (partial) hash: userdata
+
+---@class (partial) url
+---@field socket hash
+---@field path hash
+---@field fragment hash
+
+---@class (partial) node: userdata
+
+---@alias message_id hash|string
+---@alias address hash|string|url
+
+---@class (partial) vmath
+vmath = {}
+
+--- Creates a new vector with all components set to zero or to the given values.
+---@param x? number
+---@param y? number
+---@param z? number
+---@return vector3
+function vmath.vector3(x, y, z) end
+
+--- Creates a new four component vector.
+---@param x? number
+---@param y? number
+---@param z? number
+---@param w? number
+---@return vector4
+function vmath.vector4(x, y, z, w) end
+
+--- Creates a new identity quaternion or one from the given components.
+---@param x? number
+---@param y? number
+---@param z? number
+---@param w? number
+---@return quaternion
+function vmath.quat(x, y, z, w) end
+
+--- Creates a quaternion rotating around the z-axis.
+---@param angle number radians
+---@return quaternion
+function vmath.quat_rotation_z(angle) end
+
+--- Calculates the length of a vector.
+---@param v vector3|vector4
+---@return number
+function vmath.length(v) end
+
+--- Normalizes a vector.
+---@generic V: vector3|vector4
+---@param v V
+---@return V
+function vmath.normalize(v) end
+
+--- Linearly interpolates between two values.
+---@generic T: number|vector3|vector4
+---@param t number
+---@param v1 T
+---@param v2 T
+---@return T
+function vmath.lerp(t, v1, v2) end
+
+--- Calculates the dot product of two vectors.
+---@param v1 vector3|vector4
+---@param v2 vector3|vector4
+---@return number
+function vmath.dot(v1, v2) end
+
+--- Hashes a string.
+---@param s string
+---@return hash
+function hash(s) end
+
+--- Gets the hex representation of a hash value as a string.
+---@param h hash
+---@return string
+function hash_to_hex(h) end
+
+---@class (partial) msg
+msg = {}
+
+--- Posts a message to a receiving URL.
+---@param receiver address
+---@param message_id message_id
+---@param message? table
+function msg.post(receiver, message_id, message) end
+
+--- Creates a new URL from a string or components.
+---@param urlstring? string
+---@return url
+---@overload fun(socket: string|hash|nil, path: string|hash|nil, fragment: string|hash|nil): url
+function msg.url(urlstring) end
+
+---@class (partial) go
+go = {}
+
+--- Gets the position of a game object instance.
+---@param id? address
+---@return vector3
+function go.get_position(id) end
+
+--- Sets the position of a game object instance.
+---@param position vector3
+---@param id? address
+function go.set_position(position, id) end
+
+--- Gets the rotation of a game object instance.
+---@param id? address
+---@return quaternion
+function go.get_rotation(id) end
+
+--- Sets the rotation of a game object instance.
+---@param rotation quaternion
+---@param id? address
+function go.set_rotation(rotation, id) end
+
+--- Gets a named property of the specified game object or component.
+---@param url address
+---@param property string|hash
+---@param options? table
+---@return any
+function go.get(url, property, options) end
+
+--- Sets a named property of the specified game object or component.
+---@param url address
+---@param property string|hash
+---@param value any
+---@param options? table
+function go.set(url, property, value, options) end
+
+--- Returns the id of the instance, or of the given relative path.
+---@param path? string
+---@return hash
+function go.get_id(path) end
+
+--- Deletes one or more game object instances.
+---@param id? address|address[]
+---@param recursive? boolean
+function go.delete(id, recursive) end
+
+--- Animates a named property of the specified game object or component.
+---@param url address
+---@param property string|hash
+---@param playback integer
+---@param to number|vector3|vector4|quaternion
+---@param easing integer|vector3
+---@param duration number
+---@param delay? number
+---@param complete_function? fun(self, url: url, property: hash)
+function go.animate(url, property, playback, to, easing, duration, delay, complete_function) end
+
+--- Defines a script property, only valid at the top level of a script.
+---@param name string
+---@param value number|hash|url|vector3|vector4|quaternion|boolean
+function go.property(name, value) end
+
+---@class (partial) factory
+factory = {}
+
+--- Creates a new game object from a factory component.
+---@param url address
+---@param position? vector3
+---@param rotation? quaternion
+---@param properties? table
+---@param scale? number|vector3
+---@return hash id
+function factory.create(url, position, rotation, properties, scale) end
+
+---@class (partial) gui
+gui = {}
+
+--- Retrieves the node with the specified id.
+---@param id string|hash
+---@return node
+function gui.get_node(id) end
+
+--- Sets the text of a text node.
+---@param node node
+---@param text string|number
+function gui.set_text(node, text) end
+
+--- Gets the text of a text node.
+---@param node node
+---@return string
+function gui.get_text(node) end
+
+--- Sets the position of the supplied node.
+---@param node node
+---@param position vector3|vector4
+function gui.set_position(node, position) end
+
+--- Returns whether a point is inside the node's area.
+---@param node node
+---@param x number
+---@param y number
+---@return boolean
+function gui.pick_node(node, x, y) end
+
+--- Sets whether a node is enabled.
+---@param node node
+---@param enabled boolean
+function gui.set_enabled(node, enabled) end
+
+---@class (partial) timer
+timer = {}
+
+--- Adds a timer and returns a unique handle.
+---@param delay number
+---@param repeating boolean
+---@param callback fun(self, handle: integer, time_elapsed: number)
+---@return integer handle
+function timer.delay(delay, repeating, callback) end
+
+--- Cancels a timer.
+---@param handle integer
+---@return boolean cancelled
+function timer.cancel(handle) end
+
+---@class (partial) sys
+sys = {}
+
+--- Loads a custom resource.
+---@param filename string
+---@return string? data
+---@return string? error
+function sys.load_resource(filename) end
+
+--- Gets the config value from the game.project configuration file.
+---@param key string
+---@param default_value? string
+---@return string
+function sys.get_config_string(key, default_value) end
+
+--- Gets the save-file path.
+---@param application_id string
+---@param file_name string
+---@return string path
+function sys.get_save_file(application_id, file_name) end
+
+--- Saves a lua table to a file stored on disk.
+---@param filename string
+---@param table table
+---@return boolean success
+function sys.save(filename, table) end
+
+--- Loads a lua table from a file on disk.
+---@param filename string
+---@return table
+function sys.load(filename) end
@@ -0,0 +1,428 @@
+---@meta
+
+-- A subset of the LÖVE 11.x API: the callbacks and the most used functions of
+-- graphics, keyboard, mouse, audio, timer, filesystem, window, math and event.
+-- Every class is `(partial)`, declare `---@class (partial) <name>` in your own
+-- definition files to add what is missing.
+
+---@class (partial) love
+love = {}
+
+---@class (partial) love.Object
+local Object = {}
+
+--- Destroys the object's Lua reference.
+---@return boolean success
+function Object:release() end
+
+--- Gets the type of the object as a string.
+---@return string type
+function Object:type() end
+
+--- Checks whether an object is of a certain type.
+---@param name string
+---@return boolean
+function Object:typeOf(name) end
+
+--- Gets the current running version of LÖVE.
+---@return integer major
+---@return integer minor
+---@return integer revision
+---@return string codename
+function love.getVersion() end
+
+--- Callback function used to load the game, called exactly once at the beginning.
+---@param arg string[] command-line arguments given to the game
+---@param unfilteredArg string[] unfiltered command-line arguments
+function love.load(arg, unfilteredArg) end
+
+--- Callback function used to update the state of the game every frame.
+---@param dt number time since the last update in seconds
+function love.update(dt) end
+
+--- Callback function used to draw on the screen every frame.
+function love.draw() end
+
+--- Callback function triggered when the game is closed, return `true` to abort.
+---@return boolean? abort
+function love.quit() end
+
+--- Callback function triggered when a key is pressed.
+---@param key love.KeyConstant
+---@param scancode love.Scancode
+---@param isrepeat boolean
+function love.keypressed(key, scancode, isrepeat) end
+
+--- Callback function triggered when a keyboard key is released.
+---@param key love.KeyConstant
+---@param scancode love.Scancode
+function love.keyreleased(key, scancode) end
+
+--- Callback function triggered when a mouse button is pressed.
+---@param x number
+---@param y number
+---@param button integer 1 is the primary button, 2 the secondary, 3 the middle
+---@param istouch boolean
+---@param presses integer
+function love.mousepressed(x, y, button, istouch, presses) end
+
+--- Callback function triggered when a mouse button is released.
+---@param x number
+---@param y number
+---@param button integer
+---@param istouch boolean
+---@param presses integer
+function love.mousereleased(x, y, button, istouch, presses) end
+
+--- Callback function triggered when the mouse is moved.
+---@param x number
+---@param y number
+---@param dx number
+---@param dy number
+---@param istouch boolean
+function love.mousemoved(x, y, dx, dy, istouch) end
+
+--- Called when the window is resized.
+---@param w integer
+---@param h integer
+function love.resize(w, h) end
+
+--- Called when text has been entered by the user.
+---@param text string UTF-8 encoded unicode text
+function love.textinput(text) end
+
+---@alias love.KeyConstant string
+---@alias love.Scancode string
+---@alias love.DrawMode "fill"|"line"
+---@alias love.FilterMode "linear"|"nearest"
+---@alias love.AlignMode "left"|"center"|"right"|"justify"
+
+---@class (partial) love.Drawable: love.Object
+
+---@class (partial) love.Texture: love.Drawable
+local Texture = {}
+
+--- Gets the width and height of the texture.
+---@return integer width
+---@return integer height
+function Texture:getDimensions() end
+
+---@return integer
+function Texture:getWidth() end
+
+---@return integer
+function Texture:getHeight() end
+
+--- Sets the filter mode of the texture.
+---@param min love.FilterMode
+---@param mag love.FilterMode
+---@param anisotropy? number
+function Texture:setFilter(min, mag, anisotropy) end
+
+---@class (partial) love.Image: love.Texture
+
+---@class (partial) love.Canvas: love.Texture
+
+---@class (partial) love.Quad: love.Object
+
+---@class (partial) love.Font: love.Object
+local Font = {}
+
+--- Determines the width of the given text.
+---@param text string
+---@return integer width
+function Font:getWidth(text) end
+
+--- Gets the height of the font.
+---@return integer height
+function Font:getHeight() end
+
+---@class (partial) love.Source: love.Object
+local Source = {}
+
+--- Starts playing the source.
+---@return boolean success
+function Source:play() end
+
+--- Stops the source.
+function Source:stop() end
+
+--- Pauses the source.
+function Source:pause() end
+
+--- Sets whether the source should loop.
+---@param loop boolean
+function Source:setLooping(loop) end
+
+--- Sets the current volume of the source.
+---@param volume number
+function Source:setVolume(volume) end
+
+---@return boolean
+function Source:isPlaying() end
+
+---@class (partial) love.graphics
+love.graphics = {}
+
+--- Draws a drawable object (an image, canvas, text, mesh...) on the screen.
+---@param drawable love.Drawable
+---@param x? number
+---@param y? number
+---@param r? number orientation in radians
+---@param sx? number
+---@param sy? number
+---@param ox? number
+---@param oy? number
+---@overload fun(texture: love.Texture, quad: love.Quad, x?: number, y?: number, r?: number, sx?: number, sy?: number, ox?: number, oy?: number)
+function love.graphics.draw(drawable, x, y, r, sx, sy, ox, oy) end
+
+--- Draws text on screen.
+---@param text string
+---@param x? number
+---@param y? number
+---@param r? number
+---@param sx? number
+---@param sy? number
+function love.graphics.print(text, x, y, r, sx, sy) end
+
+--- Draws formatted text, with word wrap and alignment.
+---@param text string
+---@param x number
+---@param y number
+---@param limit number
+---@param align? love.AlignMode
+function love.graphics.printf(text, x, y, limit, align) end
+
+--- Draws a rectangle.
+---@param mode love.DrawMode
+---@param x number
+---@param y number
+---@param width number
+---@param height number
+---@param rx? number
+---@param ry? number
+function love.graphics.rectangle(mode, x, y, width, height, rx, ry) end
+
+--- Draws a circle.
+---@param mode love.DrawMode
+---@param x number
+---@param y number
+---@param radius number
+---@param segments? integer
+function love.graphics.circle(mode, x, y, radius, segments) end
+
+--- Draws lines between points.
+---@param ... number
+function love.graphics.line(...) end
+
+--- Sets the color used for drawing, components are in the range 0-1.
+---@param red number
+---@param green number
+---@param blue number
+---@param alpha? number
+---@overload fun(rgba: number[])
+function love.graphics.setColor(red, green, blue, alpha) end
+
+--- Sets the background color.
+---@param red number
+---@param green number
+---@param blue number
+---@param alpha? number
+function love.graphics.setBackgroundColor(red, green, blue, alpha) end
+
+--- Clears the screen or active canvas to the specified color.
+---@param red? number
+---@param green? number
+---@param blue? number
+---@param alpha? number
+function love.graphics.clear(red, green, blue, alpha) end
+
+--- Creates a new Image from a filepath.
+---@param filename string
+---@return love.Image image
+function love.graphics.newImage(filename) end
+
+--- Creates a new Canvas object for offscreen rendering.
+---@param width? integer
+---@param height? integer
+---@return love.Canvas canvas
+function love.graphics.newCanvas(width, height) end
+
+--- Creates a new Quad, a part of a texture.
+---@param x number
+---@param y number
+---@param width number
+---@param height number
+---@param sw number reference width, usually the texture width
+---@param sh number reference height, usually the texture height
+---@return love.Quad quad
+function love.graphics.newQuad(x, y, width, height, sw, sh) end
+
+--- Creates a new Font from a TrueType font or BMFont file.
+---@param filename string
+---@param size? number
+---@return love.Font font
+---@overload fun(size?: number): love.Font
+function love.graphics.newFont(filename, size) end
+
+--- Sets the active font.
+---@param font love.Font
+function love.graphics.setFont(font) end
+
+--- Sets a canvas as the render target, `nil` renders to the screen.
+---@param canvas? love.Canvas
+function love.graphics.setCanvas(canvas) end
+
+--- Copies and pushes the current coordinate transformation to the transformation stack.
+function love.graphics.push() end
+
+--- Pops the current coordinate transformation from the transformation stack.
+function love.graphics.pop() end
+
+--- Translates the coordinate system in two dimensions.
+---@param dx number
+---@param dy number
+function love.graphics.translate(dx, dy) end
+
+--- Rotates the coordinate system in two dimensions.
+---@param angle number radians
+function love.graphics.rotate(angle) end
+
+--- Scales the coordinate system in two dimensions.
+---@param sx number
+---@param sy? number
+function love.graphics.scale(sx, sy) end
+
+--- Gets the width and height of the window.
+---@return integer width
+---@return integer height
+function love.graphics.getDimensions() end
+
+---@return integer
+function love.graphics.getWidth() end
+
+---@return integer
+function love.graphics.getHeight() end
+
+---@class (partial) love.keyboard
+love.keyboard = {}
+
+--- Checks whether a certain key is down.
+---@param key love.KeyConstant
+---@param ... love.KeyConstant
+---@return boolean down
+function love.keyboard.isDown(key, ...) end
+
+---@class (partial) love.mouse
+love.mouse = {}
+
+--- Returns the current position of the mouse.
+---@return number x
+---@return number y
+function love.mouse.getPosition() end
+
+--- Checks whether a certain mouse button is down.
+---@param button integer
+---@param ... integer
+---@return boolean down
+function love.mouse.isDown(button, ...) end
+
+---@class (partial) love.audio
+love.audio = {}
+
+--- Creates a new Source from a filepath.
+---@param filename string
+---@param type "static"|"stream"
+---@return love.Source source
+function love.audio.newSource(filename, type) end
+
+--- Plays the specified sources.
+---@param source love.Source
+function love.audio.play(source) end
+
+---@class (partial) love.timer
+love.timer = {}
+
+--- Returns the time between the last two frames.
+---@return number dt
+function love.timer.getDelta() end
+
+--- Returns the current frames per second.
+---@return integer fps
+function love.timer.getFPS() end
+
+--- Returns the value of a timer with an unspecified starting time, in seconds.
+---@return number time
+function love.timer.getTime() end
+
+---@class (partial) love.filesystem
+love.filesystem = {}
+
+--- Reads the contents of a file.
+---@param name string
+---@param size? integer
+---@return string? contents
+---@return string|integer sizeOrError
+function love.filesystem.read(name, size) end
+
+--- Writes data to a file in the save directory.
+---@param name string
+---@param data string
+---@param size? integer
+---@return boolean success
+---@return string? message
+function love.filesystem.write(name, data, size) end
+
+--- Gets information about the specified file or directory.
+---@param path string
+---@return { type: "file"|"directory"|"symlink"|"other", size?: integer, modtime?: integer }? info
+function love.filesystem.getInfo(path) end
+
+--- Returns a table with the names of files and subdirectories in the specified path.
+---@param dir string
+---@return string[] files
+function love.filesystem.getDirectoryItems(dir) end
+
+--- Loads a Lua file without running it.
+---@param name string
+---@return function chunk
+---@return string? errormsg
+function love.filesystem.load(name) end
+
+---@class (partial) love.window
+love.window = {}
+
+--- Sets the window title.
+---@param title string
+function love.window.setTitle(title) end
+
+--- Sets the display mode and properties of the window.
+---@param width integer
+---@param height integer
+---@param flags? table
+---@return boolean success
+function love.window.setMode(width, height, flags) end
+
+---@class (partial) love.math
+love.math = {}
+
+--- Generates a pseudo-random number in a platform independent manner.
+---@param min? number
+---@param max? number
+---@return number
+function love.math.random(min, max) end
+
+--- Generates a Simplex or Perlin noise value in 1-4 dimensions.
+---@param x number
+---@param y? number
+---@param z? number
+---@param w? number
+---@return number value in the range 0-1
+function love.math.noise(x, y, z, w) end
+
+---@class (partial) love.event
+love.event = {}
+
+--- Exits the LÖVE program.
+---@param exitstatus? integer|"restart"
+function love.event.quit(exitstatus) end
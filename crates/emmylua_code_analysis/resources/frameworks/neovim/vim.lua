@@ -0,0 +1,255 @@
+---@meta
+
+-- A subset of the Neovim 0.10 Lua API: common `vim.api`, `vim.keymap` and `vim.log`
+-- functions and a few `vim.*` helpers.
+-- Every class is `(partial)`, declare `---@class (partial) <name>` in your own
+-- definition files to add what is missing.
+
+---@class (partial) vim
+vim = {}
+
+--- Raw Vim API functions, see `:help api`.
+---@class (partial) vim.api
+vim.api = {}
+
+--- Vim editor options, see `:help vim.o`.
+---@type table<string, any>
+vim.o = {}
+
+--- Window-local options, see `:help vim.wo`.
+---@type table<string, any>
+vim.wo = {}
+
+--- Buffer-local options, see `:help vim.bo`.
+---@type table<string, any>
+vim.bo = {}
+
+--- Global editor variables, see `:help vim.g`.
+---@type table<string, any>
+vim.g = {}
+
+--- Buffer-scoped variables, see `:help vim.b`.
+---@type table<string, any>
+vim.b = {}
+
+--- Environment variables, see `:help vim.env`.
+---@type table<string, string>
+vim.env = {}
+
+--- Vimscript functions, eg `vim.fn.expand("%")`.
+---@type table<string, fun(...): any>
+vim.fn = {}
+
+--- Option objects with list and map helpers, see `:help vim.opt`.
+---@type table<string, any>
+vim.opt = {}
+
+---@class (partial) vim.log
+vim.log = {}
+
+---@enum vim.log.levels
+vim.log.levels = {
+    TRACE = 0,
+    DEBUG = 1,
+    INFO = 2,
+    WARN = 3,
+    ERROR = 4,
+    OFF = 5,
+}
+
+--- Executes Vim script commands.
+---@param command string|table
+function vim.cmd(command) end
+
+--- Displays a notification to the user.
+---@param msg string
+---@param level? vim.log.levels
+---@param opts? table
+function vim.notify(msg, level, opts) end
+
+--- Schedules `fn` to be invoked soon by the main event loop.
+---@param fn function
+function vim.schedule(fn) end
+
+--- Defers calling `fn` until `timeout` ms passes.
+---@param fn function
+---@param timeout integer
+---@return table timer
+function vim.defer_fn(fn, timeout) end
+
+--- Returns a human readable representation of the given object.
+---@param ... any
+---@return string
+function vim.inspect(...) end
+
+--- Prints the given objects in a human readable form.
+---@generic T
+---@param ... T
+---@return T ...
+function vim.print(...) end
+
+--- Returns a deep copy of the given object.
+---@generic T
+---@param orig T
+---@return T
+function vim.deepcopy(orig) end
+
+--- Merges two or more tables.
+---@param behavior "error"|"keep"|"force"
+---@param ... table
+---@return table merged
+function vim.tbl_extend(behavior, ...) end
+
+--- Merges recursively two or more tables.
+---@param behavior "error"|"keep"|"force"
+---@param ... table
+---@return table merged
+function vim.tbl_deep_extend(behavior, ...) end
+
+--- Checks if a table contains a given value.
+---@param t table
+---@param value any
+---@return boolean
+function vim.tbl_contains(t, value) end
+
+--- Returns a list of all keys of a table.
+---@generic K
+---@param t table<K, any>
+---@return K[]
+function vim.tbl_keys(t) end
+
+--- Returns a list of all values of a table.
+---@generic V
+---@param t table<any, V>
+---@return V[]
+function vim.tbl_values(t) end
+
+--- Checks if a table is empty.
+---@param t table
+---@return boolean
+function vim.tbl_isempty(t) end
+
+--- Splits a string at each instance of a separator.
+---@param s string
+---@param sep string
+---@param opts? { plain?: boolean, trimempty?: boolean }
+---@return string[]
+function vim.split(s, sep, opts) end
+
+--- Trims whitespace from both sides of a string.
+---@param s string
+---@return string
+function vim.trim(s) end
+
+--- Tests if `s` starts with `prefix`.
+---@param s string
+---@param prefix string
+---@return boolean
+function vim.startswith(s, prefix) end
+
+--- Tests if `s` ends with `suffix`.
+---@param s string
+---@param suffix string
+---@return boolean
+function vim.endswith(s, suffix) end
+
+---@class (partial) vim.keymap
+vim.keymap = {}
+
+--- Adds a new mapping.
+---@param mode string|string[]
+---@param lhs string
+---@param rhs string|function
+---@param opts? table
+function vim.keymap.set(mode, lhs, rhs, opts) end
+
+--- Removes an existing mapping.
+---@param modes string|string[]
+---@param lhs string
+---@param opts? { buffer?: integer|boolean }
+function vim.keymap.del(modes, lhs, opts) end
+
+--- Gets the current buffer number, `0` also refers to the current buffer.
+---@return integer
+function vim.api.nvim_get_current_buf() end
+
+--- Gets the current window handle.
+---@return integer
+function vim.api.nvim_get_current_win() end
+
+--- Gets a line range from the buffer, indexing is zero-based and end-exclusive.
+---@param buffer integer
+---@param start integer
+---@param end_ integer
+---@param strict_indexing boolean
+---@return string[]
+function vim.api.nvim_buf_get_lines(buffer, start, end_, strict_indexing) end
+
+--- Sets or replaces a line range in the buffer.
+---@param buffer integer
+---@param start integer
+---@param end_ integer
+---@param strict_indexing boolean
+---@param replacement string[]
+function vim.api.nvim_buf_set_lines(buffer, start, end_, strict_indexing, replacement) end
+
+--- Gets the full file name of the buffer.
+---@param buffer integer
+---@return string
+function vim.api.nvim_buf_get_name(buffer) end
+
+--- Creates a new empty buffer.
+---@param listed boolean
+---@param scratch boolean
+---@return integer buffer
+function vim.api.nvim_create_buf(listed, scratch) end
+
+--- Gets the cursor position in the window, `(1,0)`-indexed.
+---@param window integer
+---@return integer[] pos
+function vim.api.nvim_win_get_cursor(window) end
+
+--- Sets the cursor position in the window.
+---@param window integer
+---@param pos integer[]
+function vim.api.nvim_win_set_cursor(window, pos) end
+
+--- Creates an autocommand event handler.
+---@param event string|string[]
+---@param opts table
+---@return integer id
+function vim.api.nvim_create_autocmd(event, opts) end
+
+--- Creates or gets an autocommand group.
+---@param name string
+---@param opts { clear?: boolean }
+---@return integer id
+function vim.api.nvim_create_augroup(name, opts) end
+
+--- Creates a global user command.
+---@param name string
+---@param command string|fun(args: table)
+---@param opts table
+function vim.api.nvim_create_user_command(name, command, opts) end
+
+--- Sets a global mapping for the given mode.
+---@param mode string
+---@param lhs string
+---@param rhs string
+---@param opts table
+function vim.api.nvim_set_keymap(mode, lhs, rhs, opts) end
+
+--- Sets a highlight group.
+---@param ns_id integer
+---@param name string
+---@param val table
+function vim.api.nvim_set_hl(ns_id, name, val) end
+
+--- Executes an Ex command.
+---@param command string
+function vim.api.nvim_command(command) end
+
+--- Evaluates a Vimscript expression.
+---@param expr string
+---@return any
+function vim.api.nvim_eval(expr) end
@@ -0,0 +1,376 @@
+---@meta
+
+-- A subset of the OpenResty 1.x `ngx` API: the constants, ngx.req, shared dicts,
+-- ngx.re, timers, light threads, subrequests and cosockets.
+-- Every class is `(partial)`, declare `---@class (partial) <name>` in your own
+-- definition files to add what is missing.
+
+---@class (partial) ngx
+ngx = {}
+
+ngx.OK = 0
+ngx.ERROR = -1
+ngx.AGAIN = -2
+ngx.DONE = -4
+ngx.DECLINED = -5
+
+ngx.HTTP_GET = 2
+ngx.HTTP_HEAD = 4
+ngx.HTTP_POST = 8
+ngx.HTTP_PUT = 16
+ngx.HTTP_DELETE = 32
+
+ngx.HTTP_OK = 200
+ngx.HTTP_CREATED = 201
+ngx.HTTP_NO_CONTENT = 204
+ngx.HTTP_MOVED_PERMANENTLY = 301
+ngx.HTTP_MOVED_TEMPORARILY = 302
+ngx.HTTP_NOT_MODIFIED = 304
+ngx.HTTP_BAD_REQUEST = 400
+ngx.HTTP_UNAUTHORIZED = 401
+ngx.HTTP_FORBIDDEN = 403
+ngx.HTTP_NOT_FOUND = 404
+ngx.HTTP_NOT_ALLOWED = 405
+ngx.HTTP_INTERNAL_SERVER_ERROR = 500
+ngx.HTTP_BAD_GATEWAY = 502
+ngx.HTTP_SERVICE_UNAVAILABLE = 503
+ngx.HTTP_GATEWAY_TIMEOUT = 504
+
+ngx.STDERR = 0
+ngx.EMERG = 1
+ngx.ALERT = 2
+ngx.CRIT = 3
+ngx.ERR = 4
+ngx.WARN = 5
+ngx.NOTICE = 6
+ngx.INFO = 7
+ngx.DEBUG = 8
+
+--- Represents the `null` value in the Lua context, eg for json null or redis nil.
+---@type lightuserdata
+ngx.null = nil
+
+--- Read and write the current request's response status.
+---@type integer
+ngx.status = 0
+
+--- Nginx variables, eg `ngx.var.remote_addr`.
+---@type table<string, string|nil>
+ngx.var = {}
+
+--- A Lua table that can be used to store per-request Lua context data.
+---@type table
+ngx.ctx = {}
+
+--- Response headers, eg `ngx.header["Content-Type"] = "text/plain"`.
+---@type table<string, string|string[]|nil>
+ngx.header = {}
+
+--- Writes the arguments concatenated to the http client as the response body.
+---@param ... string|number|boolean|table
+---@return integer? ok
+---@return string? err
+function ngx.print(...) end
+
+--- Like `ngx.print` but also emits a trailing newline.
+---@param ... string|number|boolean|table
+---@return integer? ok
+---@return string? err
+function ngx.say(...) end
+
+--- Logs the arguments concatenated to error.log with the given level.
+---@param level integer
+---@param ... any
+function ngx.log(level, ...) end
+
+--- Flushes the response output to the client.
+---@param wait? boolean
+---@return integer? ok
+---@return string? err
+function ngx.flush(wait) end
+
+--- Exits the current request handler with the given status.
+---@param status integer
+function ngx.exit(status) end
+
+--- Issues an HTTP 301 or 302 redirection to `uri`.
+---@param uri string
+---@param status? integer
+function ngx.redirect(uri, status) end
+
+--- Sleeps for the specified seconds without blocking, resolution is 0.001 seconds.
+---@param seconds number
+function ngx.sleep(seconds) end
+
+--- Returns the elapsed seconds from the epoch for the current time stamp from the nginx cached time.
+---@return integer secs
+function ngx.time() end
+
+--- Returns a floating-point number for the elapsed time in seconds from the epoch.
+---@return number secs
+function ngx.now() end
+
+--- Returns the current time stamp in the format `yyyy-mm-dd hh:mm:ss`.
+---@return string
+function ngx.localtime() end
+
+--- Updates the nginx cached time.
+function ngx.update_time() end
+
+--- Escapes `str` as a URI component.
+---@param str string
+---@return string
+function ngx.escape_uri(str) end
+
+--- Unescapes `str` as an escaped URI component.
+---@param str string
+---@return string
+function ngx.unescape_uri(str) end
+
+--- Encodes a Lua table to a query args string.
+---@param args table
+---@return string
+function ngx.encode_args(args) end
+
+--- Decodes a URI encoded query-string into a Lua table.
+---@param str string
+---@param max_args? integer
+---@return table
+function ngx.decode_args(str, max_args) end
+
+--- Encodes `str` to a base64 digest.
+---@param str string
+---@param no_padding? boolean
+---@return string
+function ngx.encode_base64(str, no_padding) end
+
+--- Decodes `str` as a base64 digest, returns `nil` on invalid input.
+---@param str string
+---@return string?
+function ngx.decode_base64(str) end
+
+--- Returns the hexadecimal representation of the MD5 digest of `str`.
+---@param str string
+---@return string
+function ngx.md5(str) end
+
+--- Returns the binary form of the SHA-1 digest of `str`.
+---@param str string
+---@return string
+function ngx.sha1_bin(str) end
+
+---@class (partial) ngx.req
+ngx.req = {}
+
+--- Returns the request method name, eg `GET`.
+---@return string
+function ngx.req.get_method() end
+
+--- Returns a Lua table holding all the current request URL query arguments.
+---@param max_args? integer
+---@return table<string, string|string[]|boolean> args
+---@return string? err
+function ngx.req.get_uri_args(max_args) end
+
+--- Returns a Lua table holding all the current request POST query arguments.
+---@param max_args? integer
+---@return table<string, string|string[]|boolean>? args
+---@return string? err
+function ngx.req.get_post_args(max_args) end
+
+--- Returns a Lua table holding all the current request headers.
+---@param max_headers? integer
+---@param raw? boolean
+---@return table<string, string|string[]> headers
+---@return string? err
+function ngx.req.get_headers(max_headers, raw) end
+
+--- Sets the current request's request header.
+---@param header_name string
+---@param header_value string|string[]|nil
+function ngx.req.set_header(header_name, header_value) end
+
+--- Reads the client request body synchronously.
+function ngx.req.read_body() end
+
+--- Retrieves in-memory request body data, `nil` if the body has not been read or was buffered to a file.
+---@return string?
+function ngx.req.get_body_data() end
+
+---@class (partial) ngx.shared.DICT
+local DICT = {}
+
+--- Retrieves the value in the dictionary for the key.
+---@param key string
+---@return any value
+---@return integer|string? flags_or_err
+function DICT:get(key) end
+
+--- Unconditionally sets a key-value pair into the dictionary.
+---@param key string
+---@param value any
+---@param exptime? number
+---@param flags? integer
+---@return boolean success
+---@return string? err
+---@return boolean forcible
+function DICT:set(key, value, exptime, flags) end
+
+--- Sets a key-value pair only if the key does not exist.
+---@param key string
+---@param value any
+---@param exptime? number
+---@param flags? integer
+---@return boolean success
+---@return string? err
+---@return boolean forcible
+function DICT:add(key, value, exptime, flags) end
+
+--- Increments the numerical value for the key by `value`.
+---@param key string
+---@param value number
+---@param init? number
+---@param init_ttl? number
+---@return integer|number? newval
+---@return string? err
+---@return boolean? forcible
+function DICT:incr(key, value, init, init_ttl) end
+
+--- Unconditionally removes the key-value pair.
+---@param key string
+function DICT:delete(key) end
+
+--- Shared memory zones declared with `lua_shared_dict`.
+---@type table<string, ngx.shared.DICT>
+ngx.shared = {}
+
+---@class (partial) ngx.re
+ngx.re = {}
+
+--- Matches the `subject` string using the Perl compatible regular expression `regex`.
+---@param subject string
+---@param regex string
+---@param options? string
+---@param ctx? table
+---@param res_table? table
+---@return table? captures
+---@return string? err
+function ngx.re.match(subject, regex, options, ctx, res_table) end
+
+--- Returns a Lua iterator to iterate over all matches.
+---@param subject string
+---@param regex string
+---@param options? string
+---@return (fun(): table?, string?)? iterator
+---@return string? err
+function ngx.re.gmatch(subject, regex, options) end
+
+--- Substitutes the first match of `regex` in `subject` with `replace`.
+---@param subject string
+---@param regex string
+---@param replace string|fun(m: table): string
+---@param options? string
+---@return string? newstr
+---@return integer? n
+---@return string? err
+function ngx.re.sub(subject, regex, replace, options) end
+
+--- Substitutes all matches of `regex` in `subject` with `replace`.
+---@param subject string
+---@param regex string
+---@param replace string|fun(m: table): string
+---@param options? string
+---@return string? newstr
+---@return integer? n
+---@return string? err
+function ngx.re.gsub(subject, regex, replace, options) end
+
+---@class (partial) ngx.timer
+ngx.timer = {}
+
+--- Creates an nginx timer with a user callback function.
+---@param delay number
+---@param callback fun(premature: boolean, ...)
+---@param ... any
+---@return boolean? ok
+---@return string? err
+function ngx.timer.at(delay, callback, ...) end
+
+--- Creates a recurring timer.
+---@param delay number
+---@param callback fun(premature: boolean, ...)
+---@param ... any
+---@return boolean? ok
+---@return string? err
+function ngx.timer.every(delay, callback, ...) end
+
+---@class (partial) ngx.thread
+ngx.thread = {}
+
+--- Spawns a new user light thread.
+---@param func function
+---@param ... any
+---@return thread co
+function ngx.thread.spawn(func, ...) end
+
+--- Waits on one or more child light threads.
+---@param ... thread
+---@return boolean ok
+---@return any ...
+function ngx.thread.wait(...) end
+
+---@class (partial) ngx.location
+ngx.location = {}
+
+--- Issues a synchronous but still non-blocking nginx subrequest.
+---@param uri string
+---@param options? table
+---@return { status: integer, header: table, body: string, truncated: boolean }
+function ngx.location.capture(uri, options) end
+
+---@class (partial) ngx.socket
+ngx.socket = {}
+
+---@class (partial) ngx.socket.tcpsock
+local tcpsock = {}
+
+--- Attempts to connect a TCP socket object to a remote server.
+---@param host string
+---@param port? integer
+---@param options? table
+---@return integer? ok
+---@return string? err
+function tcpsock:connect(host, port, options) end
+
+--- Sends data on the current TCP connection.
+---@param data string|table
+---@return integer? bytes
+---@return string? err
+function tcpsock:send(data) end
+
+--- Receives data from the connected socket according to the reading pattern or size.
+---@param size_or_pattern? integer|"*a"|"*l"
+---@return string? data
+---@return string? err
+---@return string? partial
+function tcpsock:receive(size_or_pattern) end
+
+--- Sets the timeout value in milliseconds for subsequent socket operations.
+---@param time integer
+function tcpsock:settimeout(time) end
+
+--- Puts the current socket's connection into the cosocket built-in connection pool.
+---@param timeout? integer
+---@param size? integer
+---@return integer? ok
+---@return string? err
+function tcpsock:setkeepalive(timeout, size) end
+
+--- Closes the current TCP or stream unix domain socket.
+---@return integer? ok
+---@return string? err
+function tcpsock:close() end
+
+--- Creates and returns a TCP or stream-oriented unix domain socket object.
+---@return ngx.socket.tcpsock
+function ngx.socket.tcp() end
@@ -0,0 +1,275 @@
+---@meta
+
+-- A subset of the Roblox engine API: the basic data types, Instance, Players,
+-- RunService and the task library.
+-- Every class is `(partial)`, declare `---@class (partial) <name>` in your own
+-- definition files to add what is missing.
+
+---@class (partial) RBXScriptConnection
+---@field Connected boolean
+local RBXScriptConnection = {}
+
+--- Disconnects the connection from the event.
+function RBXScriptConnection:Disconnect() end
+
+---@class (partial) RBXScriptSignal
+local RBXScriptSignal = {}
+
+--- Connects the given function to the event.
+---@param func function
+---@return RBXScriptConnection
+function RBXScriptSignal:Connect(func) end
+
+--- Connects the given function to the event for a single invocation.
+---@param func function
+---@return RBXScriptConnection
+function RBXScriptSignal:Once(func) end
+
+--- Yields the current thread until the signal fires and returns the arguments.
+---@return any ...
+function RBXScriptSignal:Wait() end
+
+---@class (partial) Vector3
+---@field X number
+---@field Y number
+---@field Z number
+---@field Magnitude number
+---@field Unit Vector3
+---@operator add(Vector3): Vector3
+---@operator sub(Vector3): Vector3
+---@operator mul(number): Vector3
+---@operator unm: Vector3
+local Vector3Object = {}
+
+--- Returns the dot product of the two vectors.
+---@param other Vector3
+---@return number
+function Vector3Object:Dot(other) end
+
+--- Returns the cross product of the two vectors.
+---@param other Vector3
+---@return Vector3
+function Vector3Object:Cross(other) end
+
+--- Returns a Vector3 linearly interpolated between this and the goal.
+---@param goal Vector3
+---@param alpha number
+---@return Vector3
+function Vector3Object:Lerp(goal, alpha) end
+
+---@class (partial) Vector3Constructor
+---@field zero Vector3
+---@field one Vector3
+Vector3 = {}
+
+--- Returns a new Vector3 from the given components.
+---@param x? number
+---@param y? number
+---@param z? number
+---@return Vector3
+function Vector3.new(x, y, z) end
+
+---@class (partial) CFrame
+---@field Position Vector3
+---@field LookVector Vector3
+---@field RightVector Vector3
+---@field UpVector Vector3
+---@operator mul(CFrame): CFrame
+local CFrameObject = {}
+
+--- Returns the inverse of this CFrame.
+---@return CFrame
+function CFrameObject:Inverse() end
+
+---@class (partial) CFrameConstructor
+CFrame = {}
+
+--- Returns a new CFrame at the given position, or looking from `pos` to `lookAt`.
+---@param x? number
+---@param y? number
+---@param z? number
+---@return CFrame
+---@overload fun(pos: Vector3, lookAt?: Vector3): CFrame
+function CFrame.new(x, y, z) end
+
+---@class (partial) Color3
+---@field R number
+---@field G number
+---@field B number
+
+---@class (partial) Color3Constructor
+Color3 = {}
+
+--- Returns a Color3 with the given components in the range 0-1.
+---@param r? number
+---@param g? number
+---@param b? number
+---@return Color3
+function Color3.new(r, g, b) end
+
+--- Returns a Color3 from components in the range 0-255.
+---@param r integer
+---@param g integer
+---@param b integer
+---@return Color3
+function Color3.fromRGB(r, g, b) end
+
+---@class (partial) Instance
+---@field Name string
+---@field Parent Instance?
+---@field ClassName string
+---@field ChildAdded RBXScriptSignal
+---@field ChildRemoved RBXScriptSignal
+---@field Destroying RBXScriptSignal
+local InstanceObject = {}
+
+--- Returns the first child with the given name.
+---@param name string
+---@param recursive? boolean
+---@return Instance?
+function InstanceObject:FindFirstChild(name, recursive) end
+
+--- Returns the first child that is of the given class.
+---@param className string
+---@return Instance?
+function InstanceObject:FindFirstChildOfClass(className) end
+
+--- Returns the first child for which `IsA` returns true for the given class.
+---@param className string
+---@return Instance?
+function InstanceObject:FindFirstChildWhichIsA(className) end
+
+--- Yields until a child with the given name exists.
+---@param name string
+---@param timeout? number
+---@return Instance
+function InstanceObject:WaitForChild(name, timeout) end
+
+--- Returns an array of the direct children.
+---@return Instance[]
+function InstanceObject:GetChildren() end
+
+--- Returns an array of all descendants.
+---@return Instance[]
+function InstanceObject:GetDescendants() end
+
+--- Returns true if the instance's class matches or inherits from the given class.
+---@param className string
+---@return boolean
+function InstanceObject:IsA(className) end
+
+--- Sets the Parent to nil, locks it and disconnects all connections.
+function InstanceObject:Destroy() end
+
+--- Creates a copy of the instance and all its descendants.
+---@return Instance
+function InstanceObject:Clone() end
+
+--- Returns a signal that fires when the given property changes.
+---@param property string
+---@return RBXScriptSignal
+function InstanceObject:GetPropertyChangedSignal(property) end
+
+---@class (partial) InstanceConstructor
+Instance = {}
+
+--- Creates a new Instance of the given class.
+---@param className string
+---@param parent? Instance
+---@return Instance
+function Instance.new(className, parent) end
+
+---@class (partial) Player: Instance
+---@field UserId integer
+---@field DisplayName string
+---@field Character Instance?
+---@field CharacterAdded RBXScriptSignal
+
+---@class (partial) Players: Instance
+---@field LocalPlayer Player
+---@field PlayerAdded RBXScriptSignal
+---@field PlayerRemoving RBXScriptSignal
+local Players = {}
+
+--- Returns a table of all connected players.
+---@return Player[]
+function Players:GetPlayers() end
+
+---@class (partial) RunService: Instance
+---@field Heartbeat RBXScriptSignal
+---@field RenderStepped RBXScriptSignal
+---@field Stepped RBXScriptSignal
+local RunService = {}
+
+---@return boolean
+function RunService:IsServer() end
+
+---@return boolean
+function RunService:IsClient() end
+
+---@class (partial) DataModel: Instance
+---@field Workspace Instance
+local DataModel = {}
+
+--- Returns the service with the given class name, creating it if needed.
+---@param className string
+---@return Instance
+---@overload fun(self: DataModel, className: "Players"): Players
+---@overload fun(self: DataModel, className: "RunService"): RunService
+function DataModel:GetService(className) end
+
+--- The root of the Roblox data model.
+---@type DataModel
+game = nil
+
+--- The Workspace service.
+---@type Instance
+workspace = nil
+
+--- The script object that is running the code.
+---@type Instance
+script = nil
+
+---@class (partial) task
+task = {}
+
+--- Calls or resumes a function or coroutine immediately.
+---@param functionOrThread function|thread
+---@param ... any
+---@return thread
+function task.spawn(functionOrThread, ...) end
+
+--- Calls or resumes a function or coroutine at the end of the current resumption cycle.
+---@param functionOrThread function|thread
+---@param ... any
+---@return thread
+function task.defer(functionOrThread, ...) end
+
+--- Schedules a function or coroutine to be called after the given duration.
+---@param duration number
+---@param functionOrThread function|thread
+---@param ... any
+---@return thread
+function task.delay(duration, functionOrThread, ...) end
+
+--- Yields the current thread until the given duration has passed.
+---@param duration? number
+---@return number elapsed
+function task.wait(duration) end
+
+--- Cancels a thread, preventing it from being resumed.
+---@param thread thread
+function task.cancel(thread) end
+
+--- Returns the type of the object, including Roblox types like `Instance` and `Vector3`.
+---@param value any
+---@return string
+function typeof(value) end
+
+--- Prints a warning message to the output.
+---@param ... any
+function warn(...) end
+
+--- Returns the time since the game started running, in seconds.
+---@return number
+function tick() end
@@ -3,12 +3,12 @@
 ---@meta bit32
 ---@version 5.2
 
----@version 5.2
 ---
 ---
 ---
 ---[View documents](command:extension.lua.doc?["en-us/54/manual.html/pdf-bit32"])
 ---
+---@version 5.2
 ---@class bit32lib
 bit32 = {}
 
@@ -115,3 +115,39 @@
 ---
 --- built-in type for Unpack function
 ---@alias std.Unpack<T, Start, End> unknown
+
+--- Make all fields of `T` optional
+---@alias Partial<T> { [K in keyof T]?: T[K] }
+---
+--- Make all fields of `T` non-nil
+---@alias Required<T> { [K in keyof T]: std.NonNil<T[K]> }
+---
+--- Make all fields of `T` readonly
+---@alias Readonly<T> std.Readonly<{ [K in keyof T]: T[K] }>
+---
+--- Keep only the fields `K` of `T`
+---@alias Pick<T, K> { [P in K]: T[P] }
+---
+--- Remove the fields `K` from `T`
+---@alias Omit<T, K> { [P in (keyof T) - K]: T[P] }
+---
+--- A table with keys `K` and values `V`
+---@alias Record<K, V> { [P in K]: V }
+---
+--- built-in type for `T` without `nil`, `false` is kept
+---@alias std.NonNil<T> unknown
+---
+--- built-in type for an object type whose fields are readonly
+---@alias std.Readonly<T> unknown
+---
+--- built-in type for the return type of a function
+---@alias std.ReturnType<F> unknown
+---
+--- The return type of function `F`
+---@alias ReturnType<F> std.ReturnType<F>
+---
+--- built-in type for the parameter types of a function
+---@alias std.Parameters<F> unknown
+---
+--- The parameter types of function `F` as a tuple
+---@alias Parameters<F> std.Parameters<F>
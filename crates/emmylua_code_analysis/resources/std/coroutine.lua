@@ -28,6 +28,7 @@ function coroutine.create(f) end
 ---
 --- A running coroutine is yieldable if it is not the main thread and it is not
 --- inside a non-yieldable C function.
+---@version >5.3
 ---@return boolean
 function coroutine.isyieldable() end
 
@@ -87,8 +88,9 @@ function coroutine.status(co) end
 --- passed to the function behave as the extra arguments to `resume`. Returns
 --- the same values returned by `resume`, except the first
 --- boolean. In case of error, propagates the error.
----@param f async fun()
----@return fun():any
+---@generic T..., R...
+---@param f async fun(...: T): R
+---@return fun(...: T): R
 function coroutine.wrap(f) end
 
 ---
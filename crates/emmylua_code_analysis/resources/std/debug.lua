@@ -27,10 +27,10 @@ debug = {}
 --- function, and so have no direct access to local variables.
 function debug.debug() end
 
----@version 5.1
 ---
 ---Returns the environment of object `o` .
 ---
+---@version 5.1
 ---@param o any
 ---@return table
 ---@nodiscard
@@ -154,6 +154,7 @@ function debug.getupvalue(f, up) end
 ---
 --- Returns the `n`-th user value associated to the userdata `u` plus a boolean,
 --- **false** if the userdata does not have that value.
+---@version >5.2
 ---@param u userdata
 ---@param n number
 ---@return boolean
@@ -167,6 +168,7 @@ function debug.getuservalue(u, n) end
 ---In case of success, this function returns the old limit. In case of error, it returns `false`.
 ---
 ---
+---@version 5.4
 ---@deprecated
 ---@param limit integer
 ---@return integer|boolean
@@ -252,6 +254,7 @@ function debug.setupvalue(f, up, value) end
 --- `udata` must be a full userdata.
 ---
 --- Returns `udata`, or **nil** if the userdata does not have that value.
+---@version >5.2
 ---@param udata userdata
 ---@param value any
 ---@param n number
@@ -183,16 +183,37 @@ function loadstring(text, chunkname) end
 ---@param env? any
 function loadfile(filename, mode, env) end
 
+---
+--- Creates a blank userdata with an empty metatable, or with the metatable
+--- of another proxy. Undocumented in Lua 5.1.
 ---@version 5.1, JIT
 ---@param proxy boolean|table|userdata
 ---@return userdata
 function newproxy(proxy) end
 
+---
+---Returns the current environment in use by the function. `f` can be a Lua function or a
+---number that specifies the function at that stack level, level 1 is the function calling
+---`getfenv`.
 ---@version 5.1, JIT
+---@param f? integer|function
+---@return table
+function getfenv(f) end
+
+---
+---Sets the environment to be used by the given function. `f` can be a Lua function or a
+---number that specifies the function at that stack level.
+---@version 5.1, JIT
+---@param f integer|function
+---@param table table
+---@return function
+function setfenv(f, table) end
+
 ---
 ---Creates a module.
 ---
 ---
+---@version 5.1, JIT
 ---@param name string
 ---@param ...  any
 function module(name, ...) end
@@ -242,10 +263,10 @@ function pairs(t) end
 --- boolean), which is true if the call succeeds without errors. In such case,
 --- `pcall` also returns all results from the call, after this first result. In
 --- case of any error, `pcall` returns **false** plus the error message.
----@generic T, R
----@param f fun(...: T...): R...
----@param ... T...
----@return boolean, R...
+---@generic T..., R...
+---@param f fun(...: T): R
+---@param ... T
+---@return boolean, R
 function pcall(f, ...) end
 
 ---
@@ -274,6 +295,7 @@ function rawget(table, index) end
 
 --- Returns the length of the object `v`, which must be a table or a string, without
 --- invoking any metamethod. Returns an integer number.
+---@version >5.2
 ---@param v string|table
 ---@return number
 function rawlen(v) end
@@ -438,13 +460,18 @@ _VERSION = "Lua 5.4"
 ---
 --- This function is similar to `pcall`, except that it sets a new message
 --- handler `msgh`.
----@generic T, R
----@param f fun(...:T...): R...
+---@generic T..., R...
+---@param f fun(...: T): R
 ---@param msgh fun(err:string):void
----@param ... T...
----@return boolean, R...
+---@param ... T
+---@return boolean, R
 function xpcall(f, msgh, ...) end
 
+---
+--- Returns the elements from the given `list`. This function is equivalent to
+--- ```lua
+--- return list[i], list[i+1], ···, list[j]
+--- ```
 ---@version 5.1, JIT
 ---@generic T
 ---@param i? number
@@ -453,10 +480,15 @@ function xpcall(f, msgh, ...) end
 ---@return T...
 function unpack(list, i, j) end
 
+---
+--- Emits a warning with a message composed by the concatenation of all its
+--- arguments (which should be strings).
 ---@version > 5.4
 ---@param message string
 function warn(message) end
 
+---
+--- Command-line arguments of the standalone Lua interpreter.
 ---@type string[]
 arg = {}
 
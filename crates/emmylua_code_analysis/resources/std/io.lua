@@ -59,6 +59,7 @@ function io.input(file) end
 function io.lines(filename, ...) end
 
 ---@alias OpenMode "r" | "w" | "a" | "r+" | "w+" | "a+" | "rb" | "wb" | "ab" | "rb+" | "wb+" | "ab+"
+
 ---
 --- This function opens a file, in the mode specified in the string `mode`.  In
 --- case of success, it returns a new file handle. The `mode` string can be
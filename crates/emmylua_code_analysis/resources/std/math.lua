@@ -109,6 +109,7 @@ function math.max(x, ...) end
 
 ---
 --- An integer with the maximum value for an integer.
+---@version >5.3
 ---@type number
 math.maxinteger = nil
 
@@ -122,6 +123,7 @@ function math.min(x, ...) end
 
 ---
 --- An integer with the minimum value for an integer.
+---@version >5.3
 ---@type number
 math.mininteger = nil
 
@@ -182,6 +184,7 @@ function math.tan(x) return 0 end
 ---
 --- If the value `x` is convertible to an integer, returns that integer.
 --- Otherwise, returns `nil`.
+---@version >5.3
 ---@param x number
 ---@return number
 function math.tointeger(x) end
@@ -189,6 +192,7 @@ function math.tointeger(x) end
 ---
 --- Returns "`integer`" if `x` is an integer, "`float`" if it is a float, or
 --- **nil** if `x` is not a number.
+---@version >5.3
 ---@param x number
 ---@return number
 function math.type(x) end
@@ -196,9 +200,72 @@ function math.type(x) end
 ---
 --- Returns a boolean, true if and only if integer `m` is below integer `n` when
 --- they are compared as unsigned integers.
+---@version >5.3
 ---@param m number
 ---@param n number
 ---@return boolean
 function math.ult(m, n) end
 
+---
+--- Returns `x^y`. (You can also use the expression `x^y` to compute this value.)
+---@version <5.2
+---@param x number
+---@param y number
+---@return number
+function math.pow(x, y) end
+
+---
+--- Returns `m*2^e` (`e` should be an integer).
+---@version <5.2
+---@param m number
+---@param e integer
+---@return number
+function math.ldexp(m, e) end
+
+---
+--- Returns `m` and `e` such that `x = m*2^e`, `e` is an integer and the absolute
+--- value of `m` is in the range *[0.5, 1)* (or zero when `x` is zero).
+---@version <5.2
+---@param x number
+---@return number m
+---@return integer e
+function math.frexp(x) end
+
+---
+--- Returns the arc tangent of `y/x` (in radians), using the signs of both
+--- parameters to find the quadrant of the result.
+---@version <5.2
+---@param y number
+---@param x number
+---@return number
+function math.atan2(y, x) end
+
+---
+--- Returns the hyperbolic cosine of `x`.
+---@version <5.2
+---@param x number
+---@return number
+function math.cosh(x) end
+
+---
+--- Returns the hyperbolic sine of `x`.
+---@version <5.2
+---@param x number
+---@return number
+function math.sinh(x) end
+
+---
+--- Returns the hyperbolic tangent of `x`.
+---@version <5.2
+---@param x number
+---@return number
+function math.tanh(x) end
+
+---
+--- Returns the base-10 logarithm of `x`.
+---@version <5.2
+---@param x number
+---@return number
+function math.log10(x) end
+
 return math
@@ -97,10 +97,11 @@ package.path = ""
 --- variable do not change the table used by `require`.
 package.preload = {}
 
+---
+--- A table used by `require` to control how to load modules.
 ---@version 5.1, JIT
 package.loaders = {}
 
----@version > 5.2
 --- A table used by require to control how to load modules.
 ---
 --- Each entry in this table is a *searcher function*. When looking for a
@@ -143,9 +144,9 @@ package.loaders = {}
 --- All searchers except the first one (preload) return as the extra value the
 --- file name where the module was found, as returned by `package.searchpath`.
 --- The first searcher returns no extra value.
+---@version > 5.2
 package.searchers = {}
 
----@version > 5.2,JIT
 ---
 --- Searches for the given name in the given path.
 ---
@@ -163,6 +164,7 @@ package.searchers = {}
 --- Returns the resulting name of the first file that it can open in read mode
 --- (after closing the file), or **nil** plus an error message if none succeeds.
 --- (This error message lists all file names it tried to open.)
+---@version > 5.2,JIT
 ---@overload fun(name:string, path:string):string
 ---@param name string
 ---@param path? string
@@ -227,9 +227,9 @@ function string.lower(s) end
 ---@return any
 function string.match(s, pattern, init) end
 
----@version >5.3
 --- Returns a binary string containing the values `v1`, `v2`, etc. packed (that
 --- is, serialized in binary form) according to the format string `fmt`.
+---@version >5.3
 ---@param fmt string
 ---@param v1 string
 ---@param v2? string
@@ -237,10 +237,10 @@ function string.match(s, pattern, init) end
 ---@return string
 function string.pack(fmt, v1, v2, ...) end
 
----@version >5.3
 --- Returns the size of a string resulting from `string.pack` with the given
 --- format. The format string cannot have the variable-length options '`s`' or
 --- '`z`'
+---@version >5.3
 ---@param fmt string
 ---@return number
 function string.packsize(fmt) end
@@ -285,11 +285,11 @@ function string.reverse(s) end
 ---@return string
 function string.sub(s, i, j) end
 
----@version >5.3
 --- Returns the values packed in string `s` according to the format string
 --- `fmt`. An optional `pos` marks where to start reading in `s` (default is 1).
 --- After the read values, this function also returns the index of the first
 --- unread byte in `s`.
+---@version >5.3
 ---@overload fun(fmt:string, s:string):string
 ---@param fmt string
 ---@param s string
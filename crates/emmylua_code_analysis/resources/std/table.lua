@@ -50,6 +50,7 @@ function table.insert(list, pos, value) end
 --- range. The number of elements to be moved must fit in a Lua integer.
 ---
 --- Returns the destination table `a2`.
+---@version >5.3
 ---@overload fun(a1:table, f:number, e:number, t:number):table
 ---@param a1 table
 ---@param f number
@@ -59,10 +60,10 @@ function table.insert(list, pos, value) end
 ---@return table
 function table.move(a1, f, e, t, a2) end
 
----@version 5.1
 ---
 ---Returns the largest positive numerical index of the given table, or zero if the table has no positive numerical indices.
 ---
+---@version 5.1
 ---@param table table
 ---@return integer
 ---@nodiscard
@@ -105,10 +106,10 @@ function table.remove(list, pos) end
 ---@return number
 function table.sort(list, comp) end
 
----@version > 5.2, JIT
 --- Returns the elements from the given list. This function is equivalent to
 --- return `list[i]`, `list[i+1]`, `···`, `list[j]`
 --- By default, i is 1 and j is #list.
+---@version > 5.2, JIT
 ---@generic T
 ---@param i? number
 ---@param j? number
@@ -116,21 +117,21 @@ function table.sort(list, comp) end
 ---@return T...
 function table.unpack(list, i, j) end
 
----@version > 5.2, JIT
 ---
 ---Returns a new table with all arguments stored into keys `1`, `2`, etc. and with a field `"n"` with the total number of arguments.
 ---
+---@version > 5.2, JIT
 ---@generic T
 ---@param ... T...
 ---@return [T...] | { n: integer }
 ---@nodiscard
 function table.pack(...) end
 
----@version 5.1, JIT
 ---
 ---Executes the given f over all elements of table. For each element, f is called with the index and respective value as arguments. If f returns a non-nil value, then the loop is broken, and this value is returned as the final value of foreach.
 ---
 ---
+---@version 5.1, JIT
 ---@generic T
 ---@param list any
 ---@param callback fun(key: string, value: any):T|nil
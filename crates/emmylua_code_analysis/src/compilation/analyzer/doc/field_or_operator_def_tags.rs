@@ -144,6 +144,13 @@ pub fn analyze_field(analyzer: &mut DocAnalyzer, tag: LuaDocTagField) -> Option<
         );
     }
 
+    if tag.is_readonly() {
+        analyzer
+            .db
+            .get_property_index_mut()
+            .add_readonly(analyzer.file_id, property_owner.clone());
+    }
+
     if let Some(description) = description {
         analyzer.db.get_property_index_mut().add_description(
             analyzer.file_id,
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use rowan::{TextRange, TextSize};
 
-use crate::GenericTplId;
+use crate::{GenericTplId, LuaType};
 
 #[derive(Debug, Clone)]
 pub struct FileGenericIndex {
@@ -25,10 +25,21 @@ impl FileGenericIndex {
         ranges: Vec<TextRange>,
         params: HashMap<String, usize>,
         is_func: bool,
+    ) {
+        self.add_detailed_generic_scope(ranges, params, HashMap::new(), is_func);
+    }
+
+    /// `details` holds the declared extras of params, like `T...` or `T: Bound`
+    pub fn add_detailed_generic_scope(
+        &mut self,
+        ranges: Vec<TextRange>,
+        params: HashMap<String, usize>,
+        details: HashMap<String, GenericParamDetail>,
+        is_func: bool,
     ) {
         let params_id = self.generic_params.len();
         self.generic_params
-            .push(GenericParams::new(params, is_func));
+            .push(GenericParams::new(params, details, is_func));
         let params_id = GenericParamId::new(params_id);
         let root_node_ids: Vec<_> = self.root_node_ids.clone();
         for range in ranges {
@@ -53,6 +64,23 @@ impl FileGenericIndex {
         }
     }
 
+    /// the key param of a mapped type takes the next free type tpl id of its enclosing scopes
+    pub fn add_mapped_key_scope(&mut self, range: TextRange, name: &str) -> GenericTplId {
+        let count: usize = self
+            .find_generic_params(range.start())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|params_id| self.generic_params.get(*params_id))
+            .filter(|params| !params.is_func)
+            .map(|params| params.params.len())
+            .sum();
+
+        let mut params = HashMap::new();
+        params.insert(name.to_string(), count);
+        self.add_generic_scope(vec![range], params, false);
+        GenericTplId::Type(count as u32)
+    }
+
     fn try_add_range_to_effect_node(
         &mut self,
         range: TextRange,
@@ -87,16 +115,21 @@ impl FileGenericIndex {
         false
     }
 
-    pub fn find_generic(&self, position: TextSize, name: &str) -> Option<GenericTplId> {
+    pub fn find_generic(
+        &self,
+        position: TextSize,
+        name: &str,
+    ) -> Option<(GenericTplId, GenericParamDetail)> {
         let params_ids = self.find_generic_params(position)?;
 
         for params_id in params_ids.iter().rev() {
             if let Some(params) = self.generic_params.get(*params_id) {
                 if let Some(id) = params.params.get(name) {
+                    let detail = params.details.get(name).cloned().unwrap_or_default();
                     if params.is_func {
-                        return Some(GenericTplId::Func(*id as u32));
+                        return Some((GenericTplId::Func(*id as u32), detail));
                     } else {
-                        return Some(GenericTplId::Type(*id as u32));
+                        return Some((GenericTplId::Type(*id as u32), detail));
                     }
                 }
             }
@@ -173,11 +206,28 @@ impl GenericEffectId {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenericParams {
     params: HashMap<String, usize>,
+    details: HashMap<String, GenericParamDetail>,
     is_func: bool,
 }
 
 impl GenericParams {
-    pub fn new(params: HashMap<String, usize>, is_func: bool) -> Self {
-        Self { params, is_func }
+    pub fn new(
+        params: HashMap<String, usize>,
+        details: HashMap<String, GenericParamDetail>,
+        is_func: bool,
+    ) -> Self {
+        Self {
+            params,
+            details,
+            is_func,
+        }
     }
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenericParamDetail {
+    /// declared as `T...`, it stands for a whole type tuple
+    pub is_variadic: bool,
+    /// declared as `T: Bound`
+    pub constraint: Option<LuaType>,
+}
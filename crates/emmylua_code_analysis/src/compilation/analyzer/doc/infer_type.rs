@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaDocBinaryType, LuaDocFuncType, LuaDocGenericType,
+    LuaAst, LuaAstNode, LuaDocBinaryType, LuaDocFuncType, LuaDocGenericType, LuaDocMappedType,
     LuaDocMultiLineUnionType, LuaDocObjectFieldKey, LuaDocObjectType, LuaDocStrTplType, LuaDocType,
     LuaDocUnaryType, LuaDocVariadicType, LuaLiteralToken, LuaSyntaxKind, LuaTypeBinaryOperator,
     LuaTypeUnaryOperator, LuaVarExpr,
@@ -107,6 +107,22 @@ pub fn infer_type(analyzer: &mut DocAnalyzer, node: LuaDocType) -> LuaType {
         LuaDocType::MultiLineUnion(multi_union) => {
             return infer_multi_line_union_type(analyzer, multi_union);
         }
+        LuaDocType::IndexAccess(index_access) => {
+            if let Some((base, index)) = index_access.get_types() {
+                let base_type = infer_type(analyzer, base);
+                let index_type = infer_type(analyzer, index);
+                if base_type.is_unknown() || index_type.is_unknown() {
+                    return LuaType::Unknown;
+                }
+                return LuaType::Call(
+                    LuaAliasCallType::new(LuaAliasCallKind::Index, vec![base_type, index_type])
+                        .into(),
+                );
+            }
+        }
+        LuaDocType::Mapped(mapped_type) => {
+            return infer_mapped_type(analyzer, mapped_type).unwrap_or(LuaType::Unknown);
+        }
         _ => {} // LuaDocType::Conditional(lua_doc_conditional_type) => todo!(),
     }
     LuaType::Unknown
@@ -130,11 +146,17 @@ fn infer_buildin_or_ref_type(analyzer: &mut DocAnalyzer, name: &str, range: Text
         "global" => LuaType::Global,
         "function" => LuaType::Function,
         _ => {
-            if let Some(tpl_id) = analyzer.generic_index.find_generic(position, name) {
-                return LuaType::TplRef(Arc::new(GenericTpl::new(
+            if let Some((tpl_id, detail)) = analyzer.generic_index.find_generic(position, name) {
+                let tpl = LuaType::TplRef(Arc::new(GenericTpl::new(
                     tpl_id,
                     SmolStr::new(name).into(),
+                    detail.constraint,
                 )));
+                // a `T...` generic always stands for the whole tuple
+                if detail.is_variadic {
+                    return LuaType::Variadic(tpl.into());
+                }
+                return tpl;
             }
 
             if let Some(name_type_decl) = analyzer
@@ -228,6 +250,30 @@ fn infer_special_generic_type(
             ));
         }
         "std.Unpack" => {}
+        "std.ReturnType" | "std.Parameters" => {
+            let first_doc_param_type = generic_type.get_generic_types()?.get_types().next()?;
+            let first_param = infer_type(analyzer, first_doc_param_type);
+            let call_kind = if name == "std.ReturnType" {
+                LuaAliasCallKind::ReturnType
+            } else {
+                LuaAliasCallKind::Parameters
+            };
+            return Some(LuaType::Call(
+                LuaAliasCallType::new(call_kind, vec![first_param]).into(),
+            ));
+        }
+        "std.NonNil" | "std.Readonly" => {
+            let first_doc_param_type = generic_type.get_generic_types()?.get_types().next()?;
+            let first_param = infer_type(analyzer, first_doc_param_type);
+            let call_kind = if name == "std.NonNil" {
+                LuaAliasCallKind::RemoveNil
+            } else {
+                LuaAliasCallKind::Readonly
+            };
+            return Some(LuaType::Call(
+                LuaAliasCallType::new(call_kind, vec![first_param]).into(),
+            ));
+        }
         _ => {}
     }
 
@@ -459,6 +505,29 @@ fn infer_object_type(analyzer: &mut DocAnalyzer, object_type: LuaDocObjectType)
     LuaType::Object(LuaObjectType::new(fields).into())
 }
 
+// { [K in <keys>]: <value> }
+fn infer_mapped_type(analyzer: &mut DocAnalyzer, mapped_type: LuaDocMappedType) -> Option<LuaType> {
+    let mapped_keys = mapped_type.get_mapped_keys()?;
+    let param_name = mapped_keys.get_param()?.get_name_text()?;
+    let keys_type = infer_type(analyzer, mapped_keys.get_keys_type()?);
+    if keys_type.is_unknown() {
+        return None;
+    }
+
+    let tpl_id = analyzer
+        .generic_index
+        .add_mapped_key_scope(mapped_type.get_range(), &param_name);
+    let param = LuaType::TplRef(GenericTpl::new(tpl_id, SmolStr::new(&param_name).into(), None).into());
+    let mut value_type = infer_type(analyzer, mapped_type.get_value_type()?);
+    if mapped_keys.is_optional() && !value_type.is_nullable() {
+        value_type = LuaType::Nullable(value_type.into());
+    }
+
+    Some(LuaType::Call(
+        LuaAliasCallType::new(LuaAliasCallKind::Mapped, vec![param, keys_type, value_type]).into(),
+    ))
+}
+
 fn infer_str_tpl(analyzer: &mut DocAnalyzer, str_tpl: LuaDocStrTplType) -> LuaType {
     let prefix = match str_tpl.get_prefix() {
         Some(prefix) => prefix,
@@ -488,6 +557,9 @@ fn infer_variadic_type(
     let name_type = variadic_type.get_name_type()?;
     let name = name_type.get_name_text()?;
     let base = infer_buildin_or_ref_type(analyzer, &name, name_type.get_range());
+    if let LuaType::Variadic(_) = &base {
+        return Some(base);
+    }
 
     Some(LuaType::Variadic(base.into()))
 }
@@ -1,7 +1,9 @@
-use super::{tags::get_owner_id, DocAnalyzer};
+use super::{infer_type::infer_type, tags::get_owner_id, DocAnalyzer};
+use crate::db_index::{LuaMemberId, LuaMemberKey, LuaPropertyOwnerId};
 use emmylua_parser::{
-    LuaDocDescriptionOwner, LuaDocTagDeprecated, LuaDocTagSource, LuaDocTagVersion,
-    LuaDocTagVisibility,
+    LuaAst, LuaAstNode, LuaDocDescriptionOwner, LuaDocTagAuthor, LuaDocTagDeprecated,
+    LuaDocTagExample, LuaDocTagMapping, LuaDocTagSee, LuaDocTagSince, LuaDocTagSource,
+    LuaDocTagThrows, LuaDocTagVersion, LuaDocTagVisibility, LuaVarExpr,
 };
 
 pub fn analyze_visibility(
@@ -87,3 +89,155 @@ pub fn analyze_async(analyzer: &mut DocAnalyzer) -> Option<()> {
 
     Some(())
 }
+
+pub fn analyze_readonly(analyzer: &mut DocAnalyzer) -> Option<()> {
+    let owner_id = get_owner_id(analyzer)?;
+
+    analyzer
+        .db
+        .get_property_index_mut()
+        .add_readonly(analyzer.file_id, owner_id);
+
+    Some(())
+}
+
+// ---@see Class#member description
+pub fn analyze_see(analyzer: &mut DocAnalyzer, tag: LuaDocTagSee) -> Option<()> {
+    let target = match tag.get_url_token() {
+        Some(url_token) => url_token.get_path().to_string(),
+        None => tag
+            .get_names()
+            .map(|name| name.get_name_text().to_string())
+            .collect::<Vec<_>>()
+            .join("."),
+    };
+    if target.is_empty() {
+        return None;
+    }
+    let description = tag
+        .get_description()
+        .map(|desc| desc.get_description_text().trim().to_string())
+        .unwrap_or_default();
+    let description = Some(description).filter(|desc| !desc.is_empty());
+    let owner_id = get_owner_id(analyzer)?;
+
+    analyzer
+        .db
+        .get_property_index_mut()
+        .add_see(analyzer.file_id, owner_id, target, description);
+
+    Some(())
+}
+
+// ---@example followed by the example code on the next lines
+pub fn analyze_example(analyzer: &mut DocAnalyzer, tag: LuaDocTagExample) -> Option<()> {
+    let text = tag.get_description()?.get_description_text();
+    let example = dedent(&text);
+    if example.is_empty() {
+        return None;
+    }
+    let owner_id = get_owner_id(analyzer)?;
+
+    analyzer
+        .db
+        .get_property_index_mut()
+        .add_example(analyzer.file_id, owner_id, example);
+
+    Some(())
+}
+
+fn dedent(text: &str) -> String {
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    text.lines()
+        .map(|line| line.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+// ---@throws <type> [description]
+pub fn analyze_throws(analyzer: &mut DocAnalyzer, tag: LuaDocTagThrows) -> Option<()> {
+    let typ = infer_type(analyzer, tag.get_type()?);
+    let description = tag
+        .get_description()
+        .map(|desc| desc.get_description_text().trim().to_string())
+        .filter(|desc| !desc.is_empty());
+    let owner_id = get_owner_id(analyzer)?;
+
+    analyzer
+        .db
+        .get_property_index_mut()
+        .add_throws(analyzer.file_id, owner_id, typ, description);
+
+    Some(())
+}
+
+// ---@since <version>
+pub fn analyze_since(analyzer: &mut DocAnalyzer, tag: LuaDocTagSince) -> Option<()> {
+    let since = tag
+        .get_description()?
+        .get_description_text()
+        .trim()
+        .to_string();
+    if since.is_empty() {
+        return None;
+    }
+    let owner_id = get_owner_id(analyzer)?;
+
+    analyzer
+        .db
+        .get_property_index_mut()
+        .add_since(analyzer.file_id, owner_id, since);
+
+    Some(())
+}
+
+// ---@author <name>
+pub fn analyze_author(analyzer: &mut DocAnalyzer, tag: LuaDocTagAuthor) -> Option<()> {
+    let author = tag
+        .get_description()?
+        .get_description_text()
+        .trim()
+        .to_string();
+    if author.is_empty() {
+        return None;
+    }
+    let owner_id = get_owner_id(analyzer)?;
+
+    analyzer
+        .db
+        .get_property_index_mut()
+        .add_author(analyzer.file_id, owner_id, author);
+
+    Some(())
+}
+
+// ---@mapping <new name>, the member is looked up by the new name
+pub fn analyze_mapping(analyzer: &mut DocAnalyzer, tag: LuaDocTagMapping) -> Option<()> {
+    let name = tag.get_name_token()?.get_name_text().to_string();
+    let member_id = match analyzer.comment.get_owner()? {
+        LuaAst::LuaFuncStat(func_stat) => match func_stat.get_func_name()? {
+            LuaVarExpr::IndexExpr(index_expr) => {
+                LuaMemberId::new(index_expr.get_syntax_id(), analyzer.file_id)
+            }
+            _ => return None,
+        },
+        _ => match get_owner_id(analyzer)? {
+            LuaPropertyOwnerId::Member(member_id) => member_id,
+            _ => return None,
+        },
+    };
+
+    analyzer
+        .db
+        .get_member_index_mut()
+        .set_member_key(&member_id, LuaMemberKey::Name(name.into()));
+
+    Some(())
+}
@@ -11,8 +11,9 @@ use super::{
     diagnostic_tags::analyze_diagnostic,
     field_or_operator_def_tags::{analyze_field, analyze_operator},
     property_tags::{
-        analyze_async, analyze_deprecated, analyze_nodiscard, analyze_source, analyze_version,
-        analyze_visibility,
+        analyze_async, analyze_author, analyze_deprecated, analyze_example, analyze_mapping,
+        analyze_nodiscard, analyze_readonly, analyze_see, analyze_since, analyze_source,
+        analyze_throws, analyze_version, analyze_visibility,
     },
     type_def_tags::{analyze_alias, analyze_class, analyze_enum, analyze_func_generic},
     type_ref_tags::{
@@ -74,6 +75,27 @@ pub fn analyze_tag(analyzer: &mut DocAnalyzer, tag: LuaDocTag) -> Option<()> {
         LuaDocTag::Async(_) => {
             analyze_async(analyzer)?;
         }
+        LuaDocTag::Readonly(_) => {
+            analyze_readonly(analyzer)?;
+        }
+        LuaDocTag::Mapping(mapping) => {
+            analyze_mapping(analyzer, mapping)?;
+        }
+        LuaDocTag::See(see) => {
+            analyze_see(analyzer, see)?;
+        }
+        LuaDocTag::Example(example) => {
+            analyze_example(analyzer, example)?;
+        }
+        LuaDocTag::Throws(throws) => {
+            analyze_throws(analyzer, throws)?;
+        }
+        LuaDocTag::Since(since) => {
+            analyze_since(analyzer, since)?;
+        }
+        LuaDocTag::Author(author) => {
+            analyze_author(analyzer, author)?;
+        }
 
         // field or operator
         LuaDocTag::Field(filed) => {
@@ -16,7 +16,8 @@ use crate::{
 };
 
 use super::{
-    infer_type::infer_type, preprocess_description, tags::find_owner_closure, DocAnalyzer,
+    file_generic_index::GenericParamDetail, infer_type::infer_type, preprocess_description,
+    tags::find_owner_closure, DocAnalyzer,
 };
 
 pub fn analyze_class(analyzer: &mut DocAnalyzer, tag: LuaDocTagClass) -> Option<()> {
@@ -339,6 +340,7 @@ pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -
         }
 
         let mut params_result = HashMap::new();
+        let mut details = HashMap::new();
         let mut param_info = Vec::new();
         if let Some(params_list) = tag.get_generic_decl_list() {
             let mut count = 0;
@@ -355,6 +357,13 @@ pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -
                     None
                 };
 
+                details.insert(
+                    name.clone(),
+                    GenericParamDetail {
+                        is_variadic: param.is_variadic(),
+                        constraint: type_ref.clone(),
+                    },
+                );
                 params_result.insert(name.clone(), count);
                 param_info.push((name, type_ref));
                 count += 1;
@@ -368,7 +377,7 @@ pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -
         ranges.push(range);
         analyzer
             .generic_index
-            .add_generic_scope(ranges, params_result, true);
+            .add_detailed_generic_scope(ranges, params_result, details, true);
 
         let closure = find_owner_closure(analyzer)?;
         let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
@@ -73,6 +73,16 @@ fn analyze_return(
         return None;
     }
 
+    // `---@meta` files only declare apis, their stub bodies say nothing about the returns
+    if analyzer.db.get_meta_file().is_meta_file(&analyzer.file_id) {
+        let signature = analyzer
+            .db
+            .get_signature_index_mut()
+            .get_or_create(*signature_id);
+        signature.resolve_return = true;
+        return Some(());
+    }
+
     let block = closure.get_block()?;
     let return_points = analyze_func_body_returns(block);
     let returns = match analyze_return_point(analyzer, &return_points) {
@@ -0,0 +1,86 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaIndexKey};
+use rowan::TextRange;
+
+use crate::db_index::{
+    LuaMemberId, LuaOperator, LuaOperatorMetaMethod, LuaSignatureId, LuaType, LuaTypeDeclId,
+};
+
+use super::LuaAnalyzer;
+
+/// `function Vec.__add(a, b) end` or `Vec.__call = function(self) end` on a class table
+pub fn analyze_member_metamethod(
+    analyzer: &mut LuaAnalyzer,
+    member_id: LuaMemberId,
+    value_expr: LuaExpr,
+) -> Option<()> {
+    let member = analyzer.db.get_member_index().get_member(&member_id)?;
+    let type_id = member.get_owner().get_type_id()?.clone();
+    let op = LuaOperatorMetaMethod::from_metatable_field(member.get_key().get_name()?)?;
+    let range = member.get_range();
+    add_metamethod_operator(analyzer, type_id, op, value_expr, range)
+}
+
+/// `setmetatable(Vec, { __add = function(a, b) end })`
+pub fn analyze_setmetatable_call(analyzer: &mut LuaAnalyzer, call_expr: LuaCallExpr) -> Option<()> {
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    if name_expr.get_name_text()? != "setmetatable" {
+        return None;
+    }
+
+    let mut args = call_expr.get_args_list()?.get_args();
+    let table_expr = args.next()?;
+    let LuaExpr::TableExpr(metatable) = args.next()? else {
+        return None;
+    };
+
+    let type_id = match analyzer.infer_expr(&table_expr)? {
+        LuaType::Def(type_id) | LuaType::Ref(type_id) => type_id,
+        _ => return None,
+    };
+
+    for field in metatable.get_fields() {
+        let field_name = match field.get_field_key() {
+            Some(LuaIndexKey::Name(name)) => name.get_name_text().to_string(),
+            Some(LuaIndexKey::String(string)) => string.get_value(),
+            _ => continue,
+        };
+        let Some(op) = LuaOperatorMetaMethod::from_metatable_field(&field_name) else {
+            continue;
+        };
+        let Some(value_expr) = field.get_value_expr() else {
+            continue;
+        };
+        add_metamethod_operator(analyzer, type_id.clone(), op, value_expr, field.get_range());
+    }
+
+    Some(())
+}
+
+fn add_metamethod_operator(
+    analyzer: &mut LuaAnalyzer,
+    type_id: LuaTypeDeclId,
+    op: LuaOperatorMetaMethod,
+    value_expr: LuaExpr,
+    range: TextRange,
+) -> Option<()> {
+    let signature_id = get_metamethod_signature_id(analyzer, value_expr)?;
+    let operator = LuaOperator::new_metamethod(type_id, op, signature_id, analyzer.file_id, range);
+    analyzer.db.get_operator_index_mut().add_operator(operator);
+    Some(())
+}
+
+fn get_metamethod_signature_id(
+    analyzer: &mut LuaAnalyzer,
+    value_expr: LuaExpr,
+) -> Option<LuaSignatureId> {
+    if let LuaExpr::ClosureExpr(closure) = &value_expr {
+        return Some(LuaSignatureId::from_closure(analyzer.file_id, closure));
+    }
+
+    match analyzer.infer_expr(&value_expr)? {
+        LuaType::Signature(signature_id) => Some(signature_id),
+        _ => None,
+    }
+}
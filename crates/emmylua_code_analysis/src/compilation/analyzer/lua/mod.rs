@@ -1,11 +1,13 @@
 mod closure;
 mod func_body;
+mod metatable;
 mod module;
 mod stats;
 
 use closure::analyze_closure;
 use emmylua_parser::{LuaAst, LuaAstNode, LuaExpr};
 pub use func_body::LuaReturnPoint;
+use metatable::analyze_setmetatable_call;
 use module::analyze_chunk_return;
 use stats::{
     analyze_assign_stat, analyze_for_range_stat, analyze_func_stat, analyze_local_func_stat,
@@ -59,6 +61,9 @@ fn analyze_node(analyzer: &mut LuaAnalyzer, node: LuaAst) {
         LuaAst::LuaClosureExpr(closure) => {
             analyze_closure(analyzer, closure);
         }
+        LuaAst::LuaCallExpr(call_expr) => {
+            analyze_setmetatable_call(analyzer, call_expr);
+        }
         _ => {}
     }
 }
@@ -10,7 +10,7 @@ use crate::{
     db_index::{LuaDeclId, LuaMemberId, LuaMemberOwner, LuaType},
 };
 
-use super::LuaAnalyzer;
+use super::{metatable::analyze_member_metamethod, LuaAnalyzer};
 
 pub fn analyze_local_stat(analyzer: &mut LuaAnalyzer, local_stat: LuaLocalStat) -> Option<()> {
     let name_list: Vec<_> = local_stat.get_local_name_list().collect();
@@ -204,6 +204,10 @@ pub fn analyze_assign_stat(analyzer: &mut LuaAnalyzer, assign_stat: LuaAssignSta
             }
         };
 
+        if let TypeOwner::Member(member_id) = &type_owner {
+            analyze_member_metamethod(analyzer, *member_id, expr.clone());
+        }
+
         match special_assign_pattern(analyzer, type_owner.clone(), var.clone(), expr.clone()) {
             Some(_) => {
                 continue;
@@ -436,7 +440,7 @@ pub fn analyze_func_stat(analyzer: &mut LuaAnalyzer, func_stat: LuaFuncStat) ->
     let closure = func_stat.get_closure()?;
     let func_name = func_stat.get_func_name()?;
     let signature_type = analyzer.infer_expr(&closure.clone().into())?;
-    let type_owner = get_var_type_owner(analyzer, func_name, closure.into())?;
+    let type_owner = get_var_type_owner(analyzer, func_name, closure.clone().into())?;
     match type_owner {
         TypeOwner::Decl(decl_id) => {
             let decl = analyzer.db.get_decl_index_mut().get_decl_mut(&decl_id)?;
@@ -449,6 +453,7 @@ pub fn analyze_func_stat(analyzer: &mut LuaAnalyzer, func_stat: LuaFuncStat) ->
                 .get_member_mut(&member_id)?;
 
             member.decl_type = signature_type;
+            analyze_member_metamethod(analyzer, member_id, closure.into());
         }
     }
 
@@ -474,6 +479,7 @@ pub fn analyze_table_field(analyzer: &mut LuaAnalyzer, field: LuaTableField) ->
     let _ = field.get_field_key()?;
     let value_expr = field.get_value_expr()?;
     let member_id = LuaMemberId::new(field.get_syntax_id(), analyzer.file_id);
+    analyze_member_metamethod(analyzer, member_id, value_expr.clone());
     let value_type = match analyzer.infer_expr(&value_expr.clone().into()) {
         Some(value_type) => value_type,
         None => {
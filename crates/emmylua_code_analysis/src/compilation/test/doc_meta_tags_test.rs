@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        LuaMemberKey, LuaMemberOwner, LuaPropertyOwnerId, LuaType, LuaTypeDeclId, VirtualWorkspace,
+    };
+
+    #[test]
+    fn test_doc_meta_tags() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class IOError
+
+        ---@class File
+        local File = {}
+
+        --- Read the whole file.
+        ---@since 1.2.0
+        ---@author CppCXY
+        ---@throws IOError when the file is closed
+        ---@example
+        ---    local f = File.open("a.txt")
+        ---    if f then
+        ---        print(f:read())
+        ---    end
+        function File:read() end
+        "#,
+        );
+
+        let db = ws.analysis.compilation.get_db();
+        let member_map = db
+            .get_member_index()
+            .get_member_map(LuaMemberOwner::Type(LuaTypeDeclId::new("File")))
+            .unwrap();
+        let member_id = member_map.get(&LuaMemberKey::Name("read".into())).unwrap();
+        let property = db
+            .get_property_index()
+            .get_property(LuaPropertyOwnerId::Member(*member_id))
+            .unwrap();
+
+        assert_eq!(property.since.as_deref(), Some("1.2.0"));
+        assert_eq!(property.authors.as_ref(), Some(&vec!["CppCXY".to_string()]));
+        assert_eq!(
+            property.throws.as_ref(),
+            Some(&vec![(
+                LuaType::Ref(LuaTypeDeclId::new("IOError")),
+                Some("when the file is closed".to_string())
+            )])
+        );
+        assert_eq!(
+            property.examples.as_ref(),
+            Some(&vec![
+                "local f = File.open(\"a.txt\")\nif f then\n    print(f:read())\nend".to_string()
+            ])
+        );
+        assert_eq!(
+            property.description.as_deref().map(|s| s.as_str()),
+            Some("Read the whole file.")
+        );
+    }
+}
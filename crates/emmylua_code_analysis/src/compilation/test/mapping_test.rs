@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod test {
+    use crate::{LuaMemberKey, LuaType, VirtualWorkspace};
+
+    #[test]
+    fn test_mapping_member() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Mapping
+        local M = {}
+
+        ---@mapping get
+        ---@return string
+        function M.__get_impl() end
+
+        a = M.get()
+        "#,
+        );
+        let ty = ws.expr_ty("a");
+        assert_eq!(ty, LuaType::String);
+    }
+
+    #[test]
+    fn test_meta_declaration_only() {
+        let mut ws = VirtualWorkspace::new();
+
+        let code = r#"
+        ---@meta
+
+        function meta_stub()
+            return 1
+        end
+
+        b = meta_stub()
+        "#;
+        let file_id = ws.def(code);
+        let ty = ws.expr_ty("b");
+        assert_eq!(ty, LuaType::Nil);
+
+        let db = ws.analysis.compilation.get_db();
+        let decl_id = db
+            .get_decl_index()
+            .get_global_decl_id(&LuaMemberKey::Name("meta_stub".into()))
+            .unwrap();
+        let decl = db.get_decl_index().get_decl(&decl_id).unwrap();
+        assert_eq!(decl.get_file_id(), file_id);
+        assert_eq!(
+            u32::from(decl.get_position()) as usize,
+            code.find("meta_stub").unwrap()
+        );
+
+        let Some(LuaType::Signature(signature_id)) = decl.get_type() else {
+            panic!("meta_stub is not a function");
+        };
+        let signature = db.get_signature_index().get(signature_id).unwrap();
+        assert!(signature.is_resolve_return());
+        assert!(signature.get_return_types().is_empty());
+    }
+}
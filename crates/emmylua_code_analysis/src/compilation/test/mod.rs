@@ -1,6 +1,9 @@
 mod closure_param_infer_test;
 mod closure_return_test;
+mod doc_meta_tags_test;
 mod flow;
+mod mapping_test;
 mod multi_return;
 mod out_of_order;
 mod overload_field;
+mod see_test;
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod test {
+    use crate::{LuaMemberKey, LuaPropertyOwnerId, VirtualWorkspace};
+
+    #[test]
+    fn test_see_property() {
+        let mut ws = VirtualWorkspace::new();
+
+        let code = r#"
+        ---@class Player
+        local Player = {}
+
+        ---@see Player#heal restores health
+        ---@see https://example.com/combat
+        function Player:takeDamage(amount) end
+
+        function Player:heal(amount) end
+        "#;
+        ws.def(code);
+
+        let db = ws.analysis.compilation.get_db();
+        let member_map = db
+            .get_member_index()
+            .get_member_map(crate::LuaMemberOwner::Type(crate::LuaTypeDeclId::new(
+                "Player",
+            )))
+            .unwrap();
+        let member_id = member_map
+            .get(&LuaMemberKey::Name("takeDamage".into()))
+            .unwrap();
+        let property = db
+            .get_property_index()
+            .get_property(LuaPropertyOwnerId::Member(*member_id))
+            .unwrap();
+        let see = property.see.as_ref().unwrap();
+        assert_eq!(
+            see[0],
+            (
+                "Player.heal".to_string(),
+                Some("restores health".to_string())
+            )
+        );
+        assert_eq!(see.len(), 2);
+        assert_eq!(see[1], ("https://example.com/combat".to_string(), None));
+
+        // the target names the `heal` method of the class
+        let (owner_name, member_name) = see[0].0.rsplit_once('.').unwrap();
+        let owner_type = db
+            .get_type_index()
+            .get_type_decl(&crate::LuaTypeDeclId::new(owner_name))
+            .unwrap();
+        let heal_id = db
+            .get_member_index()
+            .get_member_map(crate::LuaMemberOwner::Type(owner_type.get_id()))
+            .unwrap()
+            .get(&LuaMemberKey::Name(member_name.into()))
+            .unwrap();
+        let heal = db.get_member_index().get_member(heal_id).unwrap();
+        assert_eq!(
+            u32::from(heal.get_range().start()) as usize,
+            code.find("Player:heal").unwrap()
+        );
+    }
+}
@@ -75,7 +75,7 @@ pub fn load_configs(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<Valu
     }
 }
 
-fn merge_values(base: &mut Value, overlay: Value) {
+pub(super) fn merge_values(base: &mut Value, overlay: Value) {
     match (base, overlay) {
         (Value::Object(base_map), Value::Object(overlay_map)) => {
             for (key, overlay_value) in overlay_map {
@@ -1,22 +1,42 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct EmmyrcCodeLen {
     /// Whether to enable code lens.
     #[serde(default = "default_true")]
     pub enable: bool,
+    /// Whether to show subclass and override lenses on classes and methods.
+    #[serde(default = "default_true")]
+    pub implementation: bool,
+    /// Whether to show "Run test" and "Debug test" lenses on busted and luaunit tests.
+    #[serde(default = "default_false")]
+    pub test_enable: bool,
+    /// Functions whose calls declare busted tests, e.g. `describe` and `it`.
+    #[serde(default = "default_test_functions")]
+    pub test_functions: Vec<String>,
 }
 
 impl Default for EmmyrcCodeLen {
     fn default() -> Self {
         Self {
             enable: default_true(),
+            implementation: default_true(),
+            test_enable: default_false(),
+            test_functions: default_test_functions(),
         }
     }
 }
 
 fn default_true() -> bool {
     true
-}
\ No newline at end of file
+}
+
+fn default_false() -> bool {
+    false
+}
+
+fn default_test_functions() -> Vec<String> {
+    vec!["describe".to_string(), "it".to_string()]
+}
@@ -1,7 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct EmmyrcInlayHint {
     /// Whether to enable inlay hints.
@@ -20,6 +20,21 @@ pub struct EmmyrcInlayHint {
     /// Whether to enable override hints.
     #[serde(default = "default_true")]
     pub override_hint: bool,
+    /// Whether to show the inferred return types of functions without `---@return`.
+    #[serde(default = "default_false")]
+    pub return_hint: bool,
+    /// Whether to show the intermediate types when a method chain spans multiple lines.
+    #[serde(default = "default_false")]
+    pub chain_hint: bool,
+    /// Whether to show which block an `end` keyword closes.
+    #[serde(default = "default_false")]
+    pub end_hint: bool,
+    /// The minimum number of lines a block needs before its `end` gets a hint.
+    #[serde(default = "default_end_hint_min_lines")]
+    pub end_hint_min_lines: usize,
+    /// Whether to show the values of enum fields and `<const>` locals where they are referenced.
+    #[serde(default = "default_false")]
+    pub value_hint: bool,
 }
 
 impl Default for EmmyrcInlayHint {
@@ -30,6 +45,11 @@ impl Default for EmmyrcInlayHint {
             index_hint: default_true(),
             local_hint: default_true(),
             override_hint: default_true(),
+            return_hint: default_false(),
+            chain_hint: default_false(),
+            end_hint: default_false(),
+            end_hint_min_lines: default_end_hint_min_lines(),
+            value_hint: default_false(),
         }
     }
 }
@@ -37,3 +57,11 @@ impl Default for EmmyrcInlayHint {
 fn default_true() -> bool {
     true
 }
+
+fn default_false() -> bool {
+    false
+}
+
+fn default_end_hint_min_lines() -> usize {
+    20
+}
@@ -2,7 +2,7 @@ use emmylua_parser::LuaVersionNumber;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct EmmyrcRuntime {
     /// Lua version.
@@ -12,7 +12,8 @@ pub struct EmmyrcRuntime {
     /// Functions that like require.
     pub require_like_function: Vec<String>,
     #[serde(default)]
-    /// Framework versions.
+    /// Framework versions. Bundled definitions are loaded for love2d, neovim, openresty, defold,
+    /// roblox and busted. eg: ["love2d 11.4"]
     pub framework_versions: Vec<String>,
     #[serde(default)]
     /// file Extensions. eg: .lua, .lua.txt
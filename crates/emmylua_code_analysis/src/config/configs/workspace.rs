@@ -1,7 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct EmmyrcWorkspace {
     /// Ignore directories. 
@@ -14,6 +14,10 @@ pub struct EmmyrcWorkspace {
     /// Library paths. eg: "/usr/local/share/lua/5.1"
     pub library: Vec<String>,
     #[serde(default)]
+    /// Directories with LuaLS addons, an addon is enabled when its trigger words or files
+    /// match the workspace. eg: ["~/.local/share/luals-addons"]
+    pub user_third_party: Vec<String>,
+    #[serde(default)]
     /// Workspace roots. eg: ["src", "test"]
     pub workspace_roots: Vec<String>,
     // unused
@@ -37,6 +41,7 @@ impl Default for EmmyrcWorkspace {
             ignore_dir: Vec::new(),
             ignore_globs: Vec::new(),
             library: Vec::new(),
+            user_third_party: Vec::new(),
             workspace_roots: Vec::new(),
             preload_file_size: 0,
             encoding: encoding_default(),
@@ -45,7 +50,7 @@ impl Default for EmmyrcWorkspace {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 pub struct EmmyrcWorkspaceModuleMap {
     pub pattern: String,
     pub replace: String,
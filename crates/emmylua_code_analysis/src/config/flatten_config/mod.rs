@@ -13,6 +13,7 @@ impl FlattenConfigObject {
     pub fn parse(luals_json: Value) -> Self {
         let mut config = HashMap::new();
         flatten_object("", &luals_json, &mut config);
+        map_luals_keys(&mut config);
         Self {
             config
         }
@@ -24,9 +25,12 @@ impl FlattenConfigObject {
 }
 
 
+// luals keys whose object values are maps with arbitrary keys, eg paths containing dots
+const LUALS_MAP_KEYS: [&str; 2] = ["workspace.library", "runtime.special"];
+
 fn flatten_object(prefix: &str, val: &Value, config: &mut HashMap<String, Value>) {
     match val {
-        Value::Object(map) => {
+        Value::Object(map) if !LUALS_MAP_KEYS.contains(&normalize_key(prefix)) => {
             for (k, v) in map.iter() {
                 let new_key = if prefix.is_empty() {
                     k.to_owned()
@@ -37,7 +41,54 @@ fn flatten_object(prefix: &str, val: &Value, config: &mut HashMap<String, Value>
             }
         }
         _ => {
-            config.insert(prefix.to_string(), val.clone());
+            config.insert(normalize_key(prefix).to_string(), val.clone());
+        }
+    }
+}
+
+// vscode settings and luals addons prefix every key with `Lua.`
+fn normalize_key(key: &str) -> &str {
+    key.strip_prefix("Lua.").unwrap_or(key)
+}
+
+// map luals only keys to their emmyrc equivalent
+fn map_luals_keys(config: &mut HashMap<String, Value>) {
+    if let Some(Value::Object(library)) = config.get("workspace.library") {
+        // old luals format: { "path": true }
+        let paths = library
+            .iter()
+            .filter(|(_, enable)| enable.as_bool().unwrap_or(false))
+            .map(|(path, _)| Value::String(path.clone()))
+            .collect();
+        config.insert("workspace.library".to_string(), Value::Array(paths));
+    }
+
+    if let Some(Value::Array(paths)) = config.remove("runtime.path") {
+        extend_array(config, "runtime.requirePattern", paths);
+    }
+
+    if let Some(Value::Object(special)) = config.remove("runtime.special") {
+        // { "import": "require" }, other special functions have no emmyrc equivalent
+        let require_like = special
+            .into_iter()
+            .filter(|(_, kind)| kind.as_str() == Some("require"))
+            .map(|(name, _)| Value::String(name))
+            .collect();
+        extend_array(config, "runtime.requireLikeFunction", require_like);
+    }
+}
+
+fn extend_array(config: &mut HashMap<String, Value>, key: &str, values: Vec<Value>) {
+    match config.get_mut(key) {
+        Some(Value::Array(array)) => {
+            for value in values {
+                if !array.contains(&value) {
+                    array.push(value);
+                }
+            }
+        }
+        _ => {
+            config.insert(key.to_string(), Value::Array(values));
         }
     }
 }
@@ -33,4 +33,29 @@ mod test {
 }"#;
         assert_eq!(json_str, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_luals_keys() {
+        let luals_json = serde_json::json!({
+            "Lua.diagnostics.globals": ["vim"],
+            "runtime.path": ["?.lua", "?/init.lua"],
+            "runtime.special": {
+                "import": "require",
+                "myassert": "assert"
+            },
+            "workspace.library": {
+                "/usr/share/lua/5.1": true,
+                "/opt/lua.d": true,
+                "/disabled": false
+            }
+        });
+        let config = FlattenConfigObject::parse(luals_json);
+        let emmyrc: crate::Emmyrc = serde_json::from_value(config.to_emmyrc()).unwrap();
+        assert_eq!(emmyrc.diagnostics.globals, vec!["vim"]);
+        assert_eq!(emmyrc.runtime.require_pattern, vec!["?.lua", "?/init.lua"]);
+        assert_eq!(emmyrc.runtime.require_like_function, vec!["import"]);
+        let mut library = emmyrc.workspace.library.clone();
+        library.sort();
+        assert_eq!(library, vec!["/opt/lua.d", "/usr/share/lua/5.1"]);
+    }
+}
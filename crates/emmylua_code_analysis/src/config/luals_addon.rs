@@ -0,0 +1,408 @@
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use serde_json::Value;
+
+use super::{config_loader::merge_values, flatten_config::FlattenConfigObject, Emmyrc};
+use crate::LuaFileInfo;
+
+/// A LuaLS addon: a `config.json` with trigger `words`/`files` and `settings`, next to a
+/// `library/` folder of definition files.
+#[derive(Debug)]
+pub struct LuaLsAddon {
+    pub name: String,
+    pub library: PathBuf,
+    words: Vec<Regex>,
+    files: Vec<Regex>,
+    settings: Value,
+}
+
+impl LuaLsAddon {
+    /// addons installed by the LuaLS addon manager keep their content in `module/`
+    pub fn load(root: &Path) -> Option<Self> {
+        let root = if root.join("config.json").is_file() {
+            root.to_path_buf()
+        } else {
+            root.join("module")
+        };
+        let config_path = root.join("config.json");
+        let library = root.join("library");
+        if !config_path.is_file() || !library.is_dir() {
+            return None;
+        }
+
+        let config_json = match std::fs::read_to_string(&config_path) {
+            Ok(json_str) => json_str,
+            Err(e) => {
+                log::error!(
+                    "Failed to read addon config: {:?}, error: {:?}",
+                    config_path,
+                    e
+                );
+                return None;
+            }
+        };
+        let config: Value = match serde_json::from_str(&config_json) {
+            Ok(json) => json,
+            Err(e) => {
+                log::error!(
+                    "Failed to parse addon config: {:?}, error: {:?}",
+                    config_path,
+                    e
+                );
+                return None;
+            }
+        };
+
+        let name = config["name"]
+            .as_str()
+            .map(|name| name.to_string())
+            .or_else(|| Some(root.file_name()?.to_string_lossy().to_string()))?;
+        Some(Self {
+            name,
+            library,
+            words: parse_patterns(&config["words"]),
+            files: parse_patterns(&config["files"]),
+            settings: config["settings"].clone(),
+        })
+    }
+
+    /// `words` match the file content, `files` match the file path
+    pub fn is_match(&self, file: &LuaFileInfo) -> bool {
+        let path = file.path.replace('\\', "/");
+        self.files.iter().any(|pattern| pattern.is_match(&path))
+            || self
+                .words
+                .iter()
+                .any(|pattern| pattern.is_match(&file.content))
+    }
+}
+
+fn parse_patterns(value: &Value) -> Vec<Regex> {
+    let Some(patterns) = value.as_array() else {
+        return Vec::new();
+    };
+
+    patterns
+        .iter()
+        .filter_map(|pattern| {
+            let pattern = pattern.as_str()?;
+            let regex = lua_pattern_to_regex(pattern);
+            if regex.is_none() {
+                log::warn!("Unsupported addon pattern: {:?}", pattern);
+            }
+            regex
+        })
+        .collect()
+}
+
+/// `%b` and `%f` have no regex equivalent
+fn lua_pattern_to_regex(pattern: &str) -> Option<Regex> {
+    let mut regex = String::from("(?s)");
+    let mut chars = pattern.chars().peekable();
+    let mut in_set = false;
+    let mut first = true;
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => {
+                let class = chars.next()?;
+                match lua_class_to_regex(class) {
+                    Some(class) => regex.push_str(&class),
+                    None if class.is_ascii_alphanumeric() => return None,
+                    None => regex.push_str(&regex::escape(&class.to_string())),
+                }
+            }
+            '[' if !in_set => {
+                in_set = true;
+                regex.push('[');
+                if chars.peek() == Some(&'^') {
+                    regex.push(chars.next()?);
+                }
+            }
+            ']' if in_set => {
+                in_set = false;
+                regex.push(']');
+            }
+            '-' if in_set => regex.push('-'),
+            _ if in_set => regex.push_str(&regex::escape(&c.to_string())),
+            '^' if first => regex.push('^'),
+            '$' if chars.peek().is_none() => regex.push('$'),
+            '.' => regex.push('.'),
+            '*' | '+' | '?' => regex.push(c),
+            '-' => regex.push_str("*?"),
+            _ => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        first = false;
+    }
+
+    Regex::new(&regex).ok()
+}
+
+/// every class is a bracket expression, so it also works nested inside `[...]`
+fn lua_class_to_regex(class: char) -> Option<String> {
+    let content = match class.to_ascii_lowercase() {
+        'a' => "A-Za-z",
+        'c' => "[:cntrl:]",
+        'd' => "0-9",
+        'l' => "a-z",
+        'p' => "[:punct:]",
+        's' => r"\s",
+        'u' => "A-Z",
+        'w' => "A-Za-z0-9",
+        'x' => "0-9A-Fa-f",
+        _ => return None,
+    };
+    if class.is_ascii_uppercase() {
+        Some(format!("[^{}]", content))
+    } else {
+        Some(format!("[{}]", content))
+    }
+}
+
+impl Emmyrc {
+    /// addons in the `workspace.userThirdParty` directories that are not enabled yet
+    pub fn find_luals_addons(&self) -> Vec<LuaLsAddon> {
+        let mut addons = Vec::new();
+        for dir in &self.workspace.user_third_party {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                log::warn!("Failed to read third party directory: {:?}", dir);
+                continue;
+            };
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let Some(addon) = LuaLsAddon::load(&entry.path()) else {
+                    continue;
+                };
+                let library = addon.library.to_string_lossy();
+                if !self.workspace.library.iter().any(|lib| *lib == library) {
+                    addons.push(addon);
+                }
+            }
+        }
+
+        addons
+    }
+
+    /// addons whose trigger words or files match any of the workspace files
+    pub fn detect_luals_addons(&self, files: &[LuaFileInfo]) -> Vec<LuaLsAddon> {
+        self.find_luals_addons()
+            .into_iter()
+            .filter(|addon| files.iter().any(|file| addon.is_match(file)))
+            .collect()
+    }
+
+    /// apply the addon settings under this config, so the values the user set explicitly win,
+    /// and add the addon libraries
+    pub fn with_luals_addons(&self, addons: &[LuaLsAddon]) -> Emmyrc {
+        let (mut emmyrc_json, user_json) = match (
+            serde_json::to_value(Emmyrc::default()),
+            serde_json::to_value(self),
+        ) {
+            (Ok(default_json), Ok(user_json)) => {
+                let user_json = diff_values(user_json, &default_json);
+                (default_json, user_json)
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                log::error!("Failed to serialize config, error: {:?}", e);
+                return self.clone();
+            }
+        };
+        for addon in addons {
+            log::info!("enable addon: {} {:?}", addon.name, addon.library);
+            if addon.settings.is_object() {
+                let settings = FlattenConfigObject::parse(addon.settings.clone()).to_emmyrc();
+                merge_values(&mut emmyrc_json, settings);
+            }
+        }
+        if let Some(user_json) = user_json {
+            merge_values(&mut emmyrc_json, user_json);
+        }
+
+        let mut emmyrc: Emmyrc = match serde_json::from_value(emmyrc_json) {
+            Ok(config) => config,
+            Err(e) => {
+                log::error!("Failed to apply addon settings, error: {:?}", e);
+                return self.clone();
+            }
+        };
+        for addon in addons {
+            let library = addon.library.to_string_lossy().to_string();
+            if !emmyrc.workspace.library.contains(&library) {
+                emmyrc.workspace.library.push(library);
+            }
+        }
+        emmyrc
+    }
+
+    /// a `workspace.library` entry may point at the root of an addon, in which case its
+    /// `library/` folder is loaded and its settings are applied, returns whether any was found
+    pub(crate) fn resolve_library_addons(&mut self) -> bool {
+        let mut addons = Vec::new();
+        for lib in self.workspace.library.iter_mut() {
+            if let Some(addon) = LuaLsAddon::load(Path::new(lib)) {
+                *lib = addon.library.to_string_lossy().to_string();
+                addons.push(addon);
+            }
+        }
+
+        if addons.is_empty() {
+            return false;
+        }
+        *self = self.with_luals_addons(&addons);
+        true
+    }
+}
+
+/// the parts of `value` that differ from `default`, array items already in the default are
+/// dropped because arrays are merged by appending
+fn diff_values(value: Value, default: &Value) -> Option<Value> {
+    match (value, default) {
+        (Value::Object(map), Value::Object(default_map)) => {
+            let diff = map
+                .into_iter()
+                .filter_map(|(key, value)| match default_map.get(&key) {
+                    Some(default_value) => Some((key, diff_values(value, default_value)?)),
+                    None => Some((key, value)),
+                })
+                .collect::<serde_json::Map<_, _>>();
+            (!diff.is_empty()).then_some(Value::Object(diff))
+        }
+        (Value::Array(items), Value::Array(default_items)) => {
+            let items = items
+                .into_iter()
+                .filter(|item| !default_items.contains(item))
+                .collect::<Vec<_>>();
+            (!items.is_empty()).then_some(Value::Array(items))
+        }
+        (value, default) if value == *default => None,
+        (value, _) => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EmmyrcLuaVersion;
+
+    /// a fresh directory under the system temp dir
+    fn make_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "emmylua_addon_test_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_addon(root: &Path, config: &str) {
+        std::fs::create_dir_all(root.join("library")).unwrap();
+        std::fs::write(root.join("config.json"), config).unwrap();
+    }
+
+    #[test]
+    fn test_load_addon() {
+        let third_party = make_temp_dir("load");
+        let love = third_party.join("love2d");
+        write_addon(
+            &love,
+            r#"{
+                "name": "LÖVE",
+                "words": ["love%.%w+"],
+                "settings": { "Lua.runtime.version": "LuaJIT", "Lua.diagnostics.globals": ["love"] }
+            }"#,
+        );
+        // installed by the addon manager
+        let busted = third_party.join("busted");
+        write_addon(&busted.join("module"), r#"{ "files": ["_spec%.lua$"] }"#);
+        // no library folder
+        let broken = third_party.join("broken");
+        std::fs::create_dir_all(&broken).unwrap();
+        std::fs::write(broken.join("config.json"), "{}").unwrap();
+
+        let addon = LuaLsAddon::load(&love).unwrap();
+        assert_eq!(addon.name, "LÖVE");
+        assert_eq!(addon.library, love.join("library"));
+        let addon = LuaLsAddon::load(&busted).unwrap();
+        assert_eq!(addon.name, "module");
+        assert_eq!(addon.library, busted.join("module").join("library"));
+        assert!(LuaLsAddon::load(&broken).is_none());
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.workspace.user_third_party = vec![third_party.to_string_lossy().to_string()];
+        let files = vec![LuaFileInfo {
+            path: "/project/main.lua".to_string(),
+            content: "love.graphics.print('hi')".to_string(),
+        }];
+        let addons = emmyrc.detect_luals_addons(&files);
+        assert_eq!(addons.len(), 1);
+        assert_eq!(addons[0].name, "LÖVE");
+
+        let with_addons = emmyrc.with_luals_addons(&addons);
+        assert_eq!(
+            with_addons.workspace.library,
+            vec![love.join("library").to_string_lossy().to_string()]
+        );
+        assert_eq!(
+            serde_json::to_value(with_addons.runtime.version).unwrap(),
+            "LuaJIT"
+        );
+        // an enabled addon is not detected again
+        assert!(with_addons.detect_luals_addons(&files).is_empty());
+
+        // the values the user set win over the addon settings
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua51;
+        emmyrc.diagnostics.globals = vec!["conf".to_string()];
+        let with_addons = emmyrc.with_luals_addons(&addons);
+        assert_eq!(with_addons.runtime.version, EmmyrcLuaVersion::Lua51);
+        assert_eq!(with_addons.diagnostics.globals, vec!["love", "conf"]);
+
+        let _ = std::fs::remove_dir_all(&third_party);
+    }
+
+    #[test]
+    fn test_library_addon_paths() {
+        let workspace = make_temp_dir("library");
+        write_addon(
+            &workspace.join("addon"),
+            r#"{ "settings": { "Lua.workspace.library": ["${workspaceFolder}/types"] } }"#,
+        );
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.workspace.library = vec!["./addon".to_string()];
+        emmyrc.pre_process_emmyrc(&workspace);
+        assert_eq!(
+            emmyrc.workspace.library,
+            vec![
+                workspace.join("types").to_string_lossy().to_string(),
+                workspace
+                    .join("addon")
+                    .join("library")
+                    .to_string_lossy()
+                    .to_string(),
+            ]
+        );
+
+        let _ = std::fs::remove_dir_all(&workspace);
+    }
+
+    #[test]
+    fn test_lua_pattern_to_regex() {
+        let regex = lua_pattern_to_regex("love%.%w+").unwrap();
+        assert!(regex.is_match("local w = love.graphics.getWidth()"));
+        assert!(!regex.is_match("lovely"));
+
+        let regex = lua_pattern_to_regex("^vim%.[%a_]+$").unwrap();
+        assert!(regex.is_match("vim.api_call"));
+        assert!(!regex.is_match("local vim.api"));
+
+        let regex = lua_pattern_to_regex("conf%.lua$").unwrap();
+        assert!(regex.is_match("/project/conf.lua"));
+        assert!(!regex.is_match("/project/conf.lua.bak"));
+
+        let regex = lua_pattern_to_regex("a.-b").unwrap();
+        assert_eq!(regex.find("axxbyyb").unwrap().as_str(), "axxb");
+
+        assert!(lua_pattern_to_regex("%b()").is_none());
+    }
+}
@@ -1,6 +1,7 @@
 mod config_loader;
 mod configs;
 mod flatten_config;
+mod luals_addon;
 
 use std::{
     collections::HashSet,
@@ -12,6 +13,7 @@ pub use config_loader::load_configs;
 use configs::EmmyrcDocumentColor;
 pub use configs::EmmyrcFilenameConvention;
 pub use configs::EmmyrcLuaVersion;
+pub use luals_addon::LuaLsAddon;
 use configs::{
     EmmyrcCodeLen, EmmyrcCompletion, EmmyrcDiagnostic, EmmyrcHover, EmmyrcInlayHint,
     EmmyrcReference, EmmyrcResource, EmmyrcRuntime, EmmyrcSemanticToken, EmmyrcSignature,
@@ -23,7 +25,7 @@ use rowan::NodeCache;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema, Default)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Emmyrc {
     #[serde(rename = "$schema")]
@@ -84,6 +86,14 @@ impl Emmyrc {
     }
 
     pub fn pre_process_emmyrc(&mut self, workspace_root: &Path) {
+        self.pre_process_paths(workspace_root);
+        // addon settings may bring paths of their own
+        if self.resolve_library_addons() {
+            self.pre_process_paths(workspace_root);
+        }
+    }
+
+    fn pre_process_paths(&mut self, workspace_root: &Path) {
         fn process_and_dedup<'a>(
             iter: impl Iterator<Item = &'a String>,
             workspace_root: &Path,
@@ -101,6 +111,9 @@ impl Emmyrc {
         self.workspace.ignore_dir =
             process_and_dedup(self.workspace.ignore_dir.iter(), workspace_root);
 
+        self.workspace.user_third_party =
+            process_and_dedup(self.workspace.user_third_party.iter(), workspace_root);
+
         self.resource.paths = process_and_dedup(self.resource.paths.iter(), workspace_root);
     }
 }
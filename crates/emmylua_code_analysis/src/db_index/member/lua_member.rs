@@ -11,7 +11,7 @@ use crate::{
 #[derive(Debug)]
 pub struct LuaMember {
     pub(super) owner: LuaMemberOwner,
-    key: LuaMemberKey,
+    pub(super) key: LuaMemberKey,
     file_id: FileId,
     syntax_id: LuaSyntaxId,
     pub(crate) decl_type: LuaType,
@@ -55,6 +55,20 @@ impl LuaMemberIndex {
         Some(())
     }
 
+    pub fn set_member_key(&mut self, id: &LuaMemberId, key: LuaMemberKey) -> Option<()> {
+        let member = self.members.get_mut(id)?;
+        let old_key = std::mem::replace(&mut member.key, key.clone());
+        let owner = member.get_owner();
+        if let Some(owner_members) = self.owner_members.get_mut(&owner) {
+            if owner_members.get(&old_key) == Some(id) {
+                owner_members.remove(&old_key);
+            }
+            owner_members.entry(key).or_insert(*id);
+        }
+
+        Some(())
+    }
+
     pub fn get_member(&self, id: &LuaMemberId) -> Option<&LuaMember> {
         self.members.get(id)
     }
@@ -14,6 +14,7 @@ use super::traits::LuaIndex;
 use crate::{Emmyrc, FileId};
 use std::{
     collections::HashMap,
+    ops::Range,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -98,14 +99,26 @@ impl LuaModuleIndex {
             self.remove(file_id);
         }
 
-        let (module_path, workspace_id) = self.extract_module_path(&path)?;
+        let (module_path, workspace_id) = self.file_path_to_module_path(path)?;
+        self.add_module_by_module_path(file_id, module_path, workspace_id);
+        Some(workspace_id)
+    }
+
+    /// Get the module path a file would have at `path`, the file doesn't need to exist.
+    /// For example, "<workspace>/a/b/c.lua" represents the module "a.b.c".
+    pub fn get_module_path_by_file_path(&self, path: &str) -> Option<String> {
+        self.file_path_to_module_path(path)
+            .map(|(module_path, _)| module_path)
+    }
+
+    fn file_path_to_module_path(&self, path: &str) -> Option<(String, WorkspaceId)> {
+        let (module_path, workspace_id) = self.extract_module_path(path)?;
         let mut module_path = module_path.replace(['\\', '/'], ".");
         if !self.module_replace_vec.is_empty() {
             module_path = self.replace_module_path(&module_path);
         }
 
-        self.add_module_by_module_path(file_id, module_path, workspace_id);
-        Some(workspace_id)
+        Some((module_path, workspace_id))
     }
 
     pub fn add_module_by_module_path(
@@ -287,27 +300,53 @@ impl LuaModuleIndex {
         self.file_module_map.values().collect()
     }
 
+    pub fn get_workspace_root(&self, workspace_id: WorkspaceId) -> Option<&Path> {
+        self.workspaces
+            .iter()
+            .find(|workspace| workspace.id == workspace_id)
+            .map(|workspace| workspace.root.as_path())
+    }
+
+    /// Get the path a file moves to when its module is renamed to `new_module_path`,
+    /// it stays below the same workspace root and follows the require pattern it matched.
+    /// For example, "<workspace>/a/init.lua" renamed to "b.c" moves to "<workspace>/b/c/init.lua".
+    pub fn get_renamed_file_path(&self, path: &str, new_module_path: &str) -> Option<PathBuf> {
+        let (workspace, relative_path, range) = self.match_module_path(path)?;
+        let new_relative_path = format!(
+            "{}{}{}",
+            &relative_path[..range.start],
+            new_module_path.replace('.', "/"),
+            &relative_path[range.end..]
+        );
+        Some(workspace.root.join(new_relative_path))
+    }
+
     fn extract_module_path(&self, path: &str) -> Option<(String, WorkspaceId)> {
+        let (workspace, relative_path, range) = self.match_module_path(path)?;
+        Some((relative_path[range].to_string(), workspace.id))
+    }
+
+    /// find the workspace and the part of the relative path that names the module,
+    /// the longest module path wins when several workspaces contain the file
+    fn match_module_path(&self, path: &str) -> Option<(&Workspace, String, Range<usize>)> {
         let path = Path::new(path);
-        let mut matched_module_path: Option<(String, WorkspaceId)> = None;
+        let mut matched: Option<(&Workspace, String, Range<usize>)> = None;
         for workspace in &self.workspaces {
             if let Ok(relative_path) = path.strip_prefix(&workspace.root) {
                 let relative_path_str = relative_path.to_str().unwrap_or("");
-                let module_path = self.match_pattern(relative_path_str);
-                if let Some(module_path) = module_path {
-                    if matched_module_path.is_none() {
-                        matched_module_path = Some((module_path, workspace.id));
-                    } else {
-                        let (matched, _) = matched_module_path.as_ref().unwrap();
-                        if module_path.len() > matched.len() {
-                            matched_module_path = Some((module_path, workspace.id));
-                        }
-                    }
+                let Some(range) = self.match_pattern(relative_path_str) else {
+                    continue;
+                };
+                let is_longer = matched
+                    .as_ref()
+                    .is_none_or(|(_, _, matched_range)| range.len() > matched_range.len());
+                if is_longer {
+                    matched = Some((workspace, relative_path_str.to_string(), range));
                 }
             }
         }
 
-        matched_module_path
+        matched
     }
 
     fn replace_module_path(&self, module_path: &str) -> String {
@@ -318,11 +357,11 @@ impl LuaModuleIndex {
         module_path.to_string()
     }
 
-    fn match_pattern(&self, path: &str) -> Option<String> {
+    fn match_pattern(&self, path: &str) -> Option<Range<usize>> {
         for pattern in &self.module_patterns {
             if let Some(captures) = pattern.captures(path) {
                 if let Some(matched) = captures.get(1) {
-                    return Some(matched.as_str().to_string());
+                    return Some(matched.range());
                 }
             }
         }
@@ -356,10 +395,12 @@ impl LuaModuleIndex {
             extension_names.push("lua".to_string());
         }
 
-        let mut patterns = Vec::new();
-        for extension in extension_names {
-            patterns.push(format!("?.{}", extension));
-            patterns.push(format!("?/init.{}", extension));
+        let mut patterns = config.runtime.require_pattern.clone();
+        if patterns.is_empty() {
+            for extension in extension_names {
+                patterns.push(format!("?.{}", extension));
+                patterns.push(format!("?/init.{}", extension));
+            }
         }
 
         self.set_module_extract_patterns(patterns);
@@ -140,4 +140,40 @@ mod tests {
         let module_node = m.find_module_node("test2.aaa");
         assert!(module_node.is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_get_module_path_by_file_path() {
+        let mut m = create_module();
+        m.add_workspace_root(Path::new("C:/Users/username/Documents").into(), WorkspaceId::MAIN);
+        assert_eq!(
+            m.get_module_path_by_file_path("C:/Users/username/Documents/aaa/bbb.lua"),
+            Some("aaa.bbb".to_string())
+        );
+        assert_eq!(
+            m.get_module_path_by_file_path("C:/Users/username/Documents/aaa/init.lua"),
+            Some("aaa".to_string())
+        );
+        assert_eq!(m.get_module_path_by_file_path("C:/Users/username/Downloads/aaa.lua"), None);
+    }
+
+    #[test]
+    fn test_get_renamed_file_path() {
+        let mut m = create_module();
+        m.add_workspace_root(Path::new("C:/Users/username/Documents").into(), WorkspaceId::MAIN);
+        assert_eq!(
+            m.get_renamed_file_path("C:/Users/username/Documents/a.lua", "b"),
+            Some(Path::new("C:/Users/username/Documents/b.lua").into())
+        );
+        assert_eq!(
+            m.get_renamed_file_path("C:/Users/username/Documents/aaa/init.lua", "x.y"),
+            Some(Path::new("C:/Users/username/Documents/x/y/init.lua").into())
+        );
+        assert_eq!(m.get_renamed_file_path("C:/Users/username/Downloads/a.lua", "b"), None);
+
+        m.set_module_extract_patterns(["src/?.lua".to_string()].to_vec());
+        assert_eq!(
+            m.get_renamed_file_path("C:/Users/username/Documents/src/a/lua.lua", "lua"),
+            Some(Path::new("C:/Users/username/Documents/src/lua.lua").into())
+        );
+    }
+}
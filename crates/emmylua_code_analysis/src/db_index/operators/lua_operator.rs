@@ -1,10 +1,14 @@
+use std::sync::Arc;
+
 use rowan::{TextRange, TextSize};
 
-use crate::{db_index::{LuaType, LuaTypeDeclId}, FileId};
+use crate::{
+    db_index::{DbIndex, LuaFunctionType, LuaSignatureId, LuaType, LuaTypeDeclId},
+    FileId,
+};
 
 use super::lua_operator_meta_method::LuaOperatorMetaMethod;
 
-
 #[derive(Debug)]
 pub struct LuaOperator {
     owner: LuaTypeDeclId,
@@ -50,6 +54,25 @@ impl LuaOperator {
         }
     }
 
+    /// operator defined by a metamethod function, like `function Vec.__add(a, b) end`,
+    /// its operands and result are read from the signature when used
+    pub fn new_metamethod(
+        owner: LuaTypeDeclId,
+        op: LuaOperatorMetaMethod,
+        signature_id: LuaSignatureId,
+        file_id: FileId,
+        range: TextRange,
+    ) -> Self {
+        Self {
+            owner,
+            op,
+            operands: vec![],
+            return_or_func: LuaType::Signature(signature_id),
+            file_id,
+            range,
+        }
+    }
+
     pub fn get_owner(&self) -> &LuaTypeDeclId {
         &self.owner
     }
@@ -58,19 +81,71 @@ impl LuaOperator {
         self.op
     }
 
-    pub fn get_operands(&self) -> &[LuaType] {
-        &self.operands
+    pub fn get_operands(&self, db: &DbIndex) -> Vec<LuaType> {
+        match &self.return_or_func {
+            LuaType::Signature(signature_id) if self.operands.is_empty() => {
+                match get_metamethod_func(db, signature_id, true) {
+                    Some(func) => func
+                        .get_params()
+                        .iter()
+                        .map(|(_, typ)| typ.clone().unwrap_or(LuaType::Any))
+                        .collect(),
+                    None => vec![],
+                }
+            }
+            _ => self.operands.clone(),
+        }
+    }
+
+    /// operands when the owner is the right side of a binary operator, a metamethod is
+    /// still called as `(left, right)`, so its first param is kept and matched against the left
+    pub fn get_right_owner_operands(&self, db: &DbIndex) -> Vec<LuaType> {
+        let LuaType::Signature(signature_id) = &self.return_or_func else {
+            return self.operands.clone();
+        };
+        if !self.operands.is_empty() {
+            return self.operands.clone();
+        }
+
+        let Some(signature) = db.get_signature_index().get(signature_id) else {
+            return vec![];
+        };
+        let Some(func) = get_metamethod_func(db, signature_id, false) else {
+            return vec![];
+        };
+        let mut operands: Vec<LuaType> = func
+            .get_params()
+            .iter()
+            .map(|(_, typ)| typ.clone().unwrap_or(LuaType::Any))
+            .collect();
+        if signature.is_colon_define {
+            operands.insert(0, LuaType::Ref(self.owner.clone()));
+        }
+
+        operands
     }
 
-    pub fn get_result(&self) -> &LuaType {
-        &self.return_or_func
+    /// return None when the result comes from a metamethod whose return is not resolved yet
+    pub fn get_result(&self, db: &DbIndex) -> Option<LuaType> {
+        match &self.return_or_func {
+            LuaType::Signature(signature_id) if self.operands.is_empty() => {
+                let func = get_metamethod_func(db, signature_id, true)?;
+                Some(func.get_ret().first().cloned().unwrap_or(LuaType::Nil))
+            }
+            _ => Some(self.return_or_func.clone()),
+        }
     }
 
-    pub fn get_call_operator_type(&self) -> Option<&LuaType> {
-        if self.op == LuaOperatorMetaMethod::Call {
-            Some(&self.return_or_func)
-        } else {
-            None
+    pub fn get_call_operator_type(&self, db: &DbIndex) -> Option<LuaType> {
+        if self.op != LuaOperatorMetaMethod::Call {
+            return None;
+        }
+
+        match &self.return_or_func {
+            LuaType::Signature(signature_id) => Some(LuaType::DocFunction(
+                get_metamethod_func(db, signature_id, true)?,
+            )),
+            _ => Some(self.return_or_func.clone()),
         }
     }
 
@@ -86,6 +161,34 @@ impl LuaOperator {
     }
 }
 
+// metamethods receive the operand that owns the metatable as the first param,
+// `skip_owner` drops it
+fn get_metamethod_func(
+    db: &DbIndex,
+    signature_id: &LuaSignatureId,
+    skip_owner: bool,
+) -> Option<Arc<LuaFunctionType>> {
+    let signature = db.get_signature_index().get(signature_id)?;
+    if !signature.is_resolve_return() {
+        return None;
+    }
+
+    let mut params = signature.get_type_params();
+    if skip_owner && !signature.is_colon_define && !params.is_empty() {
+        params.remove(0);
+    }
+
+    Some(
+        LuaFunctionType::new(
+            false,
+            false,
+            params,
+            signature.get_return_types(),
+        )
+        .into(),
+    )
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LuaOperatorId {
     pub file_id: FileId,
@@ -96,4 +199,4 @@ impl LuaOperatorId {
     pub fn new(position: TextSize, file_id: FileId) -> Self {
         Self { position, file_id }
     }
-}
\ No newline at end of file
+}
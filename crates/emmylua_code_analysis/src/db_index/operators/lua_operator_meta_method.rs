@@ -21,6 +21,7 @@ pub enum LuaOperatorMetaMethod {
     Le,       // <=
     Index,    // __index
     Call,     // __call
+    NewIndex, // __newindex
 }
 
 impl LuaOperatorMetaMethod {
@@ -49,4 +50,13 @@ impl LuaOperatorMetaMethod {
             _ => None
         }
     }
-}
\ No newline at end of file
+
+    /// map a metatable field name like `__add` to its operator
+    pub fn from_metatable_field(name: &str) -> Option<Self> {
+        match name.strip_prefix("__")? {
+            "index" => Some(LuaOperatorMetaMethod::Index),
+            "newindex" => Some(LuaOperatorMetaMethod::NewIndex),
+            op => Self::from_str(op),
+        }
+    }
+}
@@ -5,7 +5,7 @@ use emmylua_parser::{LuaVersionCondition, VisibilityKind};
 use property::LuaProperty;
 pub use property::{LuaPropertyId, LuaPropertyOwnerId};
 
-use crate::FileId;
+use crate::{FileId, LuaType};
 
 use super::traits::LuaIndex;
 
@@ -171,6 +171,117 @@ impl LuaPropertyIndex {
         Some(())
     }
 
+    pub fn add_readonly(&mut self, file_id: FileId, owner_id: LuaPropertyOwnerId) -> Option<()> {
+        let property = self.get_or_create_property(owner_id.clone())?;
+        property.is_readonly = true;
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_default()
+            .insert(owner_id);
+
+        Some(())
+    }
+
+    pub fn add_see(
+        &mut self,
+        file_id: FileId,
+        owner_id: LuaPropertyOwnerId,
+        target: String,
+        description: Option<String>,
+    ) -> Option<()> {
+        let property = self.get_or_create_property(owner_id.clone())?;
+        property
+            .see
+            .get_or_insert_with(Default::default)
+            .push((target, description));
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_default()
+            .insert(owner_id);
+
+        Some(())
+    }
+
+    pub fn add_example(
+        &mut self,
+        file_id: FileId,
+        owner_id: LuaPropertyOwnerId,
+        example: String,
+    ) -> Option<()> {
+        let property = self.get_or_create_property(owner_id.clone())?;
+        property
+            .examples
+            .get_or_insert_with(Default::default)
+            .push(example);
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_default()
+            .insert(owner_id);
+
+        Some(())
+    }
+
+    pub fn add_throws(
+        &mut self,
+        file_id: FileId,
+        owner_id: LuaPropertyOwnerId,
+        typ: LuaType,
+        description: Option<String>,
+    ) -> Option<()> {
+        let property = self.get_or_create_property(owner_id.clone())?;
+        property
+            .throws
+            .get_or_insert_with(Default::default)
+            .push((typ, description));
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_default()
+            .insert(owner_id);
+
+        Some(())
+    }
+
+    pub fn add_since(
+        &mut self,
+        file_id: FileId,
+        owner_id: LuaPropertyOwnerId,
+        since: String,
+    ) -> Option<()> {
+        let property = self.get_or_create_property(owner_id.clone())?;
+        property.since = Some(since);
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_default()
+            .insert(owner_id);
+
+        Some(())
+    }
+
+    pub fn add_author(
+        &mut self,
+        file_id: FileId,
+        owner_id: LuaPropertyOwnerId,
+        author: String,
+    ) -> Option<()> {
+        let property = self.get_or_create_property(owner_id.clone())?;
+        property
+            .authors
+            .get_or_insert_with(Default::default)
+            .push(author);
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_default()
+            .insert(owner_id);
+
+        Some(())
+    }
+
     pub fn get_property(&self, owner_id: LuaPropertyOwnerId) -> Option<&LuaProperty> {
         self.property_owners_map
             .get(&owner_id)
@@ -2,7 +2,7 @@ use emmylua_parser::{LuaVersionCondition, VisibilityKind};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    db_index::{member::LuaMemberId, LuaDeclId, LuaSignatureId, LuaTypeDeclId},
+    db_index::{member::LuaMemberId, LuaDeclId, LuaSignatureId, LuaType, LuaTypeDeclId},
     FileId,
 };
 
@@ -17,6 +17,15 @@ pub struct LuaProperty {
     pub deprecated_message: Option<Box<String>>,
     pub version_conds: Option<Box<Vec<LuaVersionCondition>>>,
     pub is_async: bool,
+    pub is_readonly: bool,
+    /// `---@see` targets with their descriptions
+    pub see: Option<Vec<(String, Option<String>)>>,
+    /// `---@example` bodies
+    pub examples: Option<Vec<String>>,
+    /// `---@throws` / `---@error` types with their descriptions
+    pub throws: Option<Vec<(LuaType, Option<String>)>>,
+    pub since: Option<String>,
+    pub authors: Option<Vec<String>>,
 }
 
 impl LuaProperty {
@@ -31,6 +40,12 @@ impl LuaProperty {
             deprecated_message: None,
             version_conds: None,
             is_async: false,
+            is_readonly: false,
+            see: None,
+            examples: None,
+            throws: None,
+            since: None,
+            authors: None,
         }
     }
 }
@@ -159,6 +159,21 @@ impl LuaReferenceIndex {
         Some(results)
     }
 
+    pub fn get_global_reference_count(&self, name: &str) -> usize {
+        self.global_references
+            .get(name)
+            .map(|file_references| file_references.values().map(|ids| ids.len()).sum())
+            .unwrap_or(0)
+    }
+
+    /// how many times `key` is indexed in the workspace, without collecting the references
+    pub fn get_index_reference_count(&self, key: &LuaMemberKey) -> usize {
+        self.index_reference
+            .get(key)
+            .map(|file_references| file_references.values().map(|ids| ids.len()).sum())
+            .unwrap_or(0)
+    }
+
     pub fn get_string_references(&self, string_value: &str) -> Vec<InFiled<TextRange>> {
         let results = self
             .string_references
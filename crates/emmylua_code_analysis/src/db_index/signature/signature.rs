@@ -43,6 +43,11 @@ impl LuaSignature {
         self.resolve_return || !self.return_docs.is_empty()
     }
 
+    /// the returns come from the function body rather than `---@return`
+    pub fn is_return_inferred(&self) -> bool {
+        self.resolve_return && !self.return_docs.is_empty()
+    }
+
     pub fn get_type_params(&self) -> Vec<(String, Option<LuaType>)> {
         let mut type_params = Vec::new();
         for (idx, param_name) in self.params.iter().enumerate() {
@@ -1,8 +1,8 @@
 use crate::{
     DbIndex, GenericTpl, LuaAliasCallType, LuaFunctionType, LuaGenericType, LuaInstanceType,
     LuaIntersectionType, LuaMemberKey, LuaMemberOwner, LuaMemberPathExistType, LuaMultiReturn,
-    LuaObjectType, LuaSignatureId, LuaStringTplType, LuaTupleType, LuaType, LuaTypeDeclId,
-    LuaUnionType, TypeSubstitutor,
+    LuaObjectType, LuaPropertyOwnerId, LuaSignatureId, LuaStringTplType, LuaTupleType, LuaType,
+    LuaTypeDeclId, LuaUnionType, TypeSubstitutor,
 };
 
 use super::LuaMultiLineUnion;
@@ -127,9 +127,26 @@ fn humanize_simple_type(
     let member_owner = LuaMemberOwner::Type(id.clone());
     let member_index = db.get_member_index();
     let member_map = member_index.get_member_map(member_owner)?;
+    let version_number = db
+        .get_vfs()
+        .get_emmyrc()
+        .map(|emmyrc| emmyrc.runtime.version.to_lua_version_number());
     let mut member_vec = Vec::new();
     for (member_key, member_id) in member_map {
         let member = member_index.get_member(member_id)?;
+        // hide members that are not available in the current runtime version
+        if let Some(version_number) = &version_number {
+            let version_conds = db
+                .get_property_index()
+                .get_property(LuaPropertyOwnerId::Member(*member_id))
+                .and_then(|property| property.version_conds.as_ref());
+            if let Some(version_conds) = version_conds {
+                if !version_conds.iter().any(|cond| cond.check(version_number)) {
+                    continue;
+                }
+            }
+        }
+
         let typ = member.get_decl_type();
         if !typ.is_signature() {
             member_vec.push((member_key, typ));
@@ -11,7 +11,7 @@ use emmylua_parser::LuaSyntaxId;
 use flagset::FlagSet;
 pub use humanize_type::{humanize_type, RenderLevel};
 use rowan::TextRange;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 pub use type_assert::TypeAssertion;
 pub use type_decl::{
     LuaDeclLocation, LuaDeclTypeKind, LuaTypeAttribute, LuaTypeDecl, LuaTypeDeclId,
@@ -27,6 +27,7 @@ pub struct LuaTypeIndex {
     full_name_type_map: HashMap<LuaTypeDeclId, LuaTypeDecl>,
     generic_params: HashMap<LuaTypeDeclId, Vec<(String, Option<LuaType>)>>,
     supers: HashMap<LuaTypeDeclId, Vec<InFiled<LuaType>>>,
+    sub_types: HashMap<LuaTypeDeclId, Vec<InFiled<LuaTypeDeclId>>>,
     as_force_type: HashMap<InFiled<LuaSyntaxId>, LuaType>,
 }
 
@@ -39,6 +40,7 @@ impl LuaTypeIndex {
             full_name_type_map: HashMap::new(),
             generic_params: HashMap::new(),
             supers: HashMap::new(),
+            sub_types: HashMap::new(),
             as_force_type: HashMap::new(),
         }
     }
@@ -212,6 +214,18 @@ impl LuaTypeIndex {
     }
 
     pub fn add_super_type(&mut self, decl_id: LuaTypeDeclId, file_id: FileId, super_type: LuaType) {
+        let super_id = match &super_type {
+            LuaType::Ref(id) => Some(id.clone()),
+            LuaType::Generic(generic) => Some(generic.get_base_type_id()),
+            _ => None,
+        };
+        if let Some(super_id) = super_id {
+            self.sub_types
+                .entry(super_id)
+                .or_default()
+                .push(InFiled::new(file_id, decl_id.clone()));
+        }
+
         self.supers
             .entry(decl_id)
             .or_insert_with(Vec::new)
@@ -226,6 +240,42 @@ impl LuaTypeIndex {
         }
     }
 
+    /// the types directly inheriting `decl_id`
+    pub fn get_sub_types(&self, decl_id: &LuaTypeDeclId) -> Vec<LuaTypeDeclId> {
+        let mut result: Vec<LuaTypeDeclId> = Vec::new();
+        for sub_type in self.sub_types.get(decl_id).into_iter().flatten() {
+            if !result.contains(&sub_type.value) {
+                result.push(sub_type.value.clone());
+            }
+        }
+
+        result
+    }
+
+    /// every class `decl_id` inherits, nearest first, each one only once
+    pub fn get_all_super_type_ids(&self, decl_id: &LuaTypeDeclId) -> Vec<LuaTypeDeclId> {
+        let mut result: Vec<LuaTypeDeclId> = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(decl_id.clone());
+        while let Some(id) = queue.pop_front() {
+            for super_type in self.get_super_types(&id).unwrap_or_default() {
+                let super_id = match super_type {
+                    LuaType::Ref(id) => id,
+                    LuaType::Generic(generic) => generic.get_base_type_id(),
+                    _ => continue,
+                };
+                if super_id == *decl_id || result.contains(&super_id) {
+                    continue;
+                }
+
+                result.push(super_id.clone());
+                queue.push_back(super_id);
+            }
+        }
+
+        result
+    }
+
     pub fn get_type_decl(&self, decl_id: &LuaTypeDeclId) -> Option<&LuaTypeDecl> {
         self.full_name_type_map.get(decl_id)
     }
@@ -276,6 +326,10 @@ impl LuaIndex for LuaTypeIndex {
             }
         }
 
+        self.sub_types.retain(|_, sub_types| {
+            sub_types.retain(|sub_type| sub_type.file_id != file_id);
+            !sub_types.is_empty()
+        });
         self.as_force_type.retain(|id, _| id.file_id != file_id);
     }
 }
@@ -4,7 +4,7 @@ mod test {
 
     use crate::db_index::r#type::LuaTypeIndex;
     use crate::db_index::traits::LuaIndex;
-    use crate::db_index::{LuaDeclTypeKind, LuaTypeAttribute};
+    use crate::db_index::{LuaDeclTypeKind, LuaType, LuaTypeAttribute, LuaTypeDeclId};
     use crate::FileId;
 
     fn create_type_index() -> LuaTypeIndex {
@@ -149,4 +149,44 @@ mod test {
         assert_eq!(decl.get_namespace(), "test".into());
         assert_eq!(decl.get_full_name(), "test.new_type");
     }
+
+    #[test]
+    fn test_sub_types() {
+        let mut index = create_type_index();
+        let file_id = FileId { id: 1 };
+        let base = LuaTypeDeclId::new("Base");
+        index.add_super_type(LuaTypeDeclId::new("A"), file_id, LuaType::Ref(base.clone()));
+        index.add_super_type(LuaTypeDeclId::new("B"), file_id, LuaType::Ref(base.clone()));
+        index.add_super_type(
+            LuaTypeDeclId::new("C"),
+            file_id,
+            LuaType::Ref(LuaTypeDeclId::new("A")),
+        );
+
+        let mut sub_types = index.get_sub_types(&base);
+        sub_types.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+        assert_eq!(
+            sub_types,
+            vec![LuaTypeDeclId::new("A"), LuaTypeDeclId::new("B")]
+        );
+
+        index.remove(file_id);
+        assert!(index.get_sub_types(&base).is_empty());
+    }
+
+    #[test]
+    fn test_all_super_type_ids() {
+        let mut index = create_type_index();
+        let file_id = FileId { id: 1 };
+        let a = LuaTypeDeclId::new("A");
+        let b = LuaTypeDeclId::new("B");
+        let c = LuaTypeDeclId::new("C");
+        index.add_super_type(c.clone(), file_id, LuaType::Ref(b.clone()));
+        index.add_super_type(b.clone(), file_id, LuaType::Ref(a.clone()));
+        // a cycle must not loop forever
+        index.add_super_type(a.clone(), file_id, LuaType::Ref(c.clone()));
+
+        assert_eq!(index.get_all_super_type_ids(&c), vec![b.clone(), a.clone()]);
+        assert_eq!(index.get_all_super_type_ids(&a), vec![c, b]);
+    }
 }
@@ -5,6 +5,13 @@ pub fn remove_type(source: LuaType, removed_type: LuaType) -> LuaType {
         return remove_nil_and_not_false(source);
     }
 
+    if let LuaType::Union(removed_union) = &removed_type {
+        return removed_union
+            .get_types()
+            .iter()
+            .fold(source, |source, removed| remove_type(source, removed.clone()));
+    }
+
     match source {
         LuaType::Union(union) => {
             let mut types = union.get_types().to_vec();
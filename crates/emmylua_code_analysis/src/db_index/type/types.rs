@@ -512,6 +512,7 @@ pub enum LuaIndexAccessKey {
 pub struct LuaObjectType {
     fields: HashMap<LuaMemberKey, LuaType>,
     index_access: Vec<(LuaType, LuaType)>,
+    readonly: bool,
 }
 
 impl LuaObjectType {
@@ -535,6 +536,7 @@ impl LuaObjectType {
         Self {
             fields,
             index_access,
+            readonly: false,
         }
     }
 
@@ -545,9 +547,21 @@ impl LuaObjectType {
         Self {
             fields,
             index_access,
+            readonly: false,
         }
     }
 
+    pub fn with_readonly(&self, readonly: bool) -> Self {
+        Self {
+            readonly,
+            ..self.clone()
+        }
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+
     pub fn get_fields(&self) -> &HashMap<LuaMemberKey, LuaType> {
         &self.fields
     }
@@ -673,6 +687,14 @@ pub enum LuaAliasCallKind {
     Sub,
     Select,
     Unpack,
+    /// operands: key param, keys, value
+    Mapped,
+    ReturnType,
+    Parameters,
+    /// `T` without `nil`, unlike `Sub` with `?` it keeps `false`
+    RemoveNil,
+    /// marks the fields of an object type readonly
+    Readonly,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -937,11 +959,20 @@ impl GenericTplId {
 pub struct GenericTpl {
     tpl_id: GenericTplId,
     name: ArcIntern<SmolStr>,
+    constraint: Option<LuaType>,
 }
 
 impl GenericTpl {
-    pub fn new(tpl_id: GenericTplId, name: ArcIntern<SmolStr>) -> Self {
-        Self { tpl_id, name }
+    pub fn new(
+        tpl_id: GenericTplId,
+        name: ArcIntern<SmolStr>,
+        constraint: Option<LuaType>,
+    ) -> Self {
+        Self {
+            tpl_id,
+            name,
+            constraint,
+        }
     }
 
     pub fn get_tpl_id(&self) -> GenericTplId {
@@ -951,6 +982,10 @@ impl GenericTpl {
     pub fn get_name(&self) -> &str {
         &self.name
     }
+
+    pub fn get_constraint(&self) -> Option<&LuaType> {
+        self.constraint.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
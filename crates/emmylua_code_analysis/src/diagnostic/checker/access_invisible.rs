@@ -81,26 +81,10 @@ fn report_reason(
         .get_property_index()
         .get_property(property_owner_id)?;
 
+    // version conditions are reported by version-mismatch
     if let Some(version_conds) = &property.version_conds {
         let version_number = emmyrc.runtime.version.to_lua_version_number();
-        let visible = version_conds.iter().any(|cond| cond.check(&version_number));
-        if !visible {
-            let message = t!(
-                "The current Lua version %{version} is not accessible; expected %{conds}.",
-                version = version_number,
-                conds = version_conds
-                    .iter()
-                    .map(|it| format!("{}", it))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            );
-
-            context.add_diagnostic(
-                DiagnosticCode::AccessInvisible,
-                range,
-                message.to_string(),
-                None,
-            );
+        if !version_conds.iter().any(|cond| cond.check(&version_number)) {
             return Some(());
         }
     }
@@ -0,0 +1,90 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr};
+
+use crate::{humanize_type, DiagnosticCode, LuaType, RenderLevel, SemanticModel};
+
+use super::DiagnosticContext;
+
+pub const CODES: &[DiagnosticCode] = &[DiagnosticCode::GenericConstraintMismatch];
+
+pub fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) -> Option<()> {
+    let root = semantic_model.get_root().clone();
+    for call_expr in root.descendants::<LuaCallExpr>() {
+        check_call_expr(context, semantic_model, call_expr);
+    }
+
+    Some(())
+}
+
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let prefix_type = semantic_model.infer_expr(call_expr.get_prefix_expr()?)?;
+    let (params, colon_define) = match &prefix_type {
+        LuaType::Signature(signature_id) => {
+            let signature = semantic_model
+                .get_db()
+                .get_signature_index()
+                .get(signature_id)?;
+            if !signature.is_generic() {
+                return None;
+            }
+            (signature.get_type_params(), signature.is_colon_define)
+        }
+        LuaType::DocFunction(func) => (func.get_params().to_vec(), func.is_colon_define()),
+        _ => return None,
+    };
+
+    let mut args = call_expr
+        .get_args_list()?
+        .get_args()
+        .map(Some)
+        .collect::<Vec<_>>();
+    match (call_expr.is_colon_call(), colon_define) {
+        (true, true) | (false, false) => {}
+        (false, true) => {
+            if !args.is_empty() {
+                args.remove(0);
+            }
+        }
+        (true, false) => {
+            args.insert(0, None);
+        }
+    }
+
+    let db = semantic_model.get_db();
+    for (idx, (_, param_type)) in params.iter().enumerate() {
+        let Some(LuaType::TplRef(tpl)) = param_type else {
+            continue;
+        };
+        let Some(constraint) = tpl.get_constraint() else {
+            continue;
+        };
+        let Some(Some(arg)) = args.get(idx) else {
+            continue;
+        };
+
+        let arg_type = semantic_model
+            .infer_expr(arg.clone())
+            .unwrap_or(LuaType::Any);
+        if semantic_model.type_check(constraint, &arg_type).is_ok() {
+            continue;
+        }
+
+        context.add_diagnostic(
+            DiagnosticCode::GenericConstraintMismatch,
+            arg.get_range(),
+            t!(
+                "Generic `%{name}` requires `%{constraint}` but found `%{found}`",
+                name = tpl.get_name(),
+                constraint = humanize_type(db, constraint, RenderLevel::Simple),
+                found = humanize_type(db, &arg_type, RenderLevel::Simple)
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
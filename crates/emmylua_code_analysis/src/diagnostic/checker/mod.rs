@@ -3,13 +3,16 @@ mod analyze_error;
 mod await_in_sync;
 mod deprecated;
 mod discard_returns;
+mod generic_constraint_mismatch;
 mod local_const_reassign;
 mod missing_parameter;
 mod need_check_nil;
 mod param_type_check;
+mod readonly_assign;
 mod syntax_error;
 mod undefined_global;
 mod unused;
+mod version_mismatch;
 mod code_style_check;
 
 use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString};
@@ -51,6 +54,9 @@ pub fn check_file(
     check!(await_in_sync);
     check!(param_type_check);
     check!(need_check_nil);
+    check!(readonly_assign);
+    check!(generic_constraint_mismatch);
+    check!(version_mismatch);
     check!(code_style_check);
 
     Some(())
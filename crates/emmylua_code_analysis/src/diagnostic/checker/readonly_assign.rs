@@ -0,0 +1,184 @@
+use emmylua_parser::{
+    LuaAssignStat, LuaAstNode, LuaClosureExpr, LuaExpr, LuaFuncStat, LuaIndexExpr, LuaNameExpr,
+    LuaVarExpr,
+};
+
+use crate::{
+    DiagnosticCode, LuaMemberId, LuaMemberKey, LuaPropertyOwnerId, LuaType, SemanticModel,
+    TypeSubstitutor,
+};
+
+use super::DiagnosticContext;
+
+pub const CODES: &[DiagnosticCode] = &[DiagnosticCode::ReadonlyAssign];
+
+pub fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) -> Option<()> {
+    check_readonly_locals(context, semantic_model);
+
+    let root = semantic_model.get_root().clone();
+    for assign_stat in root.descendants::<LuaAssignStat>() {
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        for var in vars {
+            if let LuaVarExpr::IndexExpr(index_expr) = var {
+                check_index_expr(context, semantic_model, index_expr);
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn check_readonly_locals(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+) -> Option<()> {
+    let file_id = semantic_model.get_file_id();
+    let db = semantic_model.get_db();
+    let decl_tree = db.get_decl_index().get_decl_tree(&file_id)?;
+    let local_refs = db.get_reference_index().get_local_reference(&file_id)?;
+    for (decl_id, decl) in decl_tree.get_decls() {
+        if !is_readonly(semantic_model, LuaPropertyOwnerId::LuaDecl(*decl_id)) {
+            continue;
+        }
+
+        let Some(decl_refs) = local_refs.get_decl_references(decl_id) else {
+            continue;
+        };
+        for decl_ref in decl_refs {
+            if decl_ref.is_write {
+                context.add_diagnostic(
+                    DiagnosticCode::ReadonlyAssign,
+                    decl_ref.range,
+                    t!("Cannot assign to readonly %{name}", name = decl.get_name()).to_string(),
+                    None,
+                );
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn check_index_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    index_expr: LuaIndexExpr,
+) -> Option<()> {
+    let index_token = index_expr.get_index_name_token()?;
+    let readonly = is_readonly_object_field(semantic_model, &index_expr, index_token.text())
+        || is_readonly_member(semantic_model, &index_expr).unwrap_or(false);
+    if !readonly {
+        return Some(());
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::ReadonlyAssign,
+        index_token.text_range(),
+        t!("Cannot assign to readonly %{name}", name = index_token.text()).to_string(),
+        None,
+    );
+
+    Some(())
+}
+
+fn is_readonly_member(semantic_model: &SemanticModel, index_expr: &LuaIndexExpr) -> Option<bool> {
+    let property_owner = semantic_model
+        .get_property_owner_id(rowan::NodeOrToken::Node(index_expr.syntax().clone()))?;
+    let member_id = LuaMemberId::new(index_expr.get_syntax_id(), semantic_model.get_file_id());
+    if let LuaPropertyOwnerId::Member(id) = &property_owner {
+        if *id == member_id {
+            return Some(false);
+        }
+
+        // readonly fields are still initialized through `self` inside the class's own methods
+        if let LuaExpr::NameExpr(prefix) = index_expr.get_prefix_expr()? {
+            if is_own_method_receiver(semantic_model, &prefix, id).unwrap_or(false) {
+                return Some(false);
+            }
+        }
+    }
+
+    Some(is_readonly(semantic_model, property_owner))
+}
+
+// fields of `Readonly<T>` and other readonly object types
+fn is_readonly_object_field(
+    semantic_model: &SemanticModel,
+    index_expr: &LuaIndexExpr,
+    name: &str,
+) -> bool {
+    let Some(prefix_type) = index_expr
+        .get_prefix_expr()
+        .and_then(|prefix| semantic_model.infer_expr(prefix))
+    else {
+        return false;
+    };
+
+    // `Readonly<T>` is a generic alias, its object type only exists once instantiated
+    let prefix_type = match prefix_type {
+        LuaType::Generic(generic) => {
+            let db = semantic_model.get_db();
+            let substitutor = TypeSubstitutor::from_type_array(generic.get_params().clone());
+            let Some(origin) = db
+                .get_type_index()
+                .get_type_decl(&generic.get_base_type_id())
+                .filter(|type_decl| type_decl.is_alias())
+                .and_then(|type_decl| type_decl.get_alias_origin(db, Some(&substitutor)))
+            else {
+                return false;
+            };
+            origin
+        }
+        typ => typ,
+    };
+
+    match prefix_type {
+        LuaType::Object(object) => {
+            object.is_readonly()
+                && object
+                    .get_field(&LuaMemberKey::Name(name.into()))
+                    .is_some()
+        }
+        _ => false,
+    }
+}
+
+// `self` is the receiver of the nearest enclosing `function A:m()`, the write is allowed
+// when `A` is the class that owns the member
+fn is_own_method_receiver(
+    semantic_model: &SemanticModel,
+    prefix: &LuaNameExpr,
+    member_id: &LuaMemberId,
+) -> Option<bool> {
+    if prefix.get_name_text()? != "self" {
+        return Some(false);
+    }
+
+    let method_name = prefix.ancestors::<LuaClosureExpr>().find_map(|closure| {
+        let func_stat = closure.get_parent::<LuaFuncStat>()?;
+        match func_stat.get_func_name()? {
+            LuaVarExpr::IndexExpr(name) if name.get_index_token()?.is_colon() => Some(name),
+            _ => None,
+        }
+    })?;
+
+    let class_type = semantic_model.infer_expr(method_name.get_prefix_expr()?)?;
+    let class_id = match &class_type {
+        LuaType::Def(id) | LuaType::Ref(id) => id,
+        _ => return Some(false),
+    };
+
+    let member = semantic_model
+        .get_db()
+        .get_member_index()
+        .get_member(member_id)?;
+    Some(member.get_owner().get_type_id() == Some(class_id))
+}
+
+fn is_readonly(semantic_model: &SemanticModel, property_owner: LuaPropertyOwnerId) -> bool {
+    semantic_model
+        .get_db()
+        .get_property_index()
+        .get_property(property_owner)
+        .is_some_and(|property| property.is_readonly)
+}
@@ -0,0 +1,262 @@
+use emmylua_parser::{
+    BinaryOperator, LuaAst, LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaIndexExpr,
+    LuaLiteralToken, LuaNameExpr, LuaVersionCondition, LuaVersionNumber, UnaryOperator,
+};
+use rowan::TextRange;
+
+use crate::{
+    DiagnosticCode, EmmyrcLuaVersion, LuaDeclId, LuaMemberId, LuaPropertyOwnerId, SemanticModel,
+};
+
+use super::DiagnosticContext;
+
+pub const CODES: &[DiagnosticCode] = &[DiagnosticCode::VersionMismatch];
+
+pub fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) -> Option<()> {
+    let version = semantic_model.get_emmyrc().runtime.version;
+    let version_number = version.to_lua_version_number();
+    let is_before_lua53 = matches!(
+        version,
+        EmmyrcLuaVersion::Lua51 | EmmyrcLuaVersion::LuaJIT | EmmyrcLuaVersion::Lua52
+    );
+    let root = semantic_model.get_root().clone();
+    for node in root.descendants::<LuaAst>() {
+        match node {
+            LuaAst::LuaNameExpr(name_expr) => {
+                check_name_expr(context, semantic_model, &version_number, name_expr);
+            }
+            LuaAst::LuaIndexExpr(index_expr) => {
+                check_index_expr(context, semantic_model, &version_number, index_expr);
+            }
+            LuaAst::LuaCallExpr(call_expr) => {
+                check_require_call(context, semantic_model, &version_number, call_expr);
+            }
+            LuaAst::LuaGotoStat(goto_stat) if version == EmmyrcLuaVersion::Lua51 => {
+                report_syntax(
+                    context,
+                    &version_number,
+                    "goto",
+                    "Lua 5.2",
+                    goto_stat.get_range(),
+                );
+            }
+            LuaAst::LuaLabelStat(label_stat) if version == EmmyrcLuaVersion::Lua51 => {
+                report_syntax(
+                    context,
+                    &version_number,
+                    "::label::",
+                    "Lua 5.2",
+                    label_stat.get_range(),
+                );
+            }
+            LuaAst::LuaBinaryExpr(binary_expr) if is_before_lua53 => {
+                if let Some(op_token) = binary_expr.get_op_token() {
+                    let syntax = match op_token.get_op() {
+                        BinaryOperator::OpIDiv => "//",
+                        BinaryOperator::OpBAnd => "&",
+                        BinaryOperator::OpBOr => "|",
+                        BinaryOperator::OpBXor => "~",
+                        BinaryOperator::OpShl => "<<",
+                        BinaryOperator::OpShr => ">>",
+                        _ => continue,
+                    };
+                    report_syntax(
+                        context,
+                        &version_number,
+                        syntax,
+                        "Lua 5.3",
+                        op_token.get_range(),
+                    );
+                }
+            }
+            LuaAst::LuaUnaryExpr(unary_expr) if is_before_lua53 => {
+                if let Some(op_token) = unary_expr.get_op_token() {
+                    if op_token.get_op() == UnaryOperator::OpBNot {
+                        report_syntax(
+                            context,
+                            &version_number,
+                            "~",
+                            "Lua 5.3",
+                            op_token.get_range(),
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(())
+}
+
+fn check_name_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    version_number: &LuaVersionNumber,
+    name_expr: LuaNameExpr,
+) -> Option<()> {
+    let property_owner = semantic_model
+        .get_property_owner_id(rowan::NodeOrToken::Node(name_expr.syntax().clone()))?;
+
+    let decl_id = LuaDeclId::new(semantic_model.get_file_id(), name_expr.get_position());
+    if let LuaPropertyOwnerId::LuaDecl(id) = &property_owner {
+        if *id == decl_id {
+            return Some(());
+        }
+    }
+
+    let name_token = name_expr.get_name_token()?;
+    check_property_version(
+        context,
+        semantic_model,
+        version_number,
+        name_token.get_name_text(),
+        name_token.get_range(),
+        property_owner,
+    )
+}
+
+fn check_index_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    version_number: &LuaVersionNumber,
+    index_expr: LuaIndexExpr,
+) -> Option<()> {
+    let property_owner = semantic_model
+        .get_property_owner_id(rowan::NodeOrToken::Node(index_expr.syntax().clone()))?;
+    let member_id = LuaMemberId::new(index_expr.get_syntax_id(), semantic_model.get_file_id());
+    if let LuaPropertyOwnerId::Member(id) = &property_owner {
+        if *id == member_id {
+            return Some(());
+        }
+    }
+
+    let index_token = index_expr.get_index_name_token()?;
+    let name = match index_expr.get_prefix_expr() {
+        Some(LuaExpr::NameExpr(prefix)) => format!(
+            "{}.{}",
+            prefix.get_name_text().unwrap_or_default(),
+            index_token.text()
+        ),
+        _ => index_token.text().to_string(),
+    };
+    check_property_version(
+        context,
+        semantic_model,
+        version_number,
+        &name,
+        index_token.text_range(),
+        property_owner,
+    )
+}
+
+fn check_property_version(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    version_number: &LuaVersionNumber,
+    name: &str,
+    range: TextRange,
+    property_owner: LuaPropertyOwnerId,
+) -> Option<()> {
+    let property = semantic_model
+        .get_db()
+        .get_property_index()
+        .get_property(property_owner)?;
+    let version_conds = property.version_conds.as_ref()?;
+    if version_conds.iter().any(|cond| cond.check(version_number)) {
+        return Some(());
+    }
+
+    report_mismatch(context, version_number, name, version_conds, range);
+    Some(())
+}
+
+fn check_require_call(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    version_number: &LuaVersionNumber,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    let func_name = name_expr.get_name_text()?;
+    let emmyrc = semantic_model.get_emmyrc();
+    if func_name != "require" && !emmyrc.runtime.require_like_function.contains(&func_name) {
+        return None;
+    }
+
+    let first_arg = call_expr.get_args_list()?.get_args().next()?;
+    let LuaExpr::LiteralExpr(literal_expr) = first_arg else {
+        return None;
+    };
+    let LuaLiteralToken::String(module_path) = literal_expr.get_literal()? else {
+        return None;
+    };
+
+    let module_info = semantic_model
+        .get_db()
+        .get_module_index()
+        .find_module(&module_path.get_value())?;
+    let version_conds = module_info.version_conds.as_ref()?;
+    if module_info.is_visible(version_number) {
+        return Some(());
+    }
+
+    report_mismatch(
+        context,
+        version_number,
+        &module_info.full_module_name,
+        version_conds,
+        literal_expr.get_range(),
+    );
+    Some(())
+}
+
+fn report_mismatch(
+    context: &mut DiagnosticContext,
+    version_number: &LuaVersionNumber,
+    name: &str,
+    version_conds: &[LuaVersionCondition],
+    range: TextRange,
+) {
+    let message = t!(
+        "`%{name}` is not available in %{version}, requires %{conds}.",
+        name = name,
+        version = version_number,
+        conds = version_conds
+            .iter()
+            .map(|it| format!("{}", it))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    context.add_diagnostic(
+        DiagnosticCode::VersionMismatch,
+        range,
+        message.to_string(),
+        None,
+    );
+}
+
+fn report_syntax(
+    context: &mut DiagnosticContext,
+    version_number: &LuaVersionNumber,
+    syntax: &str,
+    required: &str,
+    range: TextRange,
+) {
+    let message = t!(
+        "`%{syntax}` is not supported in %{version}, requires >= %{required}.",
+        syntax = syntax,
+        version = version_number,
+        required = required
+    );
+
+    context.add_diagnostic(
+        DiagnosticCode::VersionMismatch,
+        range,
+        message.to_string(),
+        None,
+    );
+}
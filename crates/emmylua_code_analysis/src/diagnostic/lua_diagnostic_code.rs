@@ -56,6 +56,12 @@ pub enum DiagnosticCode {
     AwaitInSync,
     /// Doc tag usage error
     AnnotationUsageError,
+    /// Assign to readonly field or variable
+    ReadonlyAssign,
+    /// Generic argument does not satisfy its constraint
+    GenericConstraintMismatch,
+    /// Use of std members or syntax not available in the runtime version
+    VersionMismatch,
 
     #[serde(other)]
     None,
@@ -81,6 +87,9 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
         DiagnosticCode::LocalConstReassign => DiagnosticSeverity::ERROR,
         DiagnosticCode::DuplicateType => DiagnosticSeverity::WARNING,
         DiagnosticCode::AnnotationUsageError => DiagnosticSeverity::ERROR,
+        DiagnosticCode::ReadonlyAssign => DiagnosticSeverity::WARNING,
+        DiagnosticCode::GenericConstraintMismatch => DiagnosticSeverity::WARNING,
+        DiagnosticCode::VersionMismatch => DiagnosticSeverity::WARNING,
         _ => DiagnosticSeverity::WARNING,
     }
 }
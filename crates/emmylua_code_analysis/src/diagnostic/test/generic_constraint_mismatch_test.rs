@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_generic_constraint() {
+        let mut ws = crate::VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Animal
+        ---@field name string
+
+        ---@class Dog: Animal
+
+        ---@generic T: Animal
+        ---@param animal T
+        ---@return T
+        function feed(animal)
+            return animal
+        end
+        "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+        ---@type Dog
+        local dog
+        feed(dog)
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+        feed(1)
+        "#
+        ));
+    }
+}
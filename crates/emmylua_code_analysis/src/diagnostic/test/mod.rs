@@ -1,3 +1,6 @@
 mod await_in_sync_test;
 mod param_type_check_test;
-mod missing_parameter_test;
\ No newline at end of file
+mod missing_parameter_test;
+mod readonly_assign_test;
+mod generic_constraint_mismatch_test;
+mod version_mismatch_test;
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_readonly_field() {
+        let mut ws = crate::VirtualWorkspace::new();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@class Point
+        ---@field readonly x number
+        local Point = {}
+
+        ---@type Point
+        local p
+        p.x = 1
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@class Point2
+        ---@field readonly x number
+        local Point2 = {}
+
+        function Point2:init()
+            self.x = 1
+        end
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@class Point3
+        ---@field readonly x number
+        local Point3 = {}
+
+        ---@param self Point3
+        local function reset(self)
+            self.x = 0
+        end
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@class Point4
+        ---@field readonly x number
+
+        ---@class Other
+        local Other = {}
+
+        ---@type Point4
+        local p4
+
+        function Other:move()
+            local self = p4
+            self.x = 0
+        end
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_readonly_utility_type() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@class ReadonlyConfig
+        ---@field name string
+
+        ---@type Readonly<ReadonlyConfig>
+        local config
+        config.name = "a"
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@class WritableConfig
+        ---@field name string
+
+        ---@type Partial<WritableConfig>
+        local config
+        config.name = "a"
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_readonly_local() {
+        let mut ws = crate::VirtualWorkspace::new();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@readonly
+        local a = 1
+        a = 2
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ReadonlyAssign,
+            r#"
+        ---@readonly
+        local b = 1
+        local c = b
+        "#
+        ));
+    }
+}
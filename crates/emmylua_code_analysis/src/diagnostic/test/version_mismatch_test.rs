@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    fn new_workspace(version: EmmyrcLuaVersion) -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = version;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        ws
+    }
+
+    #[test]
+    fn test_std_member() {
+        let mut ws = new_workspace(EmmyrcLuaVersion::Lua51);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local t = table.move({}, 1, 1, 1)
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local n = utf8.len("abc")
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local env = getfenv(1)
+        local p = math.pow(2, 3)
+        "#
+        ));
+
+        let mut ws = new_workspace(EmmyrcLuaVersion::Lua54);
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local t = table.move({}, 1, 1, 1)
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local env = getfenv(1)
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_goto() {
+        let mut ws = new_workspace(EmmyrcLuaVersion::Lua51);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        for i = 1, 3 do
+            goto continue
+            ::continue::
+        end
+        "#
+        ));
+
+        let mut ws = new_workspace(EmmyrcLuaVersion::LuaJIT);
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        for i = 1, 3 do
+            goto continue
+            ::continue::
+        end
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_lua53_operators() {
+        let mut ws = new_workspace(EmmyrcLuaVersion::Lua52);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local a = 7 // 2
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local a = 1 & 3
+        local b = 1 | 3
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local a = 1 << 2
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local a = ~1
+        "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local a = 7 / 2 ~= 3
+        "#
+        ));
+
+        let mut ws = new_workspace(EmmyrcLuaVersion::LuaJIT);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local a = 1 ~ 3
+        "#
+        ));
+
+        let mut ws = new_workspace(EmmyrcLuaVersion::Lua53);
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::VersionMismatch,
+            r#"
+        local a = 7 // 2
+        local b = (1 & 3) | (1 ~ 3) | (1 << 2) | (8 >> 1) | ~1
+        "#
+        ));
+    }
+}
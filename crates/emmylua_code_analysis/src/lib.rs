@@ -17,7 +17,7 @@ pub use emmylua_codestyle::*;
 pub use locale::get_locale_code;
 use lsp_types::Uri;
 pub use profile::Profile;
-use resources::load_resource_std;
+use resources::{load_resource_framework, load_resource_std};
 pub use semantic::*;
 use std::{collections::HashSet, path::PathBuf, sync::Arc};
 pub use test_lib::VirtualWorkspace;
@@ -72,6 +72,55 @@ impl EmmyLuaAnalysis {
         self.update_files_by_path(files);
     }
 
+    /// load the bundled definition packs selected by `runtime.frameworkVersions` as libraries
+    pub fn init_framework_libs(&mut self, allow_create_resources_dir: bool) {
+        let frameworks = self.emmyrc.runtime.framework_versions.clone();
+        for framework in &frameworks {
+            let (root, files) =
+                match load_resource_framework(framework, allow_create_resources_dir) {
+                    Ok(result) => result,
+                    Err(message) => {
+                        log::warn!("{}", message);
+                        continue;
+                    }
+                };
+
+            self.add_library_workspace(root);
+            let files = files
+                .into_iter()
+                .map(|file| file.into_tuple())
+                .collect::<Vec<_>>();
+            self.update_files_by_path(files);
+        }
+    }
+
+    /// enable the LuaLS addons triggered by `files` and return the files of their libraries,
+    /// `collect_files` lists the files of the given roots with the updated config
+    pub fn load_luals_addons<F>(
+        &mut self,
+        files: &[LuaFileInfo],
+        collect_files: F,
+    ) -> Vec<LuaFileInfo>
+    where
+        F: FnOnce(&Vec<PathBuf>, &Emmyrc) -> Vec<LuaFileInfo>,
+    {
+        let addons = self.emmyrc.detect_luals_addons(files);
+        if addons.is_empty() {
+            return Vec::new();
+        }
+
+        self.update_config(Arc::new(self.emmyrc.with_luals_addons(&addons)));
+        let addon_libraries = addons
+            .iter()
+            .map(|addon| addon.library.clone())
+            .collect::<Vec<_>>();
+        for lib in &addon_libraries {
+            log::info!("add addon library: {:?}", lib);
+            self.add_library_workspace(lib.clone());
+        }
+        collect_files(&addon_libraries, &self.emmyrc)
+    }
+
     pub fn get_file_id(&self, uri: &Uri) -> Option<FileId> {
         self.compilation.get_db().get_vfs().get_file_id(uri)
     }
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use include_dir::{include_dir, Dir, DirEntry};
 
@@ -8,16 +8,64 @@ static RESOURCE_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/resources");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub fn load_resource_std(allow_create_resources_dir: bool) -> (PathBuf, Vec<LuaFileInfo>) {
+    load_resource_sub_dir("std", allow_create_resources_dir)
+}
+
+/// `frameworkVersions` entries are a framework name optionally followed by a version,
+/// eg "love2d 11.4", the error explains why an entry is not loaded
+pub fn load_resource_framework(
+    framework: &str,
+    allow_create_resources_dir: bool,
+) -> Result<(PathBuf, Vec<LuaFileInfo>), String> {
+    let mut parts = framework.split_whitespace();
+    let name = parts.next().unwrap_or_default().to_lowercase();
+    let (dir_name, pack_version) = match name.as_str() {
+        "love2d" | "love" => ("love2d", Some("11")),
+        "neovim" | "nvim" => ("neovim", Some("0.10")),
+        "openresty" | "ngx" => ("openresty", Some("1")),
+        "defold" => ("defold", Some("1")),
+        "roblox" => ("roblox", None),
+        "busted" => ("busted", Some("2")),
+        _ => return Err(format!("Unknown framework: {}", framework)),
+    };
+
+    if let Some(version) = parts.next() {
+        if !pack_version.is_some_and(|pack_version| is_version_match(version, pack_version)) {
+            return Err(format!(
+                "No bundled definitions for {} {}, the {} pack covers version {}",
+                dir_name,
+                version,
+                dir_name,
+                pack_version.unwrap_or("latest")
+            ));
+        }
+    }
+
+    let sub_dir = format!("frameworks/{}", dir_name);
+    Ok(load_resource_sub_dir(&sub_dir, allow_create_resources_dir))
+}
+
+/// "11.4" and "11" match the pack version "11", "110" does not
+fn is_version_match(version: &str, pack_version: &str) -> bool {
+    let version = version.trim_start_matches(['v', 'V']);
+    version == pack_version
+        || version
+            .strip_prefix(pack_version)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+fn load_resource_sub_dir(
+    sub_dir: &str,
+    allow_create_resources_dir: bool,
+) -> (PathBuf, Vec<LuaFileInfo>) {
     let exe_path = std::env::current_exe().unwrap();
     let exe_dir = exe_path.parent().unwrap();
     let resoucres_dir = exe_dir.join("resources");
-    let std_dir = resoucres_dir.join("std");
+    let root_dir = resoucres_dir.join(sub_dir);
 
     if allow_create_resources_dir {
-        let result = load_resource_from_file_system();
-        match result {
-            Some(files) => return (std_dir, files),
-            None => {}
+        if let Some(files) = load_resource_from_file_system(&root_dir) {
+            return (root_dir, files);
         }
     }
 
@@ -25,8 +73,8 @@ pub fn load_resource_std(allow_create_resources_dir: bool) -> (PathBuf, Vec<LuaF
     let files = files
         .into_iter()
         .filter_map(|file| {
-            if file.path.ends_with(".lua") {
-                let path = std_dir.join(&file.path).to_str().unwrap().to_string();
+            if file.path.ends_with(".lua") && Path::new(&file.path).starts_with(sub_dir) {
+                let path = resoucres_dir.join(&file.path).to_str().unwrap().to_string();
                 Some(LuaFileInfo {
                     path,
                     content: file.content,
@@ -37,10 +85,10 @@ pub fn load_resource_std(allow_create_resources_dir: bool) -> (PathBuf, Vec<LuaF
         })
         .collect::<_>();
 
-    (std_dir, files)
+    (root_dir, files)
 }
 
-fn load_resource_from_file_system() -> Option<Vec<LuaFileInfo>> {
+fn load_resource_from_file_system(root_dir: &Path) -> Option<Vec<LuaFileInfo>> {
     let exe_path = std::env::current_exe().unwrap();
     let exe_dir = exe_path.parent().unwrap();
     let resoucres_dir = exe_dir.join("resources");
@@ -81,13 +129,12 @@ fn load_resource_from_file_system() -> Option<Vec<LuaFileInfo>> {
         }
     }
 
-    let std_dir = resoucres_dir.join("std");
     let match_pattern = vec!["**/*.lua".to_string()];
-    let files = match load_workspace_files(&std_dir, &match_pattern, &Vec::new(), &Vec::new(), None)
+    let files = match load_workspace_files(root_dir, &match_pattern, &Vec::new(), &Vec::new(), None)
     {
         Ok(files) => files,
         Err(e) => {
-            log::error!("Failed to load std lib: {:?}", e);
+            log::error!("Failed to load resource dir: {:?}, {:?}", root_dir, e);
             vec![]
         }
     };
@@ -142,3 +189,68 @@ fn walk_resource_dir(dir: &Dir, files: &mut Vec<LuaFileInfo>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_resource_framework() {
+        let (root, files) = load_resource_framework("love2d 11.4", false).unwrap();
+        assert!(root.ends_with("frameworks/love2d"));
+        assert!(!files.is_empty());
+        assert!(files
+            .iter()
+            .all(|file| Path::new(&file.path).starts_with(&root)));
+
+        assert!(load_resource_framework("NeoVim", false).is_ok());
+        assert!(load_resource_framework("nvim v0.10.2", false).is_ok());
+        assert_eq!(
+            load_resource_framework("unknown", false).err().as_deref(),
+            Some("Unknown framework: unknown")
+        );
+        assert_eq!(
+            load_resource_framework("love2d 0.10.2", false)
+                .err()
+                .as_deref(),
+            Some("No bundled definitions for love2d 0.10.2, the love2d pack covers version 11")
+        );
+        assert!(load_resource_framework("love2d 110", false).is_err());
+        assert!(load_resource_framework("roblox 1", false).is_err());
+
+        let (std_root, std_files) = load_resource_std(false);
+        assert!(std_files
+            .iter()
+            .all(|file| Path::new(&file.path).starts_with(&std_root)));
+    }
+
+    #[test]
+    fn test_extend_framework_class() {
+        let mut ws = crate::VirtualWorkspace::new();
+        let mut emmyrc = crate::Emmyrc::default();
+        emmyrc.runtime.framework_versions = vec!["love2d".to_string()];
+        ws.analysis.update_config(emmyrc.into());
+        ws.analysis.init_framework_libs(false);
+
+        assert!(ws.check_code_for(
+            crate::DiagnosticCode::DuplicateType,
+            r#"
+            ---@class (partial) love.Object
+            local Object = {}
+
+            ---@return integer
+            function Object:getReferenceCount() end
+            "#
+        ));
+        ws.def(
+            r#"
+            ---@type love.Image
+            image = nil
+            "#,
+        );
+        assert_eq!(
+            ws.expr_ty("image:getReferenceCount()"),
+            crate::LuaType::Integer
+        );
+    }
+}
@@ -166,7 +166,7 @@ fn infer_type_doc_function(
     let mut overloads = Vec::new();
     for overload_id in operator_ids {
         let operator = operator_index.get_operator(overload_id)?;
-        let func = operator.get_call_operator_type()?;
+        let func = operator.get_call_operator_type(db)?;
         match func {
             LuaType::DocFunction(f) => {
                 overloads.push(f.clone());
@@ -213,8 +213,8 @@ fn infer_generic_type_doc_function(
     let mut overloads = Vec::new();
     for overload_id in operator_ids {
         let operator = operator_index.get_operator(overload_id)?;
-        let func = operator.get_call_operator_type()?;
-        let new_f = instantiate_type(db, func, &substitutor);
+        let func = operator.get_call_operator_type(db)?;
+        let new_f = instantiate_type(db, &func, &substitutor);
         match new_f {
             LuaType::DocFunction(f) => {
                 overloads.push(f.clone());
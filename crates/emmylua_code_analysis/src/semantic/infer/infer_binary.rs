@@ -64,24 +64,8 @@ fn infer_binary_custom_operator(
     right: &LuaType,
     op: LuaOperatorMetaMethod,
 ) -> InferResult {
-    let operators = get_custom_type_operator(db, left.clone(), op);
-    if let Some(operators) = operators {
-        for operator in operators {
-            let first_param = operator.get_operands().get(0)?;
-            if check_type_compact(db, first_param, right).is_ok() {
-                return Some(operator.get_result().clone());
-            }
-        }
-    }
-
-    let operators = get_custom_type_operator(db, right.clone(), op);
-    if let Some(operators) = operators {
-        for operator in operators {
-            let first_param = operator.get_operands().get(0)?;
-            if check_type_compact(db, first_param, left).is_ok() {
-                return Some(operator.get_result().clone());
-            }
-        }
+    if let Some(result) = find_binary_custom_operator(db, left, right, op) {
+        return Some(result);
     }
 
     match op {
@@ -103,6 +87,41 @@ fn infer_binary_custom_operator(
     }
 }
 
+fn find_binary_custom_operator(
+    db: &DbIndex,
+    left: &LuaType,
+    right: &LuaType,
+    op: LuaOperatorMetaMethod,
+) -> InferResult {
+    let operators = get_custom_type_operator(db, left.clone(), op);
+    if let Some(operators) = operators {
+        for operator in operators {
+            let operands = operator.get_operands(db);
+            let Some(first_param) = operands.first() else {
+                continue;
+            };
+            if check_type_compact(db, first_param, right).is_ok() {
+                return operator.get_result(db);
+            }
+        }
+    }
+
+    let operators = get_custom_type_operator(db, right.clone(), op);
+    if let Some(operators) = operators {
+        for operator in operators {
+            let operands = operator.get_right_owner_operands(db);
+            let Some(first_param) = operands.first() else {
+                continue;
+            };
+            if check_type_compact(db, first_param, left).is_ok() {
+                return operator.get_result(db);
+            }
+        }
+    }
+
+    None
+}
+
 fn infer_binary_expr_add(db: &DbIndex, left: LuaType, right: LuaType) -> InferResult {
     if left.is_number() && right.is_number() {
         return match (&left, &right) {
@@ -339,6 +358,15 @@ fn infer_binary_expr_shr(db: &DbIndex, left: LuaType, right: LuaType) -> InferRe
 }
 
 fn infer_binary_expr_concat(db: &DbIndex, left: LuaType, right: LuaType) -> InferResult {
+    // `__concat` is still called when only one side is a string or number
+    if left.is_custom_type() || right.is_custom_type() {
+        if let Some(result) =
+            find_binary_custom_operator(db, &left, &right, LuaOperatorMetaMethod::Concat)
+        {
+            return Some(result);
+        }
+    }
+
     if left.is_number() || left.is_string() || right.is_number() || right.is_string() {
         match (&left, &right) {
             (LuaType::StringConst(s1), LuaType::StringConst(s2)) => {
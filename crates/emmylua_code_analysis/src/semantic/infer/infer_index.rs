@@ -112,6 +112,16 @@ pub fn infer_member_by_member_key(
         LuaType::Global => infer_global_field_member(db, config, index_expr),
         LuaType::Instance(inst) => infer_instance_member(db, config, inst, index_expr, infer_guard),
         LuaType::Namespace(ns) => infer_namespace_member(db, config, ns, index_expr),
+        LuaType::Call(_) => {
+            let typ = instantiate_type(db, prefix_type, &TypeSubstitutor::new());
+            if typ.is_call() {
+                return None;
+            }
+            infer_member_by_member_key(db, config, &typ, index_expr, infer_guard)
+        }
+        LuaType::TplRef(tpl) => {
+            infer_member_by_member_key(db, config, tpl.get_constraint()?, index_expr, infer_guard)
+        }
         _ => None,
     }
 }
@@ -302,11 +312,18 @@ fn infer_generic_member(
     index_expr: LuaIndexMemberExpr,
 ) -> InferResult {
     let base_type = generic_type.get_base_type();
-    let member_type =
-        infer_member_by_member_key(db, config, &base_type, index_expr, &mut InferGuard::new())?;
-
     let generic_params = generic_type.get_params();
     let substitutor = TypeSubstitutor::from_type_array(generic_params.clone());
+    let type_decl = db
+        .get_type_index()
+        .get_type_decl(&generic_type.get_base_type_id())?;
+    if type_decl.is_alias() {
+        let origin = type_decl.get_alias_origin(db, Some(&substitutor))?;
+        return infer_member_by_member_key(db, config, &origin, index_expr, &mut InferGuard::new());
+    }
+
+    let member_type =
+        infer_member_by_member_key(db, config, &base_type, index_expr, &mut InferGuard::new())?;
     Some(instantiate_type(db, &member_type, &substitutor))
 }
 
@@ -409,6 +426,9 @@ pub fn infer_member_by_operator(
         LuaType::MemberPathExist(exist_field) => {
             infer_member_by_index_exist_field(db, config, exist_field, index_expr)
         }
+        LuaType::TplRef(tpl) => {
+            infer_member_by_operator(db, config, tpl.get_constraint()?, index_expr, infer_guard)
+        }
         _ => None,
     }
 }
@@ -460,31 +480,35 @@ fn infer_member_by_index_custom_type(
         if let Some(index_operator_ids) = operators_map.get(&LuaOperatorMetaMethod::Index) {
             for operator_id in index_operator_ids {
                 let operator = db.get_operator_index().get_operator(operator_id)?;
-                let operand_type = operator.get_operands().first()?;
-                if operand_type.is_string() {
+                let operands = operator.get_operands(db);
+                let operand_type = operands.first()?;
+                if operand_type.is_any() {
+                    // `__index` function without annotated key accepts any key
+                    return operator.get_result(db);
+                } else if operand_type.is_string() {
                     if member_key.is_string() || member_key.is_name() {
-                        return Some(operator.get_result().clone());
+                        return operator.get_result(db);
                     } else if member_key.is_expr() {
                         let expr = member_key.get_expr()?;
                         let expr_type = infer_expr(db, config, expr.clone())?;
                         if expr_type.is_string() {
-                            return Some(operator.get_result().clone());
+                            return operator.get_result(db);
                         }
                     }
                 } else if operand_type.is_number() {
                     if member_key.is_integer() {
-                        return Some(operator.get_result().clone());
+                        return operator.get_result(db);
                     } else if member_key.is_expr() {
                         let expr = member_key.get_expr()?;
                         let expr_type = infer_expr(db, config, expr.clone())?;
                         if expr_type.is_number() {
-                            return Some(operator.get_result().clone());
+                            return operator.get_result(db);
                         }
                     }
                 } else if let Some(expr) = member_key.get_expr() {
                     let expr_type = infer_expr(db, config, expr.clone())?;
                     if expr_type == *operand_type {
-                        return Some(operator.get_result().clone());
+                        return operator.get_result(db);
                     }
                 }
             }
@@ -638,13 +662,17 @@ fn infer_member_by_index_generic(
     let index_operator_ids = operator_maps.get(&LuaOperatorMetaMethod::Index)?;
     for index_operator_id in index_operator_ids {
         let index_operator = operator_index.get_operator(index_operator_id)?;
-        let operand_type = index_operator.get_operands().first()?;
+        let operands = index_operator.get_operands(db);
+        let operand_type = operands.first()?;
+        let index_result = index_operator.get_result(db)?;
         let instianted_operand_type = instantiate_type(db, &operand_type, &substitutor);
-        if instianted_operand_type.is_string() {
+        if instianted_operand_type.is_any() {
+            return Some(instantiate_type(db, &index_result, &substitutor));
+        } else if instianted_operand_type.is_string() {
             if member_key.is_string() || member_key.is_name() {
                 return Some(instantiate_type(
                     db,
-                    index_operator.get_result(),
+                    &index_result,
                     &substitutor,
                 ));
             } else if member_key.is_expr() {
@@ -653,7 +681,7 @@ fn infer_member_by_index_generic(
                 if expr_type.is_string() {
                     return Some(instantiate_type(
                         db,
-                        index_operator.get_result(),
+                        &index_result,
                         &substitutor,
                     ));
                 }
@@ -662,7 +690,7 @@ fn infer_member_by_index_generic(
             if member_key.is_integer() {
                 return Some(instantiate_type(
                     db,
-                    index_operator.get_result(),
+                    &index_result,
                     &substitutor,
                 ));
             } else if member_key.is_expr() {
@@ -671,7 +699,7 @@ fn infer_member_by_index_generic(
                 if expr_type.is_number() {
                     return Some(instantiate_type(
                         db,
-                        index_operator.get_result(),
+                        &index_result,
                         &substitutor,
                     ));
                 }
@@ -681,7 +709,7 @@ fn infer_member_by_index_generic(
             if expr_type == *operand_type {
                 return Some(instantiate_type(
                     db,
-                    index_operator.get_result(),
+                    &index_result,
                     &substitutor,
                 ));
             }
@@ -14,7 +14,7 @@ pub fn infer_unary_expr(
     let inner_type = infer_expr(db, config, inner_expr)?;
     match op {
         UnaryOperator::OpNot => infer_unary_expr_not(inner_type),
-        UnaryOperator::OpLen => Some(LuaType::Integer),
+        UnaryOperator::OpLen => infer_unary_expr_len(db, inner_type),
         UnaryOperator::OpUnm => infer_unary_expr_unm(db, inner_type),
         UnaryOperator::OpBNot => infer_unary_expr_bnot(db, inner_type),
         UnaryOperator::OpNop => Some(inner_type),
@@ -28,14 +28,15 @@ fn infer_unary_custom_operator(
 ) -> InferResult {
     let operators = get_custom_type_operator(db, inner.clone(), op);
     if let Some(operators) = operators {
-        for operator in operators {
-            return Some(operator.get_result().clone());
+        if let Some(operator) = operators.first() {
+            return operator.get_result(db);
         }
     }
 
     match op {
         LuaOperatorMetaMethod::Unm => Some(LuaType::Number),
         LuaOperatorMetaMethod::BNot => Some(LuaType::Integer),
+        LuaOperatorMetaMethod::Len => Some(LuaType::Integer),
         _ => None,
     }
 }
@@ -56,6 +57,14 @@ fn infer_unary_expr_unm(db: &DbIndex, inner_type: LuaType) -> InferResult {
     }
 }
 
+fn infer_unary_expr_len(db: &DbIndex, inner_type: LuaType) -> InferResult {
+    if inner_type.is_custom_type() {
+        return infer_unary_custom_operator(db, &inner_type, LuaOperatorMetaMethod::Len);
+    }
+
+    Some(LuaType::Integer)
+}
+
 fn infer_unary_expr_bnot(db: &DbIndex, inner_type: LuaType) -> InferResult {
     match inner_type {
         LuaType::IntegerConst(i) => Some(LuaType::IntegerConst(!i)),
@@ -24,4 +24,85 @@ mod test {
         let expected = ws.ty("AA");
         assert_eq!(ty, expected);
     }
+
+    #[test]
+    fn test_metatable_binary_operator() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Vec
+        local Vec = {}
+        Vec.__index = Vec
+
+        ---@param a Vec
+        ---@param b Vec
+        ---@return Vec
+        function Vec.__add(a, b)
+        end
+
+        ---@return Vec
+        Vec.__concat = function(a, b)
+        end
+
+        ---@type Vec
+        v1 = {}
+        "#,
+        );
+
+        let ty = ws.expr_ty("v1 + v1");
+        let expected = ws.ty("Vec");
+        assert_eq!(ty, expected);
+
+        let ty = ws.expr_ty("v1 .. 'suffix'");
+        assert_eq!(ty, expected);
+    }
+
+    #[test]
+    fn test_metatable_binary_operator_right_owner() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Str
+        local Str = {}
+        Str.__index = Str
+
+        ---@param a string
+        ---@param b Str
+        ---@return Str
+        function Str.__concat(a, b)
+        end
+
+        ---@type Str
+        s1 = {}
+        "#,
+        );
+
+        let ty = ws.expr_ty("'prefix' .. s1");
+        let expected = ws.ty("Str");
+        assert_eq!(ty, expected);
+    }
+
+    #[test]
+    fn test_metatable_call_operator() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Point
+        Point = {}
+
+        setmetatable(Point, {
+            ---@return Point
+            __call = function(cls, x, y)
+            end
+        })
+        "#,
+        );
+
+        let ty = ws.expr_ty("Point(1, 2)");
+        let expected = ws.ty("Point");
+        assert_eq!(ty, expected);
+    }
 }
@@ -7,7 +7,7 @@ use crate::{
     },
     semantic::{member::infer_members, type_check},
     DbIndex, GenericTpl, LuaAliasCallKind, LuaAliasCallType, LuaMemberKey, LuaPropertyOwnerId,
-    LuaSignatureId, TypeOps,
+    LuaSignatureId, LuaTypeDeclId, TypeOps,
 };
 
 use super::type_substitutor::{SubstitutorValue, TypeSubstitutor};
@@ -104,15 +104,28 @@ pub fn instantiate_doc_function(
         match &origin_param_type {
             LuaType::Variadic(inner) => {
                 if let LuaType::TplRef(tpl) = inner.deref() {
-                    if let Some(value) = substitutor.get(tpl.get_tpl_id()) {
-                        match value {
-                            SubstitutorValue::Params(params) => {
-                                for param in params {
-                                    new_params.push(param.clone());
-                                }
+                    match substitutor.get(tpl.get_tpl_id()) {
+                        Some(SubstitutorValue::Params(params)) => {
+                            for param in params {
+                                new_params.push(param.clone());
                             }
-                            _ => {}
                         }
+                        // a tuple captured from call args has no names, number them instead
+                        Some(SubstitutorValue::MultiTypes(types)) => {
+                            for (i, typ) in types.iter().enumerate() {
+                                new_params.push((format!("p{}", i + 1), Some(typ.clone())));
+                            }
+                        }
+                        Some(SubstitutorValue::Type(ty)) => {
+                            new_params.push(("p1".to_string(), Some(ty.clone())));
+                        }
+                        Some(SubstitutorValue::MultiBase(base)) => {
+                            new_params.push((
+                                origin_param.0.clone(),
+                                Some(LuaType::Variadic(base.clone().into())),
+                            ));
+                        }
+                        None => new_params.push(origin_param.clone()),
                     }
                 }
             }
@@ -183,7 +196,11 @@ fn instantiate_object(
         new_index_access.push((key, value));
     }
 
-    LuaType::Object(LuaObjectType::new_with_fields(new_fields, new_index_access).into())
+    LuaType::Object(
+        LuaObjectType::new_with_fields(new_fields, new_index_access)
+            .with_readonly(object.is_readonly())
+            .into(),
+    )
 }
 
 fn instantiate_union(db: &DbIndex, union: &LuaUnionType, substitutor: &TypeSubstitutor) -> LuaType {
@@ -366,6 +383,10 @@ fn instantiate_alias_call(
     alias_call: &LuaAliasCallType,
     substitutor: &TypeSubstitutor,
 ) -> LuaType {
+    if alias_call.get_call_kind() == LuaAliasCallKind::Mapped {
+        return instantiate_mapped_call(db, alias_call, substitutor);
+    }
+
     let operands = alias_call
         .get_operands()
         .iter()
@@ -419,12 +440,253 @@ fn instantiate_alias_call(
 
             return instantiate_select_call(&operands[0], &operands[1]);
         }
+        LuaAliasCallKind::Index => {
+            if operands.len() != 2 {
+                return LuaType::Unknown;
+            }
+
+            return instantiate_index_call(db, &operands[0], &operands[1]);
+        }
+        LuaAliasCallKind::ReturnType => {
+            if operands.len() != 1 {
+                return LuaType::Unknown;
+            }
+
+            return instantiate_return_type_call(db, &operands[0]);
+        }
+        LuaAliasCallKind::Parameters => {
+            if operands.len() != 1 {
+                return LuaType::Unknown;
+            }
+
+            return instantiate_parameters_call(db, &operands[0]);
+        }
+        LuaAliasCallKind::RemoveNil => {
+            if operands.len() != 1 {
+                return LuaType::Unknown;
+            }
+
+            if operands[0].contain_tpl() {
+                return LuaType::Call(
+                    LuaAliasCallType::new(LuaAliasCallKind::RemoveNil, operands).into(),
+                );
+            }
+
+            return remove_nil(&operands[0]);
+        }
+        LuaAliasCallKind::Readonly => {
+            if operands.len() != 1 {
+                return LuaType::Unknown;
+            }
+
+            return match &operands[0] {
+                LuaType::Object(object) => LuaType::Object(object.with_readonly(true).into()),
+                typ if typ.contain_tpl() => LuaType::Call(
+                    LuaAliasCallType::new(LuaAliasCallKind::Readonly, operands).into(),
+                ),
+                typ => typ.clone(),
+            };
+        }
         _ => {}
     }
 
     LuaType::Unknown
 }
 
+fn instantiate_mapped_call(
+    db: &DbIndex,
+    alias_call: &LuaAliasCallType,
+    substitutor: &TypeSubstitutor,
+) -> LuaType {
+    let operands = alias_call.get_operands();
+    if operands.len() != 3 {
+        return LuaType::Unknown;
+    }
+    let LuaType::TplRef(key_param) = &operands[0] else {
+        return LuaType::Unknown;
+    };
+
+    let keys = instantiate_type(db, &operands[1], substitutor);
+    if keys.contain_tpl() {
+        return LuaType::Call(
+            LuaAliasCallType::new(
+                LuaAliasCallKind::Mapped,
+                vec![operands[0].clone(), keys, operands[2].clone()],
+            )
+            .into(),
+        );
+    }
+
+    let mut fields = HashMap::new();
+    let mut index_access = Vec::new();
+    for key in flatten_union(&keys) {
+        let mut key_substitutor = substitutor.clone();
+        key_substitutor.insert_type(key_param.get_tpl_id(), key.clone());
+        let value = instantiate_type(db, &operands[2], &key_substitutor);
+        match key {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => {
+                fields.insert(LuaMemberKey::Name(s.deref().clone()), value);
+            }
+            LuaType::DocIntegerConst(i) | LuaType::IntegerConst(i) => {
+                fields.insert(LuaMemberKey::Integer(i), value);
+            }
+            _ => index_access.push((key, value)),
+        }
+    }
+
+    LuaType::Object(LuaObjectType::new_with_fields(fields, index_access).into())
+}
+
+fn instantiate_index_call(db: &DbIndex, source: &LuaType, key: &LuaType) -> LuaType {
+    if source.contain_tpl() || key.contain_tpl() {
+        return LuaType::Call(
+            LuaAliasCallType::new(LuaAliasCallKind::Index, vec![source.clone(), key.clone()])
+                .into(),
+        );
+    }
+
+    let members = infer_members(db, source).unwrap_or_default();
+    let mut result = Vec::new();
+    for key in flatten_union(key) {
+        match &key {
+            LuaType::DocStringConst(s) | LuaType::StringConst(s) => {
+                let member_key = LuaMemberKey::Name(s.deref().clone());
+                result.extend(
+                    members
+                        .iter()
+                        .filter(|m| m.key == member_key)
+                        .map(|m| m.typ.clone()),
+                );
+            }
+            LuaType::DocIntegerConst(i) | LuaType::IntegerConst(i) => {
+                if let LuaType::Array(base) = source {
+                    result.push(base.deref().clone());
+                }
+                let member_key = LuaMemberKey::Integer(*i);
+                result.extend(
+                    members
+                        .iter()
+                        .filter(|m| m.key == member_key)
+                        .map(|m| m.typ.clone()),
+                );
+            }
+            LuaType::String => {
+                result.extend(
+                    members
+                        .iter()
+                        .filter(|m| matches!(m.key, LuaMemberKey::Name(_)))
+                        .map(|m| m.typ.clone()),
+                );
+            }
+            LuaType::Integer | LuaType::Number => {
+                if let LuaType::Array(base) = source {
+                    result.push(base.deref().clone());
+                }
+                result.extend(
+                    members
+                        .iter()
+                        .filter(|m| matches!(m.key, LuaMemberKey::Integer(_)))
+                        .map(|m| m.typ.clone()),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    result.dedup();
+    match result.len() {
+        0 => LuaType::Unknown,
+        1 => result.remove(0),
+        _ => LuaType::Union(LuaUnionType::new(result).into()),
+    }
+}
+
+fn instantiate_return_type_call(db: &DbIndex, func: &LuaType) -> LuaType {
+    let returns = match func {
+        LuaType::DocFunction(doc_func) => doc_func.get_ret().to_vec(),
+        LuaType::Signature(signature_id) => match db.get_signature_index().get(signature_id) {
+            Some(signature) => signature
+                .return_docs
+                .iter()
+                .map(|ret| ret.type_ref.clone())
+                .collect(),
+            None => return LuaType::Unknown,
+        },
+        LuaType::Ref(type_decl_id) => {
+            return match get_alias_origin(db, type_decl_id) {
+                Some(origin) => instantiate_return_type_call(db, &origin),
+                None => LuaType::Unknown,
+            };
+        }
+        _ => return LuaType::Unknown,
+    };
+
+    match returns.len() {
+        0 => LuaType::Nil,
+        1 => returns[0].clone(),
+        _ => LuaType::MuliReturn(LuaMultiReturn::Multi(returns).into()),
+    }
+}
+
+fn instantiate_parameters_call(db: &DbIndex, func: &LuaType) -> LuaType {
+    let params = match func {
+        LuaType::DocFunction(doc_func) => doc_func.get_params().to_vec(),
+        LuaType::Signature(signature_id) => match db.get_signature_index().get(signature_id) {
+            Some(signature) => signature.get_type_params(),
+            None => return LuaType::Unknown,
+        },
+        LuaType::Ref(type_decl_id) => {
+            return match get_alias_origin(db, type_decl_id) {
+                Some(origin) => instantiate_parameters_call(db, &origin),
+                None => LuaType::Unknown,
+            };
+        }
+        _ => return LuaType::Unknown,
+    };
+
+    let types = params
+        .into_iter()
+        .map(|(_, typ)| typ.unwrap_or(LuaType::Any))
+        .collect();
+    LuaType::Tuple(LuaTupleType::new(types).into())
+}
+
+fn get_alias_origin(db: &DbIndex, type_decl_id: &LuaTypeDeclId) -> Option<LuaType> {
+    let type_decl = db.get_type_index().get_type_decl(type_decl_id)?;
+    if !type_decl.is_alias() {
+        return None;
+    }
+
+    type_decl.get_alias_origin(db, None)
+}
+
+fn remove_nil(typ: &LuaType) -> LuaType {
+    match typ {
+        LuaType::Nullable(inner) => remove_nil(inner),
+        LuaType::Union(union) => {
+            let mut types = union
+                .get_types()
+                .iter()
+                .filter(|t| !t.is_nil())
+                .map(remove_nil)
+                .collect::<Vec<_>>();
+            match types.len() {
+                0 => LuaType::Unknown,
+                1 => types.remove(0),
+                _ => LuaType::Union(LuaUnionType::new(types).into()),
+            }
+        }
+        _ => typ.clone(),
+    }
+}
+
+fn flatten_union(typ: &LuaType) -> Vec<LuaType> {
+    match typ {
+        LuaType::Union(union) => union.get_types().iter().flat_map(flatten_union).collect(),
+        _ => vec![typ.clone()],
+    }
+}
+
 enum NumOrLen {
     Num(i64),
     Len,
@@ -5,7 +5,7 @@ use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaSyntaxNode};
 use crate::{
     db_index::{DbIndex, LuaType},
     semantic::{infer_expr, LuaInferConfig},
-    LuaFunctionType,
+    GenericTplId, LuaFunctionType,
 };
 
 use super::{
@@ -41,13 +41,14 @@ pub fn instantiate_func_generic(
         }
     }
 
-    let substitutor = match_tpl_args(
+    let mut substitutor = match_tpl_args(
         db,
         config,
         &func_param_types,
         &arg_types,
         &call_expr.get_root(),
     );
+    bind_uninferred_constraints(&func_param_types, func.get_ret(), &mut substitutor);
 
     if let LuaType::DocFunction(f) = instantiate_doc_function(db, func, &substitutor) {
         Some(f.deref().clone())
@@ -105,6 +106,51 @@ fn match_tpl_args(
     substitutor
 }
 
+/// a generic that can't be inferred from the args is at least its bound
+fn bind_uninferred_constraints(
+    func_param_types: &[LuaType],
+    func_ret_types: &[LuaType],
+    substitutor: &mut TypeSubstitutor,
+) {
+    let mut constraints = Vec::new();
+    for typ in func_param_types.iter().chain(func_ret_types.iter()) {
+        collect_tpl_constraints(typ, &mut constraints);
+    }
+
+    for (tpl_id, constraint) in constraints {
+        substitutor.insert_type(tpl_id, constraint);
+    }
+}
+
+fn collect_tpl_constraints(typ: &LuaType, constraints: &mut Vec<(GenericTplId, LuaType)>) {
+    match typ {
+        LuaType::TplRef(tpl) => {
+            if let Some(constraint) = tpl.get_constraint() {
+                constraints.push((tpl.get_tpl_id(), constraint.clone()));
+            }
+        }
+        LuaType::Nullable(inner) | LuaType::Array(inner) => {
+            collect_tpl_constraints(inner, constraints);
+        }
+        LuaType::Union(union) => {
+            for typ in union.get_types() {
+                collect_tpl_constraints(typ, constraints);
+            }
+        }
+        LuaType::DocFunction(func) => {
+            for (_, typ) in func.get_params() {
+                if let Some(typ) = typ {
+                    collect_tpl_constraints(typ, constraints);
+                }
+            }
+            for typ in func.get_ret() {
+                collect_tpl_constraints(typ, constraints);
+            }
+        }
+        _ => {}
+    }
+}
+
 // fn instantiate_func_by_return(
 //     db: &mut DbIndex,
 //     infer_config: &mut LuaInferConfig,
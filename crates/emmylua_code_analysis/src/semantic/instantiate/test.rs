@@ -83,4 +83,187 @@ mod test {
         assert_eq!(b, expected_b);
         assert_eq!(c, expected_c);
     }
+
+    #[test]
+    fn test_mapped_type() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(r#"
+        ---@class MappedConfig
+        ---@field name string
+        ---@field port integer
+        ---@field debug boolean?
+
+        ---@type { [K in keyof MappedConfig]: boolean }
+        local flags
+        a = flags.port
+
+        ---@type Partial<MappedConfig>
+        local partial
+        b = partial.name
+
+        ---@type Required<MappedConfig>
+        local required
+        c = required.debug
+
+        ---@type Pick<MappedConfig, "name">
+        local picked
+        d = picked.name
+
+        ---@type Omit<MappedConfig, "name" | "debug">
+        local omitted
+        e = omitted.port
+
+        ---@type Record<"x" | "y", number>
+        local record
+        f = record.y
+        "#);
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("string?"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("f"), ws.ty("number"));
+        assert!(ws.expr_ty("picked.port").is_unknown());
+        assert!(ws.expr_ty("omitted.name").is_unknown());
+    }
+
+    #[test]
+    fn test_not_null_and_required() {
+        use crate::LuaType;
+
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(r#"
+        ---@class NotNullConfig
+        ---@field debug boolean?
+
+        ---@class NotNullFields
+        ---@field flag boolean?
+        ---@field name string?
+
+        ---@type { [K in keyof NotNullFields]: std.NotNull<NotNullFields[K]> }
+        local not_null
+        a = not_null.flag
+        b = not_null.name
+
+        ---@type Required<NotNullConfig>
+        local required
+        c = required.debug
+
+        ---@type Readonly<NotNullConfig>
+        local readonly
+        d = readonly.debug
+        "#);
+
+        // `std.NotNull` narrows to the truthy part, `Required` only strips nil
+        assert_eq!(ws.expr_ty("a"), LuaType::BooleanConst(true));
+        assert_eq!(ws.expr_ty("b"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("boolean?"));
+    }
+
+    #[test]
+    fn test_function_utility_type() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(r#"
+        ---@alias Handler fun(name: string, count: integer): boolean
+
+        ---@generic F
+        ---@param f F
+        ---@return ReturnType<F>
+        local function call(f) end
+
+        ---@generic F
+        ---@param f F
+        ---@return Parameters<F>
+        local function args(f) end
+
+        ---@type Handler
+        local handler
+        a = call(handler)
+        b = args(handler)
+        "#);
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("[string, integer]"));
+    }
+
+    #[test]
+    fn test_variadic_generic() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+        ---@generic A..., R...
+        ---@param fn fun(...: A): R
+        ---@return fun(...: A): R
+        function memoize(fn)
+        end
+
+        ---@param a number
+        ---@param b string
+        ---@return boolean, string
+        function work(a, b)
+        end
+
+        cached = memoize(work)
+        ok, flag, msg = pcall(work, 1, "a")
+        wrapped = coroutine.wrap(work)
+        "#,
+        );
+
+        let expected = ws.ty("fun(a: number, b: string): boolean, string");
+        assert_eq!(ws.expr_ty("cached"), expected);
+        assert_eq!(ws.expr_ty("wrapped"), expected);
+        assert_eq!(ws.expr_ty("ok"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("flag"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("msg"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_variadic_generic_args() {
+        let mut ws = crate::VirtualWorkspace::new();
+        ws.def(
+            r#"
+        ---@generic A...
+        ---@param ... A
+        ---@return fun(...: A): A
+        function pack(...)
+        end
+
+        local n ---@type number
+        local s ---@type string
+        packed = pack(n, s)
+        "#,
+        );
+
+        let expected = ws.ty("fun(p1: number, p2: string): number, string");
+        assert_eq!(ws.expr_ty("packed"), expected);
+    }
+
+    #[test]
+    fn test_generic_constraint() {
+        let mut ws = crate::VirtualWorkspace::new();
+        ws.def(
+            r#"
+        ---@class Named
+        ---@field name string
+
+        ---@generic T: Named
+        ---@param value T
+        function get_name(value)
+            a = value.name
+        end
+
+        ---@generic T: Named
+        ---@return T
+        function make()
+        end
+
+        b = make()
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("Named"));
+    }
 }
@@ -48,6 +48,15 @@ fn infer_members_guard(
         LuaType::Global => infer_global_members(db),
         LuaType::Instance(inst) => infer_instance_members(db, inst, infer_guard),
         LuaType::Namespace(ns) => infer_namespace_members(db, ns),
+        LuaType::Call(_) => {
+            let typ = instantiate_type(db, prefix_type, &TypeSubstitutor::new());
+            if typ.is_call() {
+                return None;
+            }
+            infer_members_guard(db, &typ, infer_guard)
+        }
+        // inside a generic function, `T: Bound` values have the members of the bound
+        LuaType::TplRef(tpl) => infer_members_guard(db, tpl.get_constraint()?, infer_guard),
         _ => None,
     }
 }
@@ -206,10 +215,13 @@ fn infer_generic_members(
     infer_guard: &mut InferGuard,
 ) -> InferMembersResult {
     let base_type = generic_type.get_base_type();
-    let mut members = infer_members_guard(db, &base_type, infer_guard)?;
-
     let generic_params = generic_type.get_params();
     let substitutor = TypeSubstitutor::from_type_array(generic_params.clone());
+    if let Some(origin) = get_generic_alias_origin(db, generic_type, &substitutor) {
+        return infer_members_guard(db, &origin, infer_guard);
+    }
+
+    let mut members = infer_members_guard(db, &base_type, infer_guard)?;
     for info in members.iter_mut() {
         let origin_typ = info.typ.clone();
         info.typ = instantiate_type(db, &info.typ, &substitutor);
@@ -219,6 +231,21 @@ fn infer_generic_members(
     Some(members)
 }
 
+fn get_generic_alias_origin(
+    db: &DbIndex,
+    generic_type: &LuaGenericType,
+    substitutor: &TypeSubstitutor,
+) -> Option<LuaType> {
+    let type_decl = db
+        .get_type_index()
+        .get_type_decl(&generic_type.get_base_type_id())?;
+    if !type_decl.is_alias() {
+        return None;
+    }
+
+    type_decl.get_alias_origin(db, Some(substitutor))
+}
+
 fn infer_exist_field_members(
     db: &DbIndex,
     exist_field: &LuaMemberPathExistType,
@@ -22,9 +22,9 @@ pub fn without_members(type_: &LuaType) -> bool {
         | LuaType::Any
         | LuaType::SelfInfer
         | LuaType::StrTplRef(_)
-        | LuaType::TplRef(_)
         | LuaType::Array(_)
         | LuaType::MuliReturn(_) => true,
+        LuaType::TplRef(tpl) => tpl.get_constraint().is_none(),
         _ => false,
     }
 }
@@ -49,8 +49,8 @@ pub fn without_index_operator(type_: &LuaType) -> bool {
         | LuaType::Io
         | LuaType::Any
         | LuaType::StrTplRef(_)
-        | LuaType::TplRef(_)
         | LuaType::MuliReturn(_) => true,
+        LuaType::TplRef(tpl) => tpl.get_constraint().is_none(),
         _ => false,
     }
 }
@@ -36,7 +36,7 @@ use crate::{
 pub(crate) use call_func::infer_call_expr_func;
 pub(crate) use infer::infer_expr;
 pub use instantiate::{instantiate_type, TypeSubstitutor};
-use overload_resolve::resolve_signature;
+use overload_resolve::{resolve_signature, resolve_signature_with_index};
 pub use type_check::{TypeCheckFailReason, TypeCheckResult};
 
 #[derive(Debug)]
@@ -126,6 +126,24 @@ impl<'a> SemanticModel<'a> {
         )
     }
 
+    /// the position of the function in `overloads` which fits the arguments of `call_expr` best
+    pub fn resolve_overload_index(
+        &self,
+        call_expr: LuaCallExpr,
+        overloads: Vec<Arc<LuaFunctionType>>,
+        is_generic: bool,
+    ) -> Option<usize> {
+        resolve_signature_with_index(
+            self.db,
+            &mut self.infer_config.borrow_mut(),
+            overloads,
+            call_expr,
+            is_generic,
+            None,
+        )
+        .map(|(idx, _)| idx)
+    }
+
     pub fn get_semantic_info(
         &self,
         node_or_token: NodeOrToken<LuaSyntaxNode, LuaSyntaxToken>,
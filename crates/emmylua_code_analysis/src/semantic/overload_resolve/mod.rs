@@ -17,6 +17,26 @@ pub fn resolve_signature(
     is_generic: bool,
     arg_count: Option<usize>,
 ) -> Option<Arc<LuaFunctionType>> {
+    resolve_signature_with_index(
+        db,
+        infer_config,
+        overloads,
+        call_expr,
+        is_generic,
+        arg_count,
+    )
+    .map(|(_, func)| func)
+}
+
+/// same as `resolve_signature`, also returns the position of the match in `overloads`
+pub fn resolve_signature_with_index(
+    db: &DbIndex,
+    infer_config: &mut LuaInferConfig,
+    overloads: Vec<Arc<LuaFunctionType>>,
+    call_expr: LuaCallExpr,
+    is_generic: bool,
+    arg_count: Option<usize>,
+) -> Option<(usize, Arc<LuaFunctionType>)> {
     let args = call_expr.get_args_list()?;
     let mut expr_types = Vec::new();
     for arg in args.get_args() {
@@ -49,9 +69,9 @@ fn resolve_signature_by_generic(
     call_expr: LuaCallExpr,
     expr_types: Vec<LuaType>,
     arg_count: Option<usize>,
-) -> Option<Arc<LuaFunctionType>> {
+) -> Option<(usize, Arc<LuaFunctionType>)> {
     let mut max_match: usize = 0;
-    let mut matched_func: Option<Arc<LuaFunctionType>> = None;
+    let mut matched_func: Option<(usize, Arc<LuaFunctionType>)> = None;
     let mut instantiate_funcs = Vec::new();
     for func in overloads {
         let instantiate_func =
@@ -59,7 +79,7 @@ fn resolve_signature_by_generic(
         instantiate_funcs.push(Arc::new(instantiate_func));
     }
 
-    for func in &instantiate_funcs {
+    for (idx, func) in instantiate_funcs.iter().enumerate() {
         let params = func.get_params();
         let mut match_count = 0;
         if params.len() < arg_count.unwrap_or(0) {
@@ -81,12 +101,15 @@ fn resolve_signature_by_generic(
         }
         if match_count > max_match {
             max_match = match_count;
-            matched_func = Some(func.clone());
+            matched_func = Some((idx, func.clone()));
         }
     }
 
     if matched_func.is_none() && !instantiate_funcs.is_empty() {
-        matched_func = Some(instantiate_funcs.last().cloned().unwrap());
+        matched_func = Some((
+            instantiate_funcs.len() - 1,
+            instantiate_funcs.last().cloned().unwrap(),
+        ));
     }
 
     matched_func
@@ -98,11 +121,11 @@ fn resolve_signature_by_args(
     expr_types: Vec<LuaType>,
     is_colon_call: bool,
     arg_count: Option<usize>,
-) -> Option<Arc<LuaFunctionType>> {
+) -> Option<(usize, Arc<LuaFunctionType>)> {
     let mut max_match: i32 = -1;
-    let mut matched_func: Option<Arc<LuaFunctionType>> = None;
+    let mut matched_func: Option<(usize, Arc<LuaFunctionType>)> = None;
 
-    for func in &overloads {
+    for (idx, func) in overloads.iter().enumerate() {
         let params = func.get_params();
         // 参数数量不足
         if params.len() < arg_count.unwrap_or(0) {
@@ -140,12 +163,12 @@ fn resolve_signature_by_args(
 
         if match_count > max_match {
             max_match = match_count;
-            matched_func = Some(func.clone());
+            matched_func = Some((idx, func.clone()));
             if match_count == (params.len() - jump_param) as i32 {
                 break;
             }
         }
     }
 
-    matched_func.or_else(|| overloads.last().cloned())
+    matched_func.or_else(|| Some((overloads.len().checked_sub(1)?, overloads.last().cloned()?)))
 }
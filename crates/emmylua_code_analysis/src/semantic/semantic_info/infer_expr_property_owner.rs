@@ -228,6 +228,13 @@ fn infer_member_property_owner_by_member_key(
             member_key,
             owner_guard.next_level()?,
         ),
+        LuaType::TplRef(tpl) => infer_member_property_owner_by_member_key(
+            db,
+            config,
+            tpl.get_constraint()?,
+            member_key,
+            owner_guard.next_level()?,
+        ),
         _ => None,
     }
 }
@@ -204,9 +204,9 @@ fn check_doc_func_type_compact_for_custom_type(
                 .get_operator(operator_id)
                 .ok_or(TypeCheckFailReason::TypeNotMatch)?;
             let call_type = operator
-                .get_call_operator_type()
+                .get_call_operator_type(db)
                 .ok_or(TypeCheckFailReason::TypeNotMatch)?;
-            if let LuaType::DocFunction(doc_func) = call_type {
+            if let LuaType::DocFunction(doc_func) = &call_type {
                 if check_doc_func_type_compact_for_params(
                     db,
                     source_func,
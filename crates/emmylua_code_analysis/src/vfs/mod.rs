@@ -95,6 +95,10 @@ impl Vfs {
         self.emmyrc = Some(emmyrc);
     }
 
+    pub fn get_emmyrc(&self) -> Option<Arc<Emmyrc>> {
+        self.emmyrc.clone()
+    }
+
     pub fn get_file_content(&self, id: &FileId) -> Option<&String> {
         let opt = &self.file_data[id.id as usize];
         if let Some(s) = opt {
@@ -0,0 +1,536 @@
+use std::{collections::BTreeMap, path::Path};
+
+use crate::json_generator::{DocModel, MemberDoc, SignatureDoc};
+
+/// the semver bump a change needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Impact {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Impact {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Impact::Patch => "patch",
+            Impact::Minor => "minor",
+            Impact::Major => "major",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiChange {
+    pub path: String,
+    pub kind: ChangeKind,
+    pub impact: Impact,
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ApiDiff {
+    pub changes: Vec<ApiChange>,
+}
+
+impl ApiDiff {
+    pub fn impact(&self) -> Option<Impact> {
+        self.changes.iter().map(|change| change.impact).max()
+    }
+
+    fn added(&mut self, path: String) {
+        self.changes.push(ApiChange {
+            path,
+            kind: ChangeKind::Added,
+            impact: Impact::Minor,
+            detail: None,
+        });
+    }
+
+    fn removed(&mut self, path: String) {
+        self.changes.push(ApiChange {
+            path,
+            kind: ChangeKind::Removed,
+            impact: Impact::Major,
+            detail: None,
+        });
+    }
+
+    fn changed(&mut self, path: &str, impact: Impact, detail: String) {
+        self.changes.push(ApiChange {
+            path: path.to_string(),
+            kind: ChangeKind::Changed,
+            impact,
+            detail: Some(detail),
+        });
+    }
+}
+
+pub fn load_model(path: &Path) -> Option<DocModel> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", path.display(), e);
+            return None;
+        }
+    };
+    match serde_json::from_str(&text) {
+        Ok(model) => Some(model),
+        Err(e) => {
+            eprintln!("Failed to parse {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+pub fn diff_models(old: &DocModel, new: &DocModel) -> ApiDiff {
+    let mut diff = ApiDiff::default();
+
+    let old_types = old
+        .types
+        .iter()
+        .map(|typ| (typ.full_name.as_str(), typ))
+        .collect::<BTreeMap<_, _>>();
+    let new_types = new
+        .types
+        .iter()
+        .map(|typ| (typ.full_name.as_str(), typ))
+        .collect::<BTreeMap<_, _>>();
+    for (name, old_type) in &old_types {
+        let Some(new_type) = new_types.get(name) else {
+            diff.removed(format!("type {}", name));
+            continue;
+        };
+        let path = format!("type {}", name);
+        if old_type.kind != new_type.kind {
+            diff.changed(
+                &path,
+                Impact::Major,
+                format!("{:?} -> {:?}", old_type.kind, new_type.kind).to_lowercase(),
+            );
+        }
+        if old_type.alias_of != new_type.alias_of {
+            diff.changed(
+                &path,
+                Impact::Major,
+                format!(
+                    "alias {} -> {}",
+                    old_type.alias_of.as_deref().unwrap_or("?"),
+                    new_type.alias_of.as_deref().unwrap_or("?")
+                ),
+            );
+        }
+        for super_type in &old_type.supers {
+            if !new_type.supers.contains(super_type) {
+                diff.changed(
+                    &path,
+                    Impact::Major,
+                    format!("super {} removed", super_type),
+                );
+            }
+        }
+        for super_type in &new_type.supers {
+            if !old_type.supers.contains(super_type) {
+                diff.changed(&path, Impact::Minor, format!("super {} added", super_type));
+            }
+        }
+        if old_type.description != new_type.description {
+            diff.changed(&path, Impact::Patch, "description changed".to_string());
+        }
+        diff_members(&mut diff, name, &old_type.members, &new_type.members);
+    }
+    for name in new_types.keys() {
+        if !old_types.contains_key(name) {
+            diff.added(format!("type {}", name));
+        }
+    }
+
+    let old_modules = old
+        .modules
+        .iter()
+        .map(|module| (module.name.as_str(), module))
+        .collect::<BTreeMap<_, _>>();
+    let new_modules = new
+        .modules
+        .iter()
+        .map(|module| (module.name.as_str(), module))
+        .collect::<BTreeMap<_, _>>();
+    for (name, old_module) in &old_modules {
+        match new_modules.get(name) {
+            // a module exporting a class has its members diffed with the class
+            Some(new_module) if old_module.export_type.is_none() => {
+                diff_members(&mut diff, name, &old_module.members, &new_module.members)
+            }
+            Some(_) => {}
+            None => diff.removed(format!("module {}", name)),
+        }
+    }
+    for name in new_modules.keys() {
+        if !old_modules.contains_key(name) {
+            diff.added(format!("module {}", name));
+        }
+    }
+
+    let old_globals = old
+        .globals
+        .iter()
+        .map(|global| (global.name.as_str(), global))
+        .collect::<BTreeMap<_, _>>();
+    let new_globals = new
+        .globals
+        .iter()
+        .map(|global| (global.name.as_str(), global))
+        .collect::<BTreeMap<_, _>>();
+    for (name, old_global) in &old_globals {
+        let Some(new_global) = new_globals.get(name) else {
+            diff.removed(format!("global {}", name));
+            continue;
+        };
+        let path = format!("global {}", name);
+        match (&old_global.signature, &new_global.signature) {
+            (Some(old_signature), Some(new_signature)) => {
+                diff_signatures(&mut diff, &path, old_signature, new_signature)
+            }
+            (None, None) if old_global.members.is_empty() && new_global.members.is_empty() => {
+                if old_global.type_text != new_global.type_text {
+                    diff.changed(
+                        &path,
+                        Impact::Major,
+                        format!("type {} -> {}", old_global.type_text, new_global.type_text),
+                    );
+                }
+            }
+            (None, None) => diff_members(&mut diff, name, &old_global.members, &new_global.members),
+            _ => diff.changed(
+                &path,
+                Impact::Major,
+                format!("type {} -> {}", old_global.type_text, new_global.type_text),
+            ),
+        }
+        if old_global.description != new_global.description {
+            diff.changed(&path, Impact::Patch, "description changed".to_string());
+        }
+    }
+    for name in new_globals.keys() {
+        if !old_globals.contains_key(name) {
+            diff.added(format!("global {}", name));
+        }
+    }
+
+    diff
+}
+
+fn diff_members(diff: &mut ApiDiff, owner: &str, old: &[MemberDoc], new: &[MemberDoc]) {
+    let old_members = old
+        .iter()
+        .map(|member| (member.name.as_str(), member))
+        .collect::<BTreeMap<_, _>>();
+    let new_members = new
+        .iter()
+        .map(|member| (member.name.as_str(), member))
+        .collect::<BTreeMap<_, _>>();
+    for (name, old_member) in &old_members {
+        let path = format!("{}.{}", owner, name);
+        let Some(new_member) = new_members.get(name) else {
+            diff.removed(path);
+            continue;
+        };
+        match (&old_member.signature, &new_member.signature) {
+            (Some(old_signature), Some(new_signature)) => {
+                diff_signatures(diff, &path, old_signature, new_signature)
+            }
+            _ => {
+                if old_member.type_text != new_member.type_text {
+                    diff.changed(
+                        &path,
+                        Impact::Major,
+                        format!("type {} -> {}", old_member.type_text, new_member.type_text),
+                    );
+                }
+            }
+        }
+        if old_member.description != new_member.description {
+            diff.changed(&path, Impact::Patch, "description changed".to_string());
+        }
+    }
+    for name in new_members.keys() {
+        if !old_members.contains_key(name) {
+            diff.added(format!("{}.{}", owner, name));
+        }
+    }
+}
+
+/// new optional params and new returns are additive, anything else breaks callers
+fn diff_signatures(diff: &mut ApiDiff, path: &str, old: &SignatureDoc, new: &SignatureDoc) {
+    if old.is_colon != new.is_colon {
+        diff.changed(
+            path,
+            Impact::Major,
+            if new.is_colon {
+                "now called with `:`".to_string()
+            } else {
+                "now called with `.`".to_string()
+            },
+        );
+    }
+    if old.is_async != new.is_async {
+        diff.changed(path, Impact::Major, "async changed".to_string());
+    }
+
+    for (idx, old_param) in old.params.iter().enumerate() {
+        let Some(new_param) = new.params.get(idx) else {
+            diff.changed(
+                path,
+                Impact::Major,
+                format!("param `{}` removed", old_param.name),
+            );
+            continue;
+        };
+        if old_param.type_text != new_param.type_text {
+            diff.changed(
+                path,
+                Impact::Major,
+                format!(
+                    "param `{}` type {} -> {}",
+                    new_param.name,
+                    old_param.type_text.as_deref().unwrap_or("any"),
+                    new_param.type_text.as_deref().unwrap_or("any")
+                ),
+            );
+        }
+        if old_param.optional && !new_param.optional {
+            diff.changed(
+                path,
+                Impact::Major,
+                format!("param `{}` is now required", new_param.name),
+            );
+        } else if !old_param.optional && new_param.optional {
+            diff.changed(
+                path,
+                Impact::Minor,
+                format!("param `{}` is now optional", new_param.name),
+            );
+        }
+        if old_param.name != new_param.name {
+            diff.changed(
+                path,
+                Impact::Patch,
+                format!("param `{}` renamed to `{}`", old_param.name, new_param.name),
+            );
+        }
+    }
+    for new_param in new.params.iter().skip(old.params.len()) {
+        let impact = if new_param.optional {
+            Impact::Minor
+        } else {
+            Impact::Major
+        };
+        diff.changed(path, impact, format!("param `{}` added", new_param.name));
+    }
+
+    for (idx, old_ret) in old.returns.iter().enumerate() {
+        match new.returns.get(idx) {
+            Some(new_ret) if new_ret.type_text != old_ret.type_text => diff.changed(
+                path,
+                Impact::Major,
+                format!(
+                    "return #{} type {} -> {}",
+                    idx + 1,
+                    old_ret.type_text,
+                    new_ret.type_text
+                ),
+            ),
+            Some(_) => {}
+            None => diff.changed(path, Impact::Major, format!("return #{} removed", idx + 1)),
+        }
+    }
+    for idx in old.returns.len()..new.returns.len() {
+        diff.changed(path, Impact::Minor, format!("return #{} added", idx + 1));
+    }
+}
+
+pub fn print_diff(old: &DocModel, new: &DocModel, diff: &ApiDiff) {
+    if old.version != new.version {
+        eprintln!(
+            "warning: comparing doc format version {} with {}",
+            old.version, new.version
+        );
+    }
+
+    match diff.impact() {
+        Some(impact) => println!("API diff: {} change", impact.as_str()),
+        None => {
+            println!("API diff: no changes");
+            return;
+        }
+    }
+    for change in &diff.changes {
+        let kind = match change.kind {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        };
+        match &change.detail {
+            Some(detail) => println!(
+                "{:<5}  {:<7}  {}: {}",
+                change.impact.as_str(),
+                kind,
+                change.path,
+                detail
+            ),
+            None => println!(
+                "{:<5}  {:<7}  {}",
+                change.impact.as_str(),
+                kind,
+                change.path
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// a model with the single global function `f`
+    fn function_model(params: Value, description: &str) -> DocModel {
+        serde_json::from_value(json!({
+            "version": 1,
+            "types": [],
+            "modules": [],
+            "globals": [
+                {
+                    "name": "f",
+                    "type": "function",
+                    "description": description,
+                    "signature": { "params": params, "returns": [] }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn get_changes(diff: &ApiDiff) -> Vec<(String, Impact, Option<String>)> {
+        diff.changes
+            .iter()
+            .map(|change| (change.path.clone(), change.impact, change.detail.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_signatures() {
+        let old = function_model(json!([{ "name": "a", "type": "number" }]), "f");
+
+        let new = function_model(
+            json!([{ "name": "a", "type": "number" }, { "name": "b", "type": "string" }]),
+            "f",
+        );
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.impact(), Some(Impact::Major));
+        assert_eq!(
+            get_changes(&diff),
+            vec![(
+                "global f".to_string(),
+                Impact::Major,
+                Some("param `b` added".to_string())
+            )]
+        );
+
+        let new = function_model(
+            json!([
+                { "name": "a", "type": "number" },
+                { "name": "b", "type": "string", "optional": true }
+            ]),
+            "f",
+        );
+        assert_eq!(diff_models(&old, &new).impact(), Some(Impact::Minor));
+
+        let new = function_model(json!([{ "name": "a", "type": "integer" }]), "f");
+        assert_eq!(
+            get_changes(&diff_models(&old, &new)),
+            vec![(
+                "global f".to_string(),
+                Impact::Major,
+                Some("param `a` type number -> integer".to_string())
+            )]
+        );
+
+        let new = function_model(json!([{ "name": "a", "type": "number" }]), "g");
+        assert_eq!(diff_models(&old, &new).impact(), Some(Impact::Patch));
+        assert_eq!(diff_models(&old, &old).impact(), None);
+    }
+
+    #[test]
+    fn test_diff_members() {
+        let class = |members: Value| -> DocModel {
+            serde_json::from_value(json!({
+                "version": 1,
+                "types": [
+                    { "name": "A", "full_name": "A", "kind": "class", "members": members }
+                ],
+                "modules": [],
+                "globals": []
+            }))
+            .unwrap()
+        };
+        let old = class(json!([
+            { "name": "x", "kind": "field", "type": "number" },
+            { "name": "y", "kind": "field", "type": "number" }
+        ]));
+
+        let new = class(json!([{ "name": "x", "kind": "field", "type": "number" }]));
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.impact(), Some(Impact::Major));
+        assert_eq!(diff.changes[0].path, "A.y");
+        assert_eq!(diff.changes[0].kind, ChangeKind::Removed);
+
+        let new = class(json!([
+            { "name": "x", "kind": "field", "type": "string" },
+            { "name": "y", "kind": "field", "type": "number" },
+            { "name": "z", "kind": "field", "type": "number" }
+        ]));
+        assert_eq!(
+            get_changes(&diff_models(&old, &new)),
+            vec![
+                (
+                    "A.x".to_string(),
+                    Impact::Major,
+                    Some("type number -> string".to_string())
+                ),
+                ("A.z".to_string(), Impact::Minor, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_class_module() {
+        let model = |members: Value| -> DocModel {
+            serde_json::from_value(json!({
+                "version": 1,
+                "types": [
+                    { "name": "A", "full_name": "A", "kind": "class", "members": members }
+                ],
+                "modules": [
+                    { "name": "a", "file": "a.lua", "export_type": "A", "members": members }
+                ],
+                "globals": []
+            }))
+            .unwrap()
+        };
+        let old = model(json!([{ "name": "x", "kind": "field", "type": "number" }]));
+        let new = model(json!([]));
+        assert_eq!(
+            get_changes(&diff_models(&old, &new)),
+            vec![("A.x".to_string(), Impact::Major, None)]
+        );
+    }
+}
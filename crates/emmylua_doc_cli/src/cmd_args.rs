@@ -7,9 +7,10 @@ pub struct CmdArgs {
         parse(from_os_str),
         long = "input",
         short = "i",
+        required_unless = "diff",
         help = "The path of the lua project"
     )]
-    pub input: std::path::PathBuf,
+    pub input: Option<std::path::PathBuf>,
     /// The output path of the markdown file
     #[structopt(
         parse(from_os_str),
@@ -33,4 +34,55 @@ pub struct CmdArgs {
         help = "The path of the mixin md file"
     )]
     pub mixin: Option<std::path::PathBuf>,
+
+    #[structopt(
+        long = "format",
+        default_value = "markdown",
+        possible_values = &["markdown", "json", "html", "meta"],
+        help = "The output format: mkdocs markdown, a versioned json model, a static html site or ---@meta stubs"
+    )]
+    pub format: Format,
+
+    #[structopt(
+        long = "coverage",
+        help = "Print the documentation coverage per module instead of generating docs"
+    )]
+    pub coverage: bool,
+
+    #[structopt(
+        long = "coverage-threshold",
+        help = "Exit with code 1 when the total coverage in percent is below this value"
+    )]
+    pub coverage_threshold: Option<f64>,
+
+    #[structopt(
+        parse(from_os_str),
+        long = "diff",
+        number_of_values = 2,
+        value_names = &["OLD", "NEW"],
+        help = "Compare two json doc snapshots and print the api changes with their semver impact"
+    )]
+    pub diff: Vec<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    Json,
+    Html,
+    Meta,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            "html" => Ok(Format::Html),
+            "meta" => Ok(Format::Meta),
+            _ => Err(format!("unknown format: {}", s)),
+        }
+    }
 }
@@ -0,0 +1,258 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::json_generator::{DocModel, MemberDoc, MemberDocKind, SignatureDoc};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Counter {
+    pub documented: usize,
+    pub total: usize,
+}
+
+impl Counter {
+    fn count(&mut self, documented: bool) {
+        self.total += 1;
+        if documented {
+            self.documented += 1;
+        }
+    }
+
+    fn merge(&mut self, other: Counter) {
+        self.documented += other.documented;
+        self.total += other.total;
+    }
+}
+
+/// a function counts as documented with a description, params and returns need a type and a
+/// description, fields need a description and a known type
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CoverageStats {
+    pub functions: Counter,
+    pub params: Counter,
+    pub returns: Counter,
+    pub fields: Counter,
+}
+
+impl CoverageStats {
+    fn merge(&mut self, other: &CoverageStats) {
+        self.functions.merge(other.functions);
+        self.params.merge(other.params);
+        self.returns.merge(other.returns);
+        self.fields.merge(other.fields);
+    }
+
+    fn add_function(&mut self, description: &Option<String>, signature: &SignatureDoc) {
+        self.functions.count(description.is_some());
+        for param in &signature.params {
+            if param.name == "self" {
+                continue;
+            }
+            self.params
+                .count(param.type_text.is_some() && param.description.is_some());
+        }
+        for ret in &signature.returns {
+            self.returns.count(ret.description.is_some());
+        }
+    }
+
+    fn add_members(&mut self, members: &[MemberDoc]) {
+        for member in members {
+            match (&member.kind, &member.signature) {
+                (MemberDocKind::Method, Some(signature)) => {
+                    self.add_function(&member.description, signature)
+                }
+                (MemberDocKind::Method, None) => self.functions.count(member.description.is_some()),
+                (MemberDocKind::Field, _) => self
+                    .fields
+                    .count(member.description.is_some() && is_known_type(&member.type_text)),
+            }
+        }
+    }
+
+    pub fn percent(&self) -> f64 {
+        let mut total = Counter::default();
+        total.merge(self.functions);
+        total.merge(self.params);
+        total.merge(self.returns);
+        total.merge(self.fields);
+        percent(total)
+    }
+}
+
+fn is_known_type(type_text: &str) -> bool {
+    !matches!(type_text, "any" | "unknown" | "")
+}
+
+fn percent(counter: Counter) -> f64 {
+    if counter.total == 0 {
+        return 100.0;
+    }
+    counter.documented as f64 * 100.0 / counter.total as f64
+}
+
+/// coverage per module, files that are not a module are listed by path
+pub fn collect_coverage(model: &DocModel) -> BTreeMap<String, CoverageStats> {
+    let module_names = model
+        .modules
+        .iter()
+        .map(|module| (module.file.as_str(), module.name.as_str()))
+        .collect::<HashMap<_, _>>();
+    let group_name =
+        |file: &str| -> String { module_names.get(file).map_or(file, |name| name).to_string() };
+
+    let mut result: BTreeMap<String, CoverageStats> = BTreeMap::new();
+    for module in &model.modules {
+        let stats = result.entry(module.name.clone()).or_default();
+        // members of an exported class are counted with the class
+        if module.export_type.is_none() {
+            stats.add_members(&module.members);
+        }
+    }
+    for type_doc in &model.types {
+        result
+            .entry(group_name(&type_doc.file))
+            .or_default()
+            .add_members(&type_doc.members);
+    }
+    for global in &model.globals {
+        let stats = result.entry(group_name(&global.file)).or_default();
+        match &global.signature {
+            Some(signature) => stats.add_function(&global.description, signature),
+            None if global.members.is_empty() => stats
+                .fields
+                .count(global.description.is_some() && is_known_type(&global.type_text)),
+            None => stats.add_members(&global.members),
+        }
+    }
+
+    result
+}
+
+/// print the report and return the total coverage in percent
+pub fn print_coverage(model: &DocModel) -> f64 {
+    let coverage = collect_coverage(model);
+    let mut total = CoverageStats::default();
+    let name_width = coverage
+        .keys()
+        .map(|name| name.len())
+        .max()
+        .unwrap_or(0)
+        .max("module".len());
+
+    println!(
+        "{:<width$}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "module",
+        "functions",
+        "params",
+        "returns",
+        "fields",
+        "coverage",
+        width = name_width
+    );
+    for (name, stats) in &coverage {
+        print_row(name, stats, name_width);
+        total.merge(stats);
+    }
+    print_row("total", &total, name_width);
+
+    total.percent()
+}
+
+/// the message to exit with when the total coverage is below the threshold
+pub fn check_threshold(total: f64, threshold: Option<f64>) -> Result<(), String> {
+    match threshold {
+        Some(threshold) if total < threshold => Err(format!(
+            "Documentation coverage {:.1}% is below the threshold {:.1}%",
+            total, threshold
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn print_row(name: &str, stats: &CoverageStats, name_width: usize) {
+    let cell = |counter: Counter| format!("{}/{}", counter.documented, counter.total);
+    println!(
+        "{:<width$}  {:>10}  {:>10}  {:>10}  {:>10}  {:>7.1}%",
+        name,
+        cell(stats.functions),
+        cell(stats.params),
+        cell(stats.returns),
+        cell(stats.fields),
+        stats.percent(),
+        width = name_width
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_collect_coverage() {
+        let model: DocModel = serde_json::from_value(json!({
+            "version": 1,
+            "types": [
+                {
+                    "name": "Shape",
+                    "full_name": "Shape",
+                    "kind": "class",
+                    "file": "shape.lua",
+                    "members": [
+                        { "name": "name", "kind": "field", "type": "string", "description": "name" },
+                        { "name": "data", "kind": "field", "type": "any", "description": "data" }
+                    ]
+                }
+            ],
+            "modules": [
+                {
+                    "name": "shape",
+                    "file": "shape.lua",
+                    "export_type": "Shape"
+                },
+                {
+                    "name": "util",
+                    "file": "util.lua",
+                    "members": [
+                        {
+                            "name": "clamp",
+                            "kind": "method",
+                            "type": "fun(x: number, max: number): number",
+                            "description": "clamp x",
+                            "signature": {
+                                "params": [
+                                    { "name": "x", "type": "number", "description": "value" },
+                                    { "name": "max", "type": "number" }
+                                ],
+                                "returns": [{ "type": "number" }]
+                            }
+                        }
+                    ]
+                }
+            ],
+            "globals": [
+                { "name": "VERSION", "file": "init.lua", "type": "string" }
+            ]
+        }))
+        .unwrap();
+
+        let coverage = collect_coverage(&model);
+        assert_eq!(
+            coverage.keys().collect::<Vec<_>>(),
+            vec!["init.lua", "shape", "util"]
+        );
+        // an `any` field is not documented
+        assert_eq!(coverage["shape"].fields.documented, 1);
+        assert_eq!(coverage["shape"].percent(), 50.0);
+        // 1 function, 1 of 2 params, 0 of 1 returns
+        assert_eq!(coverage["util"].percent(), 50.0);
+        assert_eq!(coverage["init.lua"].percent(), 0.0);
+
+        assert!(check_threshold(50.0, None).is_ok());
+        assert!(check_threshold(50.0, Some(50.0)).is_ok());
+        assert_eq!(
+            check_threshold(49.5, Some(80.0)),
+            Err("Documentation coverage 49.5% is below the threshold 80.0%".to_string())
+        );
+    }
+}
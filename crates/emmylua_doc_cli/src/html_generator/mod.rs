@@ -0,0 +1,363 @@
+mod render;
+
+use std::path::{Path, PathBuf};
+
+use emmylua_code_analysis::EmmyLuaAnalysis;
+use include_dir::{include_dir, Dir};
+use serde::Serialize;
+use tera::{Context, Tera};
+
+use crate::{
+    json_generator::{build_doc_model, DocModel, TypeDocKind},
+    markdown_generator::escape_type_name,
+};
+
+use render::{
+    link_type_text, render_markdown, render_members, render_meta, render_signature,
+    signature_params, TypeLinks,
+};
+
+static HTML_TEMPLATE_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/template/html");
+
+pub fn generate_html(
+    analysis: &mut EmmyLuaAnalysis,
+    input: PathBuf,
+    output: PathBuf,
+) -> Option<()> {
+    let db = analysis.compilation.get_db();
+    let model = build_doc_model(db, &input);
+    let tl = init_html_tl()?;
+
+    for dir in ["types", "modules", "globals"] {
+        let dir_out = output.join(dir);
+        if dir_out.exists() {
+            println!("Clearing {} directory: {:?}", dir, dir_out);
+            std::fs::remove_dir_all(&dir_out).ok()?;
+        }
+        std::fs::create_dir_all(&dir_out).ok()?;
+    }
+
+    let links = build_type_links(&model);
+    let mut index = HtmlIndex::default();
+    generate_type_pages(&tl, &model, &links, &output, &mut index);
+    generate_module_pages(&tl, &model, &links, &output, &mut index);
+    generate_global_pages(&tl, &model, &links, &output, &mut index);
+
+    let mut context = Context::new();
+    context.insert("title", "Index");
+    context.insert("root", "");
+    context.insert("types", &index.types);
+    context.insert("modules", &index.modules);
+    context.insert("globals", &index.globals);
+    write_page(&tl, "index.html", &context, &output.join("index.html"))?;
+
+    let search_index = serde_json::to_string(&index.search).ok()?;
+    std::fs::write(
+        output.join("search_index.js"),
+        format!("window.SEARCH_INDEX = {};\n", search_index),
+    )
+    .ok()?;
+    for asset in ["style.css", "search.js"] {
+        let content = HTML_TEMPLATE_DIR.get_file(asset)?.contents();
+        std::fs::write(output.join(asset), content).ok()?;
+    }
+
+    println!("output html site: {}", output.join("index.html").display());
+    Some(())
+}
+
+fn init_html_tl() -> Option<Tera> {
+    let mut tera = Tera::default();
+    let files = HTML_TEMPLATE_DIR
+        .files()
+        .filter(|file| file.path().extension().is_some_and(|ext| ext == "html"))
+        .map(|file| {
+            let path = file.path().to_string_lossy().into_owned();
+            let content = file.contents_utf8().unwrap().to_string();
+            (path, content)
+        })
+        .collect::<Vec<_>>();
+
+    match tera.add_raw_templates(files) {
+        Ok(_) => Some(tera),
+        Err(e) => {
+            eprintln!("Failed to add templates: {}", e);
+            None
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+struct HtmlIndex {
+    pub types: Vec<IndexItem>,
+    pub modules: Vec<IndexItem>,
+    pub globals: Vec<IndexItem>,
+    pub search: Vec<SearchItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct IndexItem {
+    pub name: String,
+    pub file: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SearchItem {
+    pub name: String,
+    pub kind: String,
+    pub url: String,
+}
+
+/// both the full and the short name link to a type page, the full name wins on conflicts
+fn build_type_links(model: &DocModel) -> TypeLinks {
+    let mut links = TypeLinks::new();
+    for type_doc in &model.types {
+        let file = type_page_file(&type_doc.full_name);
+        links
+            .entry(type_doc.name.clone())
+            .or_insert_with(|| file.clone());
+    }
+    for type_doc in &model.types {
+        links.insert(
+            type_doc.full_name.clone(),
+            type_page_file(&type_doc.full_name),
+        );
+    }
+    links
+}
+
+fn type_page_file(full_name: &str) -> String {
+    format!("types/{}.html", escape_type_name(full_name))
+}
+
+fn add_member_search_items(
+    index: &mut HtmlIndex,
+    owner_name: &str,
+    file: &str,
+    methods: &[render::MemberHtml],
+    fields: &[render::MemberHtml],
+) {
+    for (members, kind) in [(methods, "method"), (fields, "field")] {
+        for member in members {
+            index.search.push(SearchItem {
+                name: member.name.clone(),
+                kind: format!("{} in {}", kind, owner_name),
+                url: format!("{}#{}", file, member.anchor),
+            });
+        }
+    }
+}
+
+fn generate_type_pages(
+    tl: &Tera,
+    model: &DocModel,
+    links: &TypeLinks,
+    output: &Path,
+    index: &mut HtmlIndex,
+) {
+    let root = "../";
+    for type_doc in &model.types {
+        let kind = match type_doc.kind {
+            TypeDocKind::Class => "class",
+            TypeDocKind::Enum => "enum",
+            TypeDocKind::Alias => "alias",
+        };
+        let file = type_page_file(&type_doc.full_name);
+        let mut context = Context::new();
+        context.insert("title", &type_doc.full_name);
+        context.insert("root", root);
+        context.insert("kind", kind);
+        context.insert("name", &type_doc.name);
+        context.insert("namespace", &type_doc.namespace);
+        if !type_doc.supers.is_empty() {
+            let supers = type_doc
+                .supers
+                .iter()
+                .map(|super_type| link_type_text(super_type, links, root))
+                .collect::<Vec<_>>();
+            context.insert("supers", &supers.join(", "));
+        }
+        if let Some(alias_of) = &type_doc.alias_of {
+            context.insert("alias_of", &link_type_text(alias_of, links, root));
+        }
+        if let Some(description) = &type_doc.description {
+            context.insert("description", &render_markdown(description));
+        }
+        context.insert("meta", &render_meta(&type_doc.meta, links, root));
+
+        let (methods, fields) = render_members(&type_doc.members, &type_doc.name, links, root);
+        add_member_search_items(index, &type_doc.name, &file, &methods, &fields);
+        context.insert("methods", &methods);
+        context.insert("fields", &fields);
+
+        if write_page(tl, "type.html", &context, &output.join(&file)).is_some() {
+            index.types.push(IndexItem {
+                name: format!("{} {}", kind, type_doc.full_name),
+                file: file.clone(),
+            });
+            index.search.push(SearchItem {
+                name: type_doc.full_name.clone(),
+                kind: kind.to_string(),
+                url: file,
+            });
+        }
+    }
+}
+
+fn generate_module_pages(
+    tl: &Tera,
+    model: &DocModel,
+    links: &TypeLinks,
+    output: &Path,
+    index: &mut HtmlIndex,
+) {
+    let root = "../";
+    for module_doc in &model.modules {
+        let file = format!("modules/{}.html", escape_type_name(&module_doc.name));
+        let mut context = Context::new();
+        context.insert("title", &module_doc.name);
+        context.insert("root", root);
+        context.insert("name", &module_doc.name);
+        context.insert("file", &module_doc.file);
+
+        let (methods, fields) = render_members(&module_doc.members, &module_doc.name, links, root);
+        add_member_search_items(index, &module_doc.name, &file, &methods, &fields);
+        context.insert("methods", &methods);
+        context.insert("fields", &fields);
+
+        if write_page(tl, "module.html", &context, &output.join(&file)).is_some() {
+            index.modules.push(IndexItem {
+                name: module_doc.name.clone(),
+                file: file.clone(),
+            });
+            index.search.push(SearchItem {
+                name: module_doc.name.clone(),
+                kind: "module".to_string(),
+                url: file,
+            });
+        }
+    }
+}
+
+fn generate_global_pages(
+    tl: &Tera,
+    model: &DocModel,
+    links: &TypeLinks,
+    output: &Path,
+    index: &mut HtmlIndex,
+) {
+    let root = "../";
+    for global_doc in &model.globals {
+        let file = format!("globals/{}.html", escape_type_name(&global_doc.name));
+        let mut context = Context::new();
+        context.insert("title", &global_doc.name);
+        context.insert("root", root);
+        context.insert("name", &global_doc.name);
+        if global_doc.members.is_empty() {
+            let display = match &global_doc.signature {
+                Some(signature) => {
+                    context.insert("params", &signature_params(signature));
+                    render_signature(signature, &global_doc.name, links, root)
+                }
+                None => format!(
+                    "{} : {}",
+                    render::escape_html(&global_doc.name),
+                    link_type_text(&global_doc.type_text, links, root)
+                ),
+            };
+            context.insert("display", &display);
+        }
+        if let Some(description) = &global_doc.description {
+            context.insert("description", &render_markdown(description));
+        }
+        context.insert("meta", &render_meta(&global_doc.meta, links, root));
+
+        let (methods, fields) = render_members(&global_doc.members, &global_doc.name, links, root);
+        add_member_search_items(index, &global_doc.name, &file, &methods, &fields);
+        context.insert("methods", &methods);
+        context.insert("fields", &fields);
+
+        if write_page(tl, "global.html", &context, &output.join(&file)).is_some() {
+            index.globals.push(IndexItem {
+                name: global_doc.name.clone(),
+                file: file.clone(),
+            });
+            index.search.push(SearchItem {
+                name: global_doc.name.clone(),
+                kind: "global".to_string(),
+                url: file,
+            });
+        }
+    }
+}
+
+fn write_page(tl: &Tera, template_name: &str, context: &Context, outpath: &Path) -> Option<()> {
+    let render_text = match tl.render(template_name, context) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Failed to render template: {}", e);
+            return None;
+        }
+    };
+
+    println!("output html file: {}", outpath.display());
+    match std::fs::write(outpath, render_text) {
+        Ok(_) => Some(()),
+        Err(e) => {
+            eprintln!("Failed to write file: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+
+    use super::generate_html;
+
+    #[test]
+    fn test_generate_html() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_files(vec![(
+            "shape.lua",
+            r#"
+            ---@class Shape
+            ---@field name string the display name
+            local Shape = {}
+
+            --- area of the shape
+            ---@return number
+            function Shape:area()
+                return 0
+            end
+
+            return Shape
+            "#,
+        )]);
+
+        let output = std::env::temp_dir().join(format!("emmylua_doc_html_{}", std::process::id()));
+        let input = ws.virtual_url_generator.base.clone();
+        generate_html(&mut ws.analysis, input, output.clone()).unwrap();
+
+        let read = |file: &str| std::fs::read_to_string(output.join(file)).unwrap();
+        let index = read("index.html");
+        let type_page = read("types/Shape.html");
+        let search_index = read("search_index.js");
+        let has_style = output.join("style.css").exists() && output.join("search.js").exists();
+        std::fs::remove_dir_all(&output).ok();
+
+        assert!(index.contains(r#"<a href="types&#x2F;Shape.html">class Shape</a>"#));
+        assert!(index.contains(r#"<a href="modules&#x2F;shape.html">shape</a>"#));
+        assert!(type_page.contains(r#"<h1><span class="kind">class</span> Shape</h1>"#));
+        assert!(type_page.contains(
+            "<h3>Shape:area</h3>\n  <pre><code>function Shape:area() -&gt; number</code></pre>\n  <p>area of the shape</p>"
+        ));
+        assert!(type_page.contains("<pre><code>Shape.name : string</code></pre>"));
+        assert!(search_index.starts_with("window.SEARCH_INDEX = ["));
+        assert!(search_index.contains(
+            r#"{"name":"Shape:area","kind":"method in Shape","url":"types/Shape.html#area"}"#
+        ));
+        assert!(has_style);
+    }
+}
@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+
+use crate::json_generator::{DocMeta, MemberDoc, MemberDocKind, SignatureDoc};
+
+/// type name -> page path relative to the site root
+pub type TypeLinks = HashMap<String, String>;
+
+pub fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// escape a type text and link every name that has its own page
+pub fn link_type_text(text: &str, links: &TypeLinks, root: &str) -> String {
+    let mut result = String::new();
+    let mut word = String::new();
+    let flush = |word: &mut String, result: &mut String| {
+        if word.is_empty() {
+            return;
+        }
+        match links.get(word.as_str()) {
+            Some(file) => result.push_str(&format!(
+                "<a href=\"{}{}\">{}</a>",
+                root,
+                file,
+                escape_html(word)
+            )),
+            None => result.push_str(&escape_html(word)),
+        }
+        word.clear();
+    };
+
+    for c in text.chars() {
+        if c.is_alphanumeric() || c == '_' || c == '.' {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut result);
+            result.push_str(&escape_html(&c.to_string()));
+        }
+    }
+    flush(&mut word, &mut result);
+    result
+}
+
+/// paragraphs, fenced code blocks and inline code, everything else is escaped
+pub fn render_markdown(text: &str) -> String {
+    let mut result = String::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut code: Option<Vec<String>> = None;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(code_lines) = &mut code {
+            if trimmed.starts_with("```") {
+                result.push_str(&format!(
+                    "<pre><code>{}</code></pre>\n",
+                    escape_html(&code_lines.join("\n"))
+                ));
+                code = None;
+            } else {
+                code_lines.push(line.to_string());
+            }
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            flush_paragraph(&mut paragraph, &mut result);
+            code = Some(Vec::new());
+        } else if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut result);
+        } else {
+            paragraph.push(render_inline(trimmed));
+        }
+    }
+    if let Some(code_lines) = code {
+        result.push_str(&format!(
+            "<pre><code>{}</code></pre>\n",
+            escape_html(&code_lines.join("\n"))
+        ));
+    }
+    flush_paragraph(&mut paragraph, &mut result);
+    result
+}
+
+fn flush_paragraph(paragraph: &mut Vec<String>, result: &mut String) {
+    if paragraph.is_empty() {
+        return;
+    }
+    result.push_str(&format!("<p>{}</p>\n", paragraph.join("\n")));
+    paragraph.clear();
+}
+
+fn render_inline(line: &str) -> String {
+    let mut result = String::new();
+    for (i, part) in line.split('`').enumerate() {
+        if i % 2 == 1 {
+            result.push_str(&format!("<code>{}</code>", escape_html(part)));
+        } else {
+            result.push_str(&escape_html(part));
+        }
+    }
+    result
+}
+
+pub fn render_meta(meta: &DocMeta, links: &TypeLinks, root: &str) -> String {
+    let mut items = Vec::new();
+    if let Some(deprecated) = &meta.deprecated {
+        if deprecated.is_empty() {
+            items.push("<li>deprecated</li>".to_string());
+        } else {
+            items.push(format!(
+                "<li>deprecated: {}</li>",
+                render_inline(deprecated)
+            ));
+        }
+    }
+    if let Some(since) = &meta.since {
+        items.push(format!("<li>since: {}</li>", escape_html(since)));
+    }
+    if !meta.authors.is_empty() {
+        items.push(format!(
+            "<li>author: {}</li>",
+            escape_html(&meta.authors.join(", "))
+        ));
+    }
+    for throws in &meta.throws {
+        let type_html = link_type_text(&throws.type_text, links, root);
+        match &throws.description {
+            Some(description) => items.push(format!(
+                "<li>throws: <code>{}</code> {}</li>",
+                type_html,
+                render_inline(description)
+            )),
+            None => items.push(format!("<li>throws: <code>{}</code></li>", type_html)),
+        }
+    }
+    for target in &meta.see {
+        if target.starts_with("http://") || target.starts_with("https://") {
+            items.push(format!(
+                "<li>see: <a href=\"{0}\">{0}</a></li>",
+                escape_html(target)
+            ));
+        } else {
+            let type_name = target.split(['.', ':', '#']).next().unwrap_or(target);
+            match links.get(type_name) {
+                Some(file) => items.push(format!(
+                    "<li>see: <a href=\"{}{}\"><code>{}</code></a></li>",
+                    root,
+                    file,
+                    escape_html(target)
+                )),
+                None => items.push(format!(
+                    "<li>see: <code>{}</code></li>",
+                    escape_html(target)
+                )),
+            }
+        }
+    }
+
+    let mut result = String::new();
+    if !items.is_empty() {
+        result.push_str(&format!("<ul class=\"meta\">{}</ul>\n", items.join("")));
+    }
+    for example in &meta.examples {
+        result.push_str(&format!(
+            "<p><strong>Example</strong></p>\n<pre><code>{}</code></pre>\n",
+            escape_html(example)
+        ));
+    }
+    result
+}
+
+/// the code line shown for a function, `function Player:heal(amount: integer) -> boolean`
+pub fn render_signature(
+    signature: &SignatureDoc,
+    func_name: &str,
+    links: &TypeLinks,
+    root: &str,
+) -> String {
+    let params = signature
+        .params
+        .iter()
+        .map(|param| {
+            let type_is_nullable = param
+                .type_text
+                .as_ref()
+                .is_some_and(|type_text| type_text.ends_with('?'));
+            let name = if param.optional && !type_is_nullable && !param.name.ends_with('?') {
+                format!("{}?", param.name)
+            } else {
+                param.name.clone()
+            };
+            match &param.type_text {
+                Some(type_text) => format!(
+                    "{}: {}",
+                    escape_html(&name),
+                    link_type_text(type_text, links, root)
+                ),
+                None => escape_html(&name),
+            }
+        })
+        .collect::<Vec<_>>();
+    let returns = signature
+        .returns
+        .iter()
+        .map(|ret| match &ret.name {
+            Some(name) => format!(
+                "{}: {}",
+                escape_html(name),
+                link_type_text(&ret.type_text, links, root)
+            ),
+            None => link_type_text(&ret.type_text, links, root),
+        })
+        .collect::<Vec<_>>();
+
+    let mut result = String::new();
+    if signature.is_async {
+        result.push_str("async ");
+    }
+    result.push_str(&format!(
+        "function {}({})",
+        escape_html(func_name),
+        params.join(", ")
+    ));
+    if !returns.is_empty() {
+        result.push_str(&format!(" -&gt; {}", returns.join(", ")));
+    }
+    result
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ParamDisplay {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct MemberHtml {
+    pub name: String,
+    pub anchor: String,
+    pub display: String,
+    pub params: Vec<ParamDisplay>,
+    pub description: String,
+    pub meta: String,
+}
+
+pub fn signature_params(signature: &SignatureDoc) -> Vec<ParamDisplay> {
+    let params = signature.params.iter().filter_map(|param| {
+        let description = param.description.as_ref()?;
+        Some(ParamDisplay {
+            name: param.name.clone(),
+            description: render_inline(description),
+        })
+    });
+    let returns = signature.returns.iter().filter_map(|ret| {
+        let description = ret.description.as_ref()?;
+        Some(ParamDisplay {
+            name: match &ret.name {
+                Some(name) => format!("return {}", name),
+                None => "return".to_string(),
+            },
+            description: render_inline(description),
+        })
+    });
+    params.chain(returns).collect()
+}
+
+/// split members into methods and fields, ready for `members.html`
+pub fn render_members(
+    members: &[MemberDoc],
+    owner_name: &str,
+    links: &TypeLinks,
+    root: &str,
+) -> (Vec<MemberHtml>, Vec<MemberHtml>) {
+    let mut methods = Vec::new();
+    let mut fields = Vec::new();
+    for member in members {
+        let separator = match &member.signature {
+            Some(signature) if signature.is_colon => ":",
+            _ => ".",
+        };
+        let full_name = format!("{}{}{}", owner_name, separator, member.name);
+        let (display, params) = match &member.signature {
+            Some(signature) => (
+                render_signature(signature, &full_name, links, root),
+                signature_params(signature),
+            ),
+            None => (
+                format!(
+                    "{} : {}",
+                    escape_html(&full_name),
+                    link_type_text(&member.type_text, links, root)
+                ),
+                Vec::new(),
+            ),
+        };
+        let member_html = MemberHtml {
+            name: full_name,
+            anchor: member.name.clone(),
+            display,
+            params,
+            description: member
+                .description
+                .as_deref()
+                .map(render_markdown)
+                .unwrap_or_default(),
+            meta: render_meta(&member.meta, links, root),
+        };
+        match member.kind {
+            MemberDocKind::Method => methods.push(member_html),
+            MemberDocKind::Field => fields.push(member_html),
+        }
+    }
+    (methods, fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_type_text() {
+        let mut links = TypeLinks::new();
+        links.insert("Player".to_string(), "types/Player.html".to_string());
+        links.insert("ns.Item".to_string(), "types/ns.Item.html".to_string());
+
+        assert_eq!(
+            link_type_text("table<string, Player>?", &links, "../"),
+            "table&lt;string, <a href=\"../types/Player.html\">Player</a>&gt;?"
+        );
+        assert_eq!(
+            link_type_text("fun(a: ns.Item): \"x\"|Other", &links, ""),
+            "fun(a: <a href=\"types/ns.Item.html\">ns.Item</a>): &quot;x&quot;|Other"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(
+            render_markdown("a <b> & `x < y`\nnext line\n\nsecond"),
+            "<p>a &lt;b&gt; &amp; <code>x &lt; y</code>\nnext line</p>\n<p>second</p>\n"
+        );
+        assert_eq!(
+            render_markdown("before\n```lua\nif a < b then\n```\nafter"),
+            "<p>before</p>\n<pre><code>if a &lt; b then</code></pre>\n<p>after</p>\n"
+        );
+        // an unclosed fence still ends the code block
+        assert_eq!(
+            render_markdown("```\n<script>"),
+            "<pre><code>&lt;script&gt;</code></pre>\n"
+        );
+    }
+}
@@ -36,8 +36,12 @@ pub fn load_workspace(workspace_folders: Vec<&str>) -> Option<EmmyLuaAnalysis> {
     }
 
     analysis.update_config(emmyrc);
+    analysis.init_framework_libs(false);
+
+    let mut file_infos = collect_files(&workspace_folders, &analysis.emmyrc);
+    let addon_files = analysis.load_luals_addons(&file_infos, collect_files);
+    file_infos.extend(addon_files);
 
-    let file_infos = collect_files(&workspace_folders, &analysis.emmyrc);
     let files = file_infos
         .into_iter()
         .map(|file| file.into_tuple())
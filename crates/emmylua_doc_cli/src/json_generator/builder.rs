@@ -0,0 +1,435 @@
+use std::path::Path;
+
+use emmylua_code_analysis::{
+    humanize_type, DbIndex, FileId, LuaDeclId, LuaMemberKey, LuaMemberOwner, LuaPropertyOwnerId,
+    LuaSignatureId, LuaType, LuaTypeDecl, ModuleInfo, RenderLevel,
+};
+use emmylua_parser::VisibilityKind;
+
+use super::model::{
+    DocMeta, DocModel, GlobalDoc, MemberDoc, MemberDocKind, ModuleDoc, ParamDoc, ReturnDoc,
+    SignatureDoc, ThrowsDoc, TypeDoc, TypeDocKind, DOC_FORMAT_VERSION,
+};
+
+pub fn build_doc_model(db: &DbIndex, input: &Path) -> DocModel {
+    let mut model = DocModel {
+        version: DOC_FORMAT_VERSION,
+        ..Default::default()
+    };
+
+    for type_decl in db.get_type_index().get_all_types() {
+        if let Some(type_doc) = build_type_doc(db, type_decl, input) {
+            model.types.push(type_doc);
+        }
+    }
+    model.types.sort_by(|a, b| a.full_name.cmp(&b.full_name));
+
+    for module in db.get_module_index().get_module_infos() {
+        if let Some(module_doc) = build_module_doc(db, module, input) {
+            model.modules.push(module_doc);
+        }
+    }
+    model.modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+    for decl_id in db.get_decl_index().get_global_decls() {
+        if let Some(global_doc) = build_global_doc(db, &decl_id, input) {
+            model.globals.push(global_doc);
+        }
+    }
+    model.globals.sort_by(|a, b| a.name.cmp(&b.name));
+
+    model
+}
+
+fn build_type_doc(db: &DbIndex, typ: &LuaTypeDecl, input: &Path) -> Option<TypeDoc> {
+    let main_location = typ.get_locations().iter().find(|loc| {
+        db.get_module_index()
+            .get_module(loc.file_id)
+            .is_some_and(|module| module.workspace_id.is_main())
+    })?;
+
+    let typ_id = typ.get_id();
+    let property_owner = LuaPropertyOwnerId::TypeDecl(typ_id.clone());
+    let kind = if typ.is_class() {
+        TypeDocKind::Class
+    } else if typ.is_enum() {
+        TypeDocKind::Enum
+    } else {
+        TypeDocKind::Alias
+    };
+
+    let mut type_doc = TypeDoc {
+        name: typ.get_name().to_string(),
+        full_name: typ.get_full_name().to_string(),
+        kind,
+        file: get_relative_file(db, main_location.file_id, input).unwrap_or_default(),
+        namespace: typ.get_namespace().map(|namespace| namespace.to_string()),
+        description: get_description(db, property_owner.clone()),
+        supers: Vec::new(),
+        alias_of: None,
+        members: Vec::new(),
+        meta: build_meta(db, property_owner),
+    };
+
+    match kind {
+        TypeDocKind::Class | TypeDocKind::Enum => {
+            if let Some(supers) = db.get_type_index().get_super_types(&typ_id) {
+                type_doc.supers = supers
+                    .iter()
+                    .map(|super_typ| humanize_type(db, super_typ, RenderLevel::Detailed))
+                    .collect();
+            }
+            type_doc.members = build_members(db, LuaMemberOwner::Type(typ_id));
+        }
+        TypeDocKind::Alias => {
+            type_doc.alias_of = typ
+                .get_alias_origin(db, None)
+                .map(|origin| humanize_type(db, &origin, RenderLevel::Detailed));
+        }
+    }
+
+    Some(type_doc)
+}
+
+fn build_module_doc(db: &DbIndex, module: &ModuleInfo, input: &Path) -> Option<ModuleDoc> {
+    if !module.workspace_id.is_main() {
+        return None;
+    }
+
+    let (member_owner, export_type) = match module.export_type.as_ref()? {
+        LuaType::Def(type_id) => (
+            LuaMemberOwner::Type(type_id.clone()),
+            Some(type_id.get_name().to_string()),
+        ),
+        LuaType::TableConst(t) => (LuaMemberOwner::Element(t.clone()), None),
+        LuaType::Instance(i) => (LuaMemberOwner::Element(i.get_range().clone()), None),
+        _ => return None,
+    };
+
+    Some(ModuleDoc {
+        name: module.full_module_name.clone(),
+        file: get_relative_file(db, module.file_id, input)?,
+        export_type,
+        members: build_members(db, member_owner),
+    })
+}
+
+fn build_global_doc(db: &DbIndex, decl_id: &LuaDeclId, input: &Path) -> Option<GlobalDoc> {
+    let module = db.get_module_index().get_module(decl_id.file_id)?;
+    if !module.workspace_id.is_main() {
+        return None;
+    }
+
+    let decl = db.get_decl_index().get_decl(decl_id)?;
+    let typ = decl.get_type()?;
+    if matches!(typ, LuaType::Ref(_) | LuaType::Def(_)) {
+        return None;
+    }
+
+    let property_owner = LuaPropertyOwnerId::LuaDecl(*decl_id);
+    let members = match typ {
+        LuaType::TableConst(table) => build_members(db, LuaMemberOwner::Element(table.clone())),
+        _ => Vec::new(),
+    };
+    Some(GlobalDoc {
+        name: decl.get_name().to_string(),
+        file: get_relative_file(db, decl_id.file_id, input).unwrap_or_default(),
+        type_text: humanize_type(db, typ, RenderLevel::Detailed),
+        description: get_description(db, property_owner.clone()),
+        signature: build_signature(db, typ),
+        members,
+        meta: build_meta(db, property_owner),
+    })
+}
+
+/// the file path relative to the input directory, always with `/` separators
+fn get_relative_file(db: &DbIndex, file_id: FileId, input: &Path) -> Option<String> {
+    let file_path = db.get_vfs().get_file_path(&file_id)?;
+    Some(
+        file_path
+            .strip_prefix(input)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .replace('\\', "/"),
+    )
+}
+
+fn build_members(db: &DbIndex, member_owner: LuaMemberOwner) -> Vec<MemberDoc> {
+    let Some(member_map) = db.get_member_index().get_member_map(member_owner) else {
+        return Vec::new();
+    };
+    let mut member_vecs = member_map.iter().collect::<Vec<_>>();
+    member_vecs.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut members = Vec::new();
+    for (member_name, member_id) in member_vecs {
+        let Some(member) = db.get_member_index().get_member(member_id) else {
+            continue;
+        };
+        let property_owner = LuaPropertyOwnerId::Member(*member_id);
+        if let Some(property) = db.get_property_index().get_property(property_owner.clone()) {
+            if property.visibility.unwrap_or(VisibilityKind::Public) != VisibilityKind::Public {
+                continue;
+            }
+        }
+
+        let name = match member_name {
+            LuaMemberKey::Name(name) => name.to_string(),
+            LuaMemberKey::Integer(i) => format!("[{}]", i),
+            _ => continue,
+        };
+        let member_typ = member.get_decl_type();
+        let kind = if member_typ.is_function() {
+            MemberDocKind::Method
+        } else {
+            MemberDocKind::Field
+        };
+        members.push(MemberDoc {
+            name,
+            kind,
+            type_text: humanize_type(db, member_typ, RenderLevel::Simple),
+            description: get_description(db, property_owner.clone()),
+            signature: build_signature(db, member_typ),
+            meta: build_meta(db, property_owner),
+        });
+    }
+
+    members
+}
+
+fn build_signature(db: &DbIndex, typ: &LuaType) -> Option<SignatureDoc> {
+    match typ {
+        LuaType::Signature(signature_id) => build_signature_doc(db, *signature_id),
+        LuaType::DocFunction(func) => Some(SignatureDoc {
+            is_async: func.is_async(),
+            is_colon: func.is_colon_define(),
+            params: func
+                .get_params()
+                .iter()
+                .map(|(name, typ)| ParamDoc {
+                    name: name.clone(),
+                    type_text: typ
+                        .as_ref()
+                        .map(|typ| humanize_type(db, typ, RenderLevel::Simple)),
+                    optional: typ.as_ref().is_some_and(|typ| typ.is_optional()),
+                    description: None,
+                })
+                .collect(),
+            returns: func
+                .get_ret()
+                .iter()
+                .map(|typ| ReturnDoc {
+                    name: None,
+                    type_text: humanize_type(db, typ, RenderLevel::Simple),
+                    description: None,
+                })
+                .collect(),
+        }),
+        _ => None,
+    }
+}
+
+fn build_signature_doc(db: &DbIndex, signature_id: LuaSignatureId) -> Option<SignatureDoc> {
+    let signature = db.get_signature_index().get(&signature_id)?;
+    let is_async = db
+        .get_property_index()
+        .get_property(LuaPropertyOwnerId::Signature(signature_id))
+        .is_some_and(|property| property.is_async);
+
+    let params = signature
+        .get_type_params()
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (name, typ))| {
+            let param_info = signature.get_param_info_by_id(idx);
+            ParamDoc {
+                name,
+                type_text: typ.map(|typ| humanize_type(db, &typ, RenderLevel::Simple)),
+                optional: param_info.is_some_and(|info| info.nullable),
+                description: param_info.and_then(|info| info.description.clone()),
+            }
+        })
+        .collect();
+    let returns = signature
+        .return_docs
+        .iter()
+        .map(|ret| ReturnDoc {
+            name: ret.name.clone(),
+            type_text: humanize_type(db, &ret.type_ref, RenderLevel::Simple),
+            description: ret.description.clone(),
+        })
+        .collect();
+
+    Some(SignatureDoc {
+        is_async,
+        is_colon: signature.is_colon_define,
+        params,
+        returns,
+    })
+}
+
+fn get_description(db: &DbIndex, property_owner: LuaPropertyOwnerId) -> Option<String> {
+    let property = db.get_property_index().get_property(property_owner)?;
+    property
+        .description
+        .as_ref()
+        .map(|description| description.to_string())
+        .filter(|description| !description.is_empty())
+}
+
+fn build_meta(db: &DbIndex, property_owner: LuaPropertyOwnerId) -> DocMeta {
+    let Some(property) = db.get_property_index().get_property(property_owner) else {
+        return DocMeta::default();
+    };
+
+    let deprecated = if property.is_deprecated {
+        Some(
+            property
+                .deprecated_message
+                .as_ref()
+                .map(|message| message.to_string())
+                .unwrap_or_default(),
+        )
+    } else {
+        None
+    };
+    let throws = property
+        .throws
+        .as_ref()
+        .map(|throws| {
+            throws
+                .iter()
+                .map(|(typ, description)| ThrowsDoc {
+                    type_text: humanize_type(db, typ, RenderLevel::Simple),
+                    description: description.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    DocMeta {
+        deprecated,
+        since: property.since.as_ref().map(|since| since.to_string()),
+        authors: property
+            .authors
+            .as_ref()
+            .map(|authors| authors.to_vec())
+            .unwrap_or_default(),
+        throws,
+        see: property
+            .see
+            .as_ref()
+            .map(|see| see.iter().map(|(target, _)| target.clone()).collect())
+            .unwrap_or_default(),
+        examples: property
+            .examples
+            .as_ref()
+            .map(|examples| examples.to_vec())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use serde_json::json;
+
+    use super::build_doc_model;
+
+    #[test]
+    fn test_build_doc_model() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_files(vec![
+            (
+                "shape.lua",
+                r#"
+                ---@class Shape
+                ---@field name string the display name
+                local Shape = {}
+
+                --- area of the shape
+                ---@param scale? number
+                ---@return number area
+                function Shape:area(scale)
+                    return 0
+                end
+
+                return Shape
+                "#,
+            ),
+            (
+                "util.lua",
+                r#"
+                ---@alias Color "red" | "green"
+
+                ---@deprecated use `paint2`
+                ---@param color Color
+                function paint(color)
+                end
+                "#,
+            ),
+        ]);
+
+        let db = ws.analysis.compilation.get_db();
+        let model = build_doc_model(db, &ws.virtual_url_generator.base);
+        let area = json!({
+            "name": "area",
+            "kind": "method",
+            "type": "fun(scale: number?) -> number",
+            "description": "area of the shape",
+            "signature": {
+                "is_colon": true,
+                "params": [{ "name": "scale", "type": "number?", "optional": true }],
+                "returns": [{ "name": "area", "type": "number" }]
+            }
+        });
+        let name = json!({
+            "name": "name",
+            "kind": "field",
+            "type": "string",
+            "description": "the display name"
+        });
+        assert_eq!(
+            serde_json::to_value(&model).unwrap(),
+            json!({
+                "version": 1,
+                "types": [
+                    {
+                        "name": "Color",
+                        "full_name": "Color",
+                        "kind": "alias",
+                        "file": "util.lua",
+                        "alias_of": "(\"red\"|\"green\")"
+                    },
+                    {
+                        "name": "Shape",
+                        "full_name": "Shape",
+                        "kind": "class",
+                        "file": "shape.lua",
+                        "members": [area, name]
+                    }
+                ],
+                "modules": [
+                    {
+                        "name": "shape",
+                        "file": "shape.lua",
+                        "export_type": "Shape",
+                        "members": [area, name]
+                    }
+                ],
+                "globals": [
+                    {
+                        "name": "paint",
+                        "file": "util.lua",
+                        "type": "fun(color: Color)",
+                        "signature": {
+                            "params": [{ "name": "color", "type": "Color" }],
+                            "returns": []
+                        },
+                        "meta": { "deprecated": "use `paint2`" }
+                    }
+                ]
+            })
+        );
+    }
+}
@@ -0,0 +1,39 @@
+mod builder;
+mod model;
+
+use std::path::PathBuf;
+
+use emmylua_code_analysis::EmmyLuaAnalysis;
+
+pub use builder::build_doc_model;
+pub use model::*;
+
+pub fn generate_json(
+    analysis: &mut EmmyLuaAnalysis,
+    input: PathBuf,
+    output: PathBuf,
+) -> Option<()> {
+    let db = analysis.compilation.get_db();
+    let model = build_doc_model(db, &input);
+    let text = match serde_json::to_string_pretty(&model) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Failed to serialize doc model: {}", e);
+            return None;
+        }
+    };
+
+    if !output.exists() {
+        std::fs::create_dir_all(&output).ok()?;
+    }
+    let outpath = output.join("doc.json");
+    println!("output json file: {}", outpath.display());
+    match std::fs::write(outpath, text) {
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to write file: {}", e);
+            return None;
+        }
+    }
+    Some(())
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+/// bumped whenever a field changes meaning or is removed, adding fields keeps the version
+pub const DOC_FORMAT_VERSION: u32 = 1;
+
+/// everything public in the main workspace, sorted by name so snapshots diff cleanly
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DocModel {
+    pub version: u32,
+    pub types: Vec<TypeDoc>,
+    pub modules: Vec<ModuleDoc>,
+    pub globals: Vec<GlobalDoc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeDocKind {
+    Class,
+    Enum,
+    Alias,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeDoc {
+    pub name: String,
+    pub full_name: String,
+    pub kind: TypeDocKind,
+    /// file of the first declaration, relative to the input directory
+    #[serde(default)]
+    pub file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias_of: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<MemberDoc>,
+    #[serde(default, skip_serializing_if = "DocMeta::is_empty")]
+    pub meta: DocMeta,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemberDocKind {
+    Method,
+    Field,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberDoc {
+    pub name: String,
+    pub kind: MemberDocKind,
+    #[serde(rename = "type")]
+    pub type_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<SignatureDoc>,
+    #[serde(default, skip_serializing_if = "DocMeta::is_empty")]
+    pub meta: DocMeta,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureDoc {
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_async: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_colon: bool,
+    pub params: Vec<ParamDoc>,
+    pub returns: Vec<ReturnDoc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamDoc {
+    pub name: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_text: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub optional: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReturnDoc {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub type_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleDoc {
+    pub name: String,
+    pub file: String,
+    /// the class returned by the module, its members are the class members
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<MemberDoc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalDoc {
+    pub name: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(rename = "type")]
+    pub type_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<SignatureDoc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<MemberDoc>,
+    #[serde(default, skip_serializing_if = "DocMeta::is_empty")]
+    pub meta: DocMeta,
+}
+
+/// structured doc tags of a declaration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DocMeta {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub throws: Vec<ThrowsDoc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub see: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
+}
+
+impl DocMeta {
+    pub fn is_empty(&self) -> bool {
+        self == &DocMeta::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThrowsDoc {
+    #[serde(rename = "type")]
+    pub type_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
@@ -1,25 +1,66 @@
-use cmd_args::CmdArgs;
+use cmd_args::{CmdArgs, Format};
 use structopt::StructOpt;
 
+mod api_diff;
 mod cmd_args;
+mod coverage;
+mod html_generator;
 mod init;
+mod json_generator;
 mod markdown_generator;
+mod meta_generator;
 
 fn main() {
     let args = CmdArgs::from_args();
-    let mut input = args.input;
+    if let [old, new] = args.diff.as_slice() {
+        let (Some(old_model), Some(new_model)) =
+            (api_diff::load_model(old), api_diff::load_model(new))
+        else {
+            std::process::exit(2);
+        };
+        let diff = api_diff::diff_models(&old_model, &new_model);
+        api_diff::print_diff(&old_model, &new_model, &diff);
+        return;
+    }
+
+    let Some(mut input) = args.input else {
+        return;
+    };
     if input.is_relative() {
         input = std::env::current_dir().ok().unwrap().join(&input);
     }
 
     let analysis = init::load_workspace(vec![input.to_str().unwrap()]);
     if let Some(mut analysis) = analysis {
-        markdown_generator::generate_markdown(
-            &mut analysis,
-            input,
-            args.output,
-            args.override_template,
-            args.mixin,
-        );
+        if args.coverage || args.coverage_threshold.is_some() {
+            let model = json_generator::build_doc_model(analysis.compilation.get_db(), &input);
+            let total = coverage::print_coverage(&model);
+            if let Err(message) = coverage::check_threshold(total, args.coverage_threshold) {
+                eprintln!("{}", message);
+                std::process::exit(1);
+            }
+            return;
+        }
+
+        match args.format {
+            Format::Markdown => {
+                markdown_generator::generate_markdown(
+                    &mut analysis,
+                    input,
+                    args.output,
+                    args.override_template,
+                    args.mixin,
+                );
+            }
+            Format::Json => {
+                json_generator::generate_json(&mut analysis, input, args.output);
+            }
+            Format::Html => {
+                html_generator::generate_html(&mut analysis, input, args.output);
+            }
+            Format::Meta => {
+                meta_generator::generate_meta(&mut analysis, input, args.output);
+            }
+        }
     }
 }
@@ -11,7 +11,7 @@ use crate::markdown_generator::{
 };
 
 use super::{
-    render::{render_const_type, render_function_type},
+    render::{render_const_type, render_function_type, render_property_meta},
     MkdocsIndex,
 };
 
@@ -86,6 +86,7 @@ fn check_filter(db: &DbIndex, decl_id: &LuaDeclId) -> Option<()> {
 
 fn generate_simple_global(db: &DbIndex, decl: &LuaDecl, context: &mut Context) -> Option<()> {
     let property_owner_id = LuaPropertyOwnerId::LuaDecl(decl.get_id());
+    context.insert("meta", &render_property_meta(db, property_owner_id.clone()));
     let property = db.get_property_index().get_property(property_owner_id);
 
     let description = if let Some(property) = property {
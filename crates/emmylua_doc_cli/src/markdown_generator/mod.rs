@@ -91,6 +91,7 @@ struct MemberDisplay {
     pub name: String,
     pub display: String,
     pub description: String,
+    pub meta: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -106,7 +107,7 @@ struct IndexStruct {
     pub file: String,
 }
 
-fn escape_type_name(name: &str) -> String {
+pub(crate) fn escape_type_name(name: &str) -> String {
     name.chars()
         .map(|c| {
             // Windows Invalid Characters
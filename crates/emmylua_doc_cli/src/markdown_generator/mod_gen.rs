@@ -10,7 +10,7 @@ use tera::{Context, Tera};
 use crate::markdown_generator::{escape_type_name, IndexStruct, MemberDisplay};
 
 use super::{
-    render::{render_const_type, render_function_type},
+    render::{render_const_type, render_function_type, render_property_meta},
     MkdocsIndex,
 };
 
@@ -96,6 +96,7 @@ pub fn generate_member_owner_module(
         for (member_name, member_id) in member_vecs {
             let member = db.get_member_index().get_member(member_id)?;
             let member_typ = member.get_decl_type();
+            let meta = render_property_meta(db, LuaPropertyOwnerId::Member(*member_id));
             let member_property_id = LuaPropertyOwnerId::Member(member_id.clone());
             let member_property = db.get_property_index().get_property(member_property_id);
             if let Some(member_property) = member_property {
@@ -128,6 +129,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display,
                     description,
+                    meta,
                 });
             } else if member_typ.is_const() {
                 let display = render_const_type(db, &member_typ);
@@ -135,6 +137,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display: format!("```lua\n{}.{}: {}\n```\n", owner_name, name, display),
                     description,
+                    meta,
                 });
             } else {
                 let typ_display = humanize_type(db, &member_typ, RenderLevel::Detailed);
@@ -142,6 +145,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display: format!("```lua\n{}.{} : {}\n```\n", owner_name, name, typ_display),
                     description,
+                    meta,
                 });
             }
         }
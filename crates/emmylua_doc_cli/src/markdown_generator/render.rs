@@ -3,6 +3,39 @@ use emmylua_code_analysis::{
     RenderLevel,
 };
 
+/// `@since`, `@author`, `@throws` and `@example` of the owner as markdown
+pub fn render_property_meta(db: &DbIndex, property_owner: LuaPropertyOwnerId) -> String {
+    let Some(property) = db.get_property_index().get_property(property_owner) else {
+        return String::new();
+    };
+
+    let mut result = String::new();
+    if let Some(since) = &property.since {
+        result.push_str(&format!("- since: {}\n", since));
+    }
+    if let Some(authors) = &property.authors {
+        result.push_str(&format!("- author: {}\n", authors.join(", ")));
+    }
+    if let Some(throws) = &property.throws {
+        for (typ, description) in throws.iter() {
+            let type_text = humanize_type(db, typ, RenderLevel::Simple);
+            match description {
+                Some(description) => {
+                    result.push_str(&format!("- throws: `{}` {}\n", type_text, description))
+                }
+                None => result.push_str(&format!("- throws: `{}`\n", type_text)),
+            }
+        }
+    }
+    if let Some(examples) = &property.examples {
+        for example in examples.iter() {
+            result.push_str(&format!("\n**Example**\n```lua\n{}\n```\n", example));
+        }
+    }
+
+    result
+}
+
 pub fn render_const_type(db: &DbIndex, typ: &LuaType) -> String {
     let const_value = humanize_type(db, typ, RenderLevel::Detailed);
 
@@ -222,3 +255,44 @@ fn render_typ(db: &DbIndex, typ: &LuaType) -> String {
         _ => humanize_type(db, typ, RenderLevel::Simple),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::{
+        LuaMemberKey, LuaMemberOwner, LuaPropertyOwnerId, LuaTypeDeclId, VirtualWorkspace,
+    };
+
+    use super::render_property_meta;
+
+    #[test]
+    fn test_render_property_meta() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class IOError
+
+            ---@class File
+            local File = {}
+
+            ---@since 1.2.0
+            ---@author CppCXY
+            ---@throws IOError when the file is closed
+            ---@example
+            ---    print(File:read())
+            function File:read() end
+            "#,
+        );
+
+        let db = ws.analysis.compilation.get_db();
+        let member_id = *db
+            .get_member_index()
+            .get_member_map(LuaMemberOwner::Type(LuaTypeDeclId::new("File")))
+            .unwrap()
+            .get(&LuaMemberKey::Name("read".into()))
+            .unwrap();
+        assert_eq!(
+            render_property_meta(db, LuaPropertyOwnerId::Member(member_id)),
+            "- since: 1.2.0\n- author: CppCXY\n- throws: `IOError` when the file is closed\n\n**Example**\n```lua\nprint(File:read())\n```\n"
+        );
+    }
+}
@@ -10,7 +10,7 @@ use tera::{Context, Tera};
 use crate::markdown_generator::{escape_type_name, IndexStruct, MemberDisplay};
 
 use super::{
-    render::{render_const_type, render_function_type},
+    render::{render_const_type, render_function_type, render_property_meta},
     MkdocsIndex,
 };
 
@@ -63,6 +63,7 @@ fn generate_class_type_markdown(
     context.insert("namespace", &namespace);
 
     let type_property_id = LuaPropertyOwnerId::TypeDecl(typ_id.clone());
+    context.insert("meta", &render_property_meta(db, type_property_id.clone()));
     let typ_property = db.get_property_index().get_property(type_property_id);
     if let Some(typ_property) = typ_property {
         if let Some(property_text) = &typ_property.description {
@@ -91,6 +92,7 @@ fn generate_class_type_markdown(
         for (member_name, member_id) in member_vecs {
             let member = db.get_member_index().get_member(member_id)?;
             let member_typ = member.get_decl_type();
+            let meta = render_property_meta(db, LuaPropertyOwnerId::Member(*member_id));
             let member_property_id = LuaPropertyOwnerId::Member(member_id.clone());
             let member_property = db.get_property_index().get_property(member_property_id);
             if let Some(member_property) = member_property {
@@ -124,6 +126,7 @@ fn generate_class_type_markdown(
                     name: title_name,
                     display,
                     description,
+                    meta,
                 });
             } else if member_typ.is_const() {
                 let const_type_display = render_const_type(db, &member_typ);
@@ -134,6 +137,7 @@ fn generate_class_type_markdown(
                         typ_name, name, const_type_display
                     ),
                     description,
+                    meta,
                 });
             } else {
                 let typ_display = humanize_type(db, &member_typ, RenderLevel::Detailed);
@@ -141,6 +145,7 @@ fn generate_class_type_markdown(
                     name: title_name,
                     display: format!("```lua\n{}.{} : {}\n```\n", typ_name, name, typ_display),
                     description,
+                    meta,
                 });
             }
         }
@@ -0,0 +1,30 @@
+mod stub_gen;
+mod type_text;
+
+use std::path::PathBuf;
+
+use emmylua_code_analysis::EmmyLuaAnalysis;
+
+pub fn generate_meta(
+    analysis: &mut EmmyLuaAnalysis,
+    input: PathBuf,
+    output: PathBuf,
+) -> Option<()> {
+    let db = analysis.compilation.get_db();
+    let stubs = stub_gen::build_stubs(db, &input);
+    for (file, text) in stubs {
+        let outpath = output.join(file);
+        if let Some(parent) = outpath.parent() {
+            std::fs::create_dir_all(parent).ok()?;
+        }
+        println!("output meta file: {}", outpath.display());
+        match std::fs::write(outpath, text) {
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("Failed to write file: {}", e);
+                return None;
+            }
+        }
+    }
+    Some(())
+}
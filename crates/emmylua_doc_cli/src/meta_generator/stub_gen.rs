@@ -0,0 +1,716 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
+
+use emmylua_code_analysis::{
+    DbIndex, FileId, LuaDeclId, LuaMemberKey, LuaMemberOwner, LuaPropertyOwnerId, LuaSignatureId,
+    LuaType, LuaTypeDecl, LuaTypeDeclId,
+};
+use emmylua_parser::{LuaSyntaxKind, VisibilityKind};
+use rowan::TextSize;
+
+use super::type_text::{render_type, render_type_without_nil};
+
+#[derive(Debug, Default)]
+struct StubFile {
+    items: Vec<(TextSize, String)>,
+    tail: Option<String>,
+}
+
+/// one `---@meta` file per main workspace file, keyed by the path relative to `input`
+pub fn build_stubs(db: &DbIndex, input: &Path) -> BTreeMap<String, String> {
+    let mut stub_files: HashMap<FileId, StubFile> = HashMap::new();
+
+    for type_decl in db.get_type_index().get_all_types() {
+        let Some(location) = type_decl
+            .get_locations()
+            .iter()
+            .find(|loc| is_main_file(db, loc.file_id))
+        else {
+            continue;
+        };
+        if let Some(text) = build_type_stub(db, type_decl) {
+            stub_files
+                .entry(location.file_id)
+                .or_default()
+                .items
+                .push((location.range.start(), text));
+        }
+    }
+
+    for decl_id in db.get_decl_index().get_global_decls() {
+        if !is_main_file(db, decl_id.file_id) {
+            continue;
+        }
+        if let Some(text) = build_global_stub(db, &decl_id) {
+            stub_files
+                .entry(decl_id.file_id)
+                .or_default()
+                .items
+                .push((decl_id.position, text));
+        }
+    }
+
+    for module in db.get_module_index().get_module_infos() {
+        if !module.workspace_id.is_main() {
+            continue;
+        }
+        let Some(export_type) = &module.export_type else {
+            continue;
+        };
+        if let Some(tail) = build_module_stub(db, module.file_id, export_type) {
+            stub_files.entry(module.file_id).or_default().tail = Some(tail);
+        }
+    }
+
+    let mut result = BTreeMap::new();
+    for (file_id, mut stub_file) in stub_files {
+        let Some(file_path) = db.get_vfs().get_file_path(&file_id) else {
+            continue;
+        };
+        let file = file_path
+            .strip_prefix(input)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        stub_file.items.sort_by_key(|(position, _)| *position);
+        let mut parts = vec!["---@meta".to_string()];
+        parts.extend(stub_file.items.into_iter().map(|(_, text)| text));
+        parts.extend(stub_file.tail);
+        result.insert(file, parts.join("\n\n") + "\n");
+    }
+
+    result
+}
+
+fn is_main_file(db: &DbIndex, file_id: FileId) -> bool {
+    db.get_module_index()
+        .get_module(file_id)
+        .is_some_and(|module| module.workspace_id.is_main())
+}
+
+/// the doc tags worth keeping in a stub, merged over the owners of one declaration
+#[derive(Debug, Default)]
+struct StubProperty {
+    description: Option<String>,
+    visibility: Option<VisibilityKind>,
+    deprecated: Option<String>,
+    is_async: bool,
+    is_nodiscard: bool,
+    see: Vec<String>,
+}
+
+impl StubProperty {
+    fn collect(db: &DbIndex, owners: &[LuaPropertyOwnerId]) -> Self {
+        let mut result = StubProperty::default();
+        for owner in owners {
+            let Some(property) = db.get_property_index().get_property(owner.clone()) else {
+                continue;
+            };
+            if result.description.is_none() {
+                result.description = property
+                    .description
+                    .as_ref()
+                    .map(|description| description.to_string());
+            }
+            if result.visibility.is_none() {
+                result.visibility = property.visibility;
+            }
+            if property.is_deprecated && result.deprecated.is_none() {
+                result.deprecated = Some(
+                    property
+                        .deprecated_message
+                        .as_ref()
+                        .map(|message| message.to_string())
+                        .unwrap_or_default(),
+                );
+            }
+            result.is_async |= property.is_async;
+            result.is_nodiscard |= property.is_nodiscard;
+            if let Some(see) = &property.see {
+                for (target, _) in see.iter() {
+                    if !result.see.contains(target) {
+                        result.see.push(target.clone());
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn is_private(&self) -> bool {
+        matches!(self.visibility, Some(VisibilityKind::Private))
+    }
+
+    fn push_description(&self, lines: &mut Vec<String>) {
+        let Some(description) = &self.description else {
+            return;
+        };
+        // text lines are trimmed when parsed back, lines inside code fences are kept verbatim
+        let mut in_fence = false;
+        for line in description.lines() {
+            let is_fence = line.trim_start().starts_with("```");
+            if line.is_empty() || (in_fence && !is_fence) {
+                lines.push(format!("---{}", line));
+            } else {
+                lines.push(format!("--- {}", line));
+            }
+            if is_fence {
+                in_fence = !in_fence;
+            }
+        }
+    }
+
+    fn push_tags(&self, lines: &mut Vec<String>) {
+        if let Some(message) = &self.deprecated {
+            if message.is_empty() {
+                lines.push("---@deprecated".to_string());
+            } else {
+                lines.push(format!("---@deprecated {}", message));
+            }
+        }
+        if matches!(self.visibility, Some(VisibilityKind::Protected)) {
+            lines.push("---@protected".to_string());
+        }
+        if self.is_async {
+            lines.push("---@async".to_string());
+        }
+        if self.is_nodiscard {
+            lines.push("---@nodiscard".to_string());
+        }
+        for target in &self.see {
+            lines.push(format!("---@see {}", target));
+        }
+    }
+}
+
+fn build_type_stub(db: &DbIndex, type_decl: &LuaTypeDecl) -> Option<String> {
+    let type_id = type_decl.get_id();
+    let property = StubProperty::collect(db, &[LuaPropertyOwnerId::TypeDecl(type_id.clone())]);
+    let mut lines = Vec::new();
+    property.push_description(&mut lines);
+    property.push_tags(&mut lines);
+
+    let generic_params = db
+        .get_type_index()
+        .get_generic_params(&type_id)
+        .map(|params| {
+            let names = params
+                .iter()
+                .map(|(name, constraint)| match constraint {
+                    Some(constraint) => format!("{}: {}", name, render_type(db, constraint)),
+                    None => name.clone(),
+                })
+                .collect::<Vec<_>>();
+            format!("<{}>", names.join(", "))
+        })
+        .unwrap_or_default();
+    let mut attribs = Vec::new();
+    if type_decl.is_exact() {
+        attribs.push("exact");
+    }
+    if type_decl.is_partial() {
+        attribs.push("partial");
+    }
+    if type_decl.is_enum_key() {
+        attribs.push("key");
+    }
+    let attrib_text = if attribs.is_empty() {
+        String::new()
+    } else {
+        format!("({}) ", attribs.join(", "))
+    };
+    let full_name = type_decl.get_full_name();
+
+    if type_decl.is_alias() {
+        match type_decl.get_alias_origin(db, None) {
+            Some(LuaType::MultiLineUnion(multi)) => {
+                lines.push(format!("---@alias {}{}", full_name, generic_params));
+                for (typ, description) in multi.get_unions() {
+                    match description {
+                        Some(description) => {
+                            lines.push(format!("---| {} # {}", render_type(db, typ), description))
+                        }
+                        None => lines.push(format!("---| {}", render_type(db, typ))),
+                    }
+                }
+            }
+            Some(origin) => lines.push(format!(
+                "---@alias {}{} {}",
+                full_name,
+                generic_params,
+                render_type(db, &origin)
+            )),
+            None => lines.push(format!("---@alias {}{} any", full_name, generic_params)),
+        }
+        return Some(lines.join("\n"));
+    }
+
+    let var_name = type_decl.get_name();
+    let declare = if is_global_type_table(db, &type_id, var_name) {
+        format!("{} = ", var_name)
+    } else {
+        format!("local {} = ", var_name)
+    };
+    let member_owner = LuaMemberOwner::Type(type_id.clone());
+
+    if type_decl.is_enum() {
+        lines.push(format!("---@enum {}{}", attrib_text, full_name));
+        let mut fields = Vec::new();
+        for member in get_sorted_members(db, member_owner) {
+            fields.push(format!(
+                "    {} = {},",
+                member_key_text(&member.key),
+                render_literal(&member.typ)
+            ));
+        }
+        if fields.is_empty() {
+            lines.push(format!("{}{{}}", declare));
+        } else {
+            lines.push(format!("{}{{\n{}\n}}", declare, fields.join("\n")));
+        }
+        return Some(lines.join("\n"));
+    }
+
+    let supers = db
+        .get_type_index()
+        .get_super_types(&type_id)
+        .map(|supers| {
+            supers
+                .iter()
+                .map(|super_type| render_type(db, super_type))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if supers.is_empty() {
+        lines.push(format!(
+            "---@class {}{}{}",
+            attrib_text, full_name, generic_params
+        ));
+    } else {
+        lines.push(format!(
+            "---@class {}{}{}: {}",
+            attrib_text,
+            full_name,
+            generic_params,
+            supers.join(", ")
+        ));
+    }
+
+    let mut functions = Vec::new();
+    for member in get_sorted_members(db, member_owner) {
+        if member.property.is_private() {
+            continue;
+        }
+        match &member.typ {
+            LuaType::Signature(signature_id) if !member.is_doc_field => {
+                if let Some(text) =
+                    build_function_stub(db, var_name, &member.key, *signature_id, member.property)
+                {
+                    functions.push(text);
+                }
+            }
+            _ => {
+                let visibility = match member.property.visibility {
+                    Some(VisibilityKind::Protected) => "protected ",
+                    _ => "",
+                };
+                let description = member
+                    .property
+                    .description
+                    .as_ref()
+                    .map(|description| format!(" {}", description.replace('\n', " ")))
+                    .unwrap_or_default();
+                lines.push(format!(
+                    "---@field {}{} {}{}",
+                    visibility,
+                    member_key_text(&member.key),
+                    render_type(db, &member.typ),
+                    description
+                ));
+            }
+        }
+    }
+    lines.push(format!("{}{{}}", declare));
+
+    let mut parts = vec![lines.join("\n")];
+    parts.extend(functions);
+    Some(parts.join("\n\n"))
+}
+
+/// `---@class Foo` followed by the global `Foo = {}` rather than a local
+fn is_global_type_table(db: &DbIndex, type_id: &LuaTypeDeclId, var_name: &str) -> bool {
+    let Some(decl_id) = db
+        .get_decl_index()
+        .get_global_decl_id(&LuaMemberKey::Name(var_name.into()))
+    else {
+        return false;
+    };
+    db.get_decl_index()
+        .get_decl(&decl_id)
+        .and_then(|decl| decl.get_type())
+        .is_some_and(|typ| matches!(typ, LuaType::Def(id) if id == type_id))
+}
+
+fn build_global_stub(db: &DbIndex, decl_id: &LuaDeclId) -> Option<String> {
+    let decl = db.get_decl_index().get_decl(decl_id)?;
+    let name = decl.get_name();
+    let typ = decl.get_type()?;
+    let mut owners = vec![LuaPropertyOwnerId::LuaDecl(*decl_id)];
+    if let LuaType::Signature(signature_id) = typ {
+        owners.push(LuaPropertyOwnerId::Signature(*signature_id));
+    }
+    let property = StubProperty::collect(db, &owners);
+
+    match typ {
+        // the class stub declares it
+        LuaType::Def(_) => None,
+        LuaType::Signature(signature_id) => {
+            let key = LuaMemberKey::Name(name.into());
+            build_function_stub(db, "", &key, *signature_id, property)
+        }
+        LuaType::TableConst(table) => {
+            let mut lines = Vec::new();
+            property.push_description(&mut lines);
+            property.push_tags(&mut lines);
+            lines.push(format!("{} = {{}}", name));
+            let mut parts = vec![lines.join("\n")];
+            parts.extend(build_table_member_stubs(
+                db,
+                LuaMemberOwner::Element(table.clone()),
+                name,
+            ));
+            Some(parts.join("\n\n"))
+        }
+        _ => {
+            let mut lines = Vec::new();
+            property.push_description(&mut lines);
+            property.push_tags(&mut lines);
+            lines.push(format!("---@type {}", render_type(db, typ)));
+            lines.push(format!("{} = nil", name));
+            Some(lines.join("\n"))
+        }
+    }
+}
+
+fn build_module_stub(db: &DbIndex, file_id: FileId, export_type: &LuaType) -> Option<String> {
+    match export_type {
+        LuaType::Def(type_id) => {
+            let type_decl = db.get_type_index().get_type_decl(type_id)?;
+            let declared_here = type_decl
+                .get_locations()
+                .first()
+                .is_some_and(|loc| loc.file_id == file_id);
+            if declared_here {
+                Some(format!("return {}", type_decl.get_name()))
+            } else {
+                Some(format!(
+                    "---@type {}\nlocal M\n\nreturn M",
+                    type_decl.get_full_name()
+                ))
+            }
+        }
+        LuaType::TableConst(table) => Some(build_module_table_stub(
+            db,
+            LuaMemberOwner::Element(table.clone()),
+        )),
+        LuaType::Instance(instance) => Some(build_module_table_stub(
+            db,
+            LuaMemberOwner::Element(instance.get_range().clone()),
+        )),
+        LuaType::Ref(_) | LuaType::Generic(_) => Some(format!(
+            "---@type {}\nlocal M\n\nreturn M",
+            render_type(db, export_type)
+        )),
+        _ => None,
+    }
+}
+
+fn build_module_table_stub(db: &DbIndex, member_owner: LuaMemberOwner) -> String {
+    let mut parts = vec!["local M = {}".to_string()];
+    parts.extend(build_table_member_stubs(db, member_owner, "M"));
+    parts.push("return M".to_string());
+    parts.join("\n\n")
+}
+
+fn build_table_member_stubs(
+    db: &DbIndex,
+    member_owner: LuaMemberOwner,
+    var_name: &str,
+) -> Vec<String> {
+    let mut parts = Vec::new();
+    for member in get_sorted_members(db, member_owner) {
+        if member.property.is_private() {
+            continue;
+        }
+        match &member.typ {
+            LuaType::Signature(signature_id) => {
+                if let Some(text) =
+                    build_function_stub(db, var_name, &member.key, *signature_id, member.property)
+                {
+                    parts.push(text);
+                }
+            }
+            _ => {
+                let mut lines = Vec::new();
+                member.property.push_description(&mut lines);
+                member.property.push_tags(&mut lines);
+                lines.push(format!("---@type {}", render_type(db, &member.typ)));
+                lines.push(format!("{} = nil", member_access(var_name, &member.key)));
+                parts.push(lines.join("\n"));
+            }
+        }
+    }
+    parts
+}
+
+struct StubMember {
+    key: LuaMemberKey,
+    typ: LuaType,
+    property: StubProperty,
+    /// declared by `---@field`, so a `fun()` type stays a field
+    is_doc_field: bool,
+}
+
+/// members sorted by their position in the source, each with its merged doc tags
+fn get_sorted_members(db: &DbIndex, member_owner: LuaMemberOwner) -> Vec<StubMember> {
+    let Some(member_map) = db.get_member_index().get_member_map(member_owner) else {
+        return Vec::new();
+    };
+    let mut members = Vec::new();
+    for (key, member_id) in member_map {
+        let Some(member) = db.get_member_index().get_member(member_id) else {
+            continue;
+        };
+        if matches!(key, LuaMemberKey::None) {
+            continue;
+        }
+        let typ = member.get_decl_type().clone();
+        let mut owners = vec![LuaPropertyOwnerId::Member(*member_id)];
+        if let LuaType::Signature(signature_id) = &typ {
+            owners.push(LuaPropertyOwnerId::Signature(*signature_id));
+        }
+        let position = (member.get_file_id(), member.get_range().start());
+        members.push((
+            position,
+            StubMember {
+                key: key.clone(),
+                typ,
+                property: StubProperty::collect(db, &owners),
+                is_doc_field: member_id.get_syntax_id().get_kind() == LuaSyntaxKind::DocTagField,
+            },
+        ));
+    }
+    members.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.key.cmp(&b.1.key)));
+    members.into_iter().map(|(_, member)| member).collect()
+}
+
+/// `function Owner:name(a, b) end` with its `---@param` and `---@return` tags, an empty
+/// `owner` declares a global function
+fn build_function_stub(
+    db: &DbIndex,
+    owner: &str,
+    name: &LuaMemberKey,
+    signature_id: LuaSignatureId,
+    property: StubProperty,
+) -> Option<String> {
+    let signature = db.get_signature_index().get(&signature_id)?;
+    let mut lines = Vec::new();
+    property.push_description(&mut lines);
+    property.push_tags(&mut lines);
+
+    for (generic_name, constraint) in &signature.generic_params {
+        match constraint {
+            Some(constraint) => lines.push(format!(
+                "---@generic {}: {}",
+                generic_name,
+                render_type(db, constraint)
+            )),
+            None => lines.push(format!("---@generic {}", generic_name)),
+        }
+    }
+    for (idx, (param_name, typ)) in signature.get_type_params().into_iter().enumerate() {
+        let param_info = signature.get_param_info_by_id(idx);
+        let Some(typ) = typ else {
+            continue;
+        };
+        let optional = param_info.is_some_and(|info| info.nullable) && param_name != "...";
+        let description = param_info
+            .and_then(|info| info.description.as_ref())
+            .map(|description| format!(" {}", description.replace('\n', " ")))
+            .unwrap_or_default();
+        let type_text = if optional {
+            render_type_without_nil(db, &typ)
+        } else {
+            render_type(db, &typ)
+        };
+        lines.push(format!(
+            "---@param {}{} {}{}",
+            param_name,
+            if optional { "?" } else { "" },
+            type_text,
+            description
+        ));
+    }
+    for ret in &signature.return_docs {
+        let mut line = format!("---@return {}", render_type(db, &ret.type_ref));
+        if let Some(name) = &ret.name {
+            line.push(' ');
+            line.push_str(name);
+        }
+        if let Some(description) = &ret.description {
+            line.push_str(" # ");
+            line.push_str(&description.replace('\n', " "));
+        }
+        lines.push(line);
+    }
+
+    let func_name = match (owner.is_empty(), name) {
+        (true, LuaMemberKey::Name(name)) => name.to_string(),
+        (false, LuaMemberKey::Name(name)) if is_identifier(name) => {
+            let separator = if signature.is_colon_define { ":" } else { "." };
+            format!("{}{}{}", owner, separator, name)
+        }
+        // not expressible as a function statement
+        _ => {
+            lines.push(format!(
+                "{} = function({}) end",
+                member_access(owner, name),
+                signature.params.join(", ")
+            ));
+            return Some(lines.join("\n"));
+        }
+    };
+    lines.push(format!(
+        "function {}({}) end",
+        func_name,
+        signature.params.join(", ")
+    ));
+    Some(lines.join("\n"))
+}
+
+fn render_literal(typ: &LuaType) -> String {
+    match typ {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => i.to_string(),
+        LuaType::FloatConst(f) => f.to_string(),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => format!("{:?}", s.as_str()),
+        LuaType::BooleanConst(b) => b.to_string(),
+        _ => "nil".to_string(),
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn member_access(owner: &str, key: &LuaMemberKey) -> String {
+    match key {
+        LuaMemberKey::Name(name) if is_identifier(name) => format!("{}.{}", owner, name),
+        LuaMemberKey::Name(name) => format!("{}[{:?}]", owner, name.as_str()),
+        LuaMemberKey::Integer(i) => format!("{}[{}]", owner, i),
+        LuaMemberKey::None => owner.to_string(),
+    }
+}
+
+/// a table constructor key, also valid as a `---@field` name
+fn member_key_text(key: &LuaMemberKey) -> String {
+    match key {
+        LuaMemberKey::Name(name) if is_identifier(name) => name.to_string(),
+        LuaMemberKey::Name(name) => format!("[{:?}]", name.as_str()),
+        LuaMemberKey::Integer(i) => format!("[{}]", i),
+        LuaMemberKey::None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+
+    use super::build_stubs;
+
+    const SHAPES: &str = r#"
+---@class Shape
+---@field name string
+---@field private id integer
+local Shape = {}
+
+---@param scale? number
+---@return number area
+function Shape:area(scale)
+    return 0
+end
+
+---@alias ShapeKind "circle" | "square"
+
+---@enum Align
+Align = {
+    Left = 1,
+    Right = 2,
+}
+
+---@type string
+VERSION = "1.0"
+
+local M = {}
+
+---@param kind ShapeKind
+---@return Shape
+function M.create(kind)
+    return Shape
+end
+
+return M
+"#;
+
+    const SHAPES_META: &str = r#"---@meta
+
+---@class Shape
+---@field name string
+local Shape = {}
+
+---@param scale? number
+---@return number area
+function Shape:area(scale) end
+
+---@alias ShapeKind "circle"|"square"
+
+---@enum Align
+Align = {
+    Left = 1,
+    Right = 2,
+}
+
+---@type string
+VERSION = nil
+
+local M = {}
+
+---@param kind ShapeKind
+---@return Shape
+function M.create(kind) end
+
+return M
+"#;
+
+    fn get_stub(content: &str) -> String {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("shapes.lua", content);
+        let db = ws.analysis.compilation.get_db();
+        let mut stubs = build_stubs(db, &ws.virtual_url_generator.base);
+        assert_eq!(stubs.len(), 1);
+        stubs.remove("shapes.lua").unwrap()
+    }
+
+    #[test]
+    fn test_build_stubs() {
+        assert_eq!(get_stub(SHAPES), SHAPES_META);
+        // the stub declares the same types and signatures as the source it came from
+        assert_eq!(get_stub(SHAPES_META), SHAPES_META);
+    }
+}
@@ -0,0 +1,184 @@
+use emmylua_code_analysis::{
+    humanize_type, DbIndex, LuaAliasCallKind, LuaMemberKey, LuaType, RenderLevel,
+};
+
+/// render a type in annotation syntax so that it parses back to the same type, unlike
+/// `humanize_type` which truncates and uses `->` for returns
+pub fn render_type(db: &DbIndex, typ: &LuaType) -> String {
+    match typ {
+        LuaType::Unknown => "any".to_string(),
+        LuaType::Ref(id) | LuaType::Def(id) => id.get_name().to_string(),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => format!("{:?}", s.as_str()),
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => i.to_string(),
+        LuaType::FloatConst(_) => "number".to_string(),
+        LuaType::TableConst(_) => "table".to_string(),
+        LuaType::Array(base) => format!("{}[]", render_wrapped_type(db, base)),
+        LuaType::Nullable(base) => format!("{}?", render_wrapped_type(db, base)),
+        LuaType::Variadic(base) => format!("{}...", render_wrapped_type(db, base)),
+        LuaType::Union(union) => join_types(db, union.get_types(), "|"),
+        LuaType::MultiLineUnion(multi) => {
+            let types = multi
+                .get_unions()
+                .iter()
+                .map(|(typ, _)| typ.clone())
+                .collect::<Vec<_>>();
+            join_types(db, &types, "|")
+        }
+        LuaType::Intersection(intersection) => join_types(db, intersection.get_types(), " & "),
+        LuaType::Tuple(tuple) => format!("[{}]", join_types(db, tuple.get_types(), ", ")),
+        LuaType::Generic(generic) => format!(
+            "{}<{}>",
+            generic.get_base_type_id().get_name(),
+            join_types(db, generic.get_params(), ", ")
+        ),
+        LuaType::TableGeneric(params) => format!("table<{}>", join_types(db, params, ", ")),
+        LuaType::TplRef(tpl) => tpl.get_name().to_string(),
+        LuaType::StrTplRef(str_tpl) => format!("{}`{}`", str_tpl.get_prefix(), str_tpl.get_name()),
+        LuaType::DocFunction(func) => {
+            let params = func
+                .get_params()
+                .iter()
+                .map(|(name, typ)| match typ {
+                    Some(typ) => format!("{}: {}", name, render_type(db, typ)),
+                    None => name.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let prefix = if func.is_async() { "async fun" } else { "fun" };
+            match func.get_ret() {
+                [] => format!("{}({})", prefix, params),
+                rets => format!("{}({}): {}", prefix, params, join_types(db, rets, ", ")),
+            }
+        }
+        LuaType::Signature(signature_id) => {
+            let Some(signature) = db.get_signature_index().get(signature_id) else {
+                return "function".to_string();
+            };
+            let params = signature
+                .get_type_params()
+                .iter()
+                .enumerate()
+                .map(|(idx, (name, typ))| {
+                    let nullable = signature
+                        .get_param_info_by_id(idx)
+                        .is_some_and(|info| info.nullable);
+                    match typ {
+                        Some(typ) if nullable && name != "..." => {
+                            format!("{}?: {}", name, render_type_without_nil(db, typ))
+                        }
+                        Some(typ) => format!("{}: {}", name, render_type(db, typ)),
+                        None => name.clone(),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let rets = signature
+                .return_docs
+                .iter()
+                .map(|ret| ret.type_ref.clone())
+                .collect::<Vec<_>>();
+            match rets.as_slice() {
+                [] => format!("fun({})", params),
+                rets => format!("fun({}): {}", params, join_types(db, rets, ", ")),
+            }
+        }
+        LuaType::Object(object) => {
+            let mut fields = object
+                .get_fields()
+                .iter()
+                .filter_map(|(key, typ)| {
+                    let key = match key {
+                        LuaMemberKey::Name(name) => name.to_string(),
+                        LuaMemberKey::Integer(i) => format!("[{}]", i),
+                        LuaMemberKey::None => return None,
+                    };
+                    Some(format!("{}: {}", key, render_type(db, typ)))
+                })
+                .collect::<Vec<_>>();
+            fields.sort();
+            for (key, value) in object.get_index_access() {
+                fields.push(format!(
+                    "[{}]: {}",
+                    render_type(db, key),
+                    render_type(db, value)
+                ));
+            }
+            format!("{{ {} }}", fields.join(", "))
+        }
+        LuaType::Instance(instance) => render_type(db, instance.get_base()),
+        LuaType::Call(call) => {
+            let operands = call.get_operands();
+            match (call.get_call_kind(), operands.as_slice()) {
+                (LuaAliasCallKind::Select, _) => {
+                    format!("std.Select<{}>", join_types(db, operands, ", "))
+                }
+                (LuaAliasCallKind::ReturnType, _) => {
+                    format!("std.ReturnType<{}>", join_types(db, operands, ", "))
+                }
+                (LuaAliasCallKind::Parameters, _) => {
+                    format!("std.Parameters<{}>", join_types(db, operands, ", "))
+                }
+                (LuaAliasCallKind::RemoveNil, _) => {
+                    format!("std.NonNil<{}>", join_types(db, operands, ", "))
+                }
+                (LuaAliasCallKind::Readonly, _) => {
+                    format!("std.Readonly<{}>", join_types(db, operands, ", "))
+                }
+                (LuaAliasCallKind::KeyOf, [base]) => {
+                    format!("keyof {}", render_wrapped_type(db, base))
+                }
+                (LuaAliasCallKind::Index, [base, index]) => format!(
+                    "{}[{}]",
+                    render_wrapped_type(db, base),
+                    render_type(db, index)
+                ),
+                // mapped and arithmetic types only appear inside generic aliases
+                _ => "any".to_string(),
+            }
+        }
+        _ => humanize_type(db, typ, RenderLevel::Simple),
+    }
+}
+
+/// `T|nil` and `T?` render as `T`, for params and fields already marked optional with `?`
+pub fn render_type_without_nil(db: &DbIndex, typ: &LuaType) -> String {
+    match typ {
+        LuaType::Nullable(base) => render_type(db, base),
+        LuaType::Union(union) => {
+            let types = union
+                .get_types()
+                .iter()
+                .filter(|typ| !typ.is_nil())
+                .cloned()
+                .collect::<Vec<_>>();
+            join_types(db, &types, "|")
+        }
+        _ => render_type(db, typ),
+    }
+}
+
+/// parenthesize types that would bind differently inside `[]`, `?` or `...`
+fn render_wrapped_type(db: &DbIndex, typ: &LuaType) -> String {
+    let text = render_type(db, typ);
+    match typ {
+        LuaType::Union(_)
+        | LuaType::MultiLineUnion(_)
+        | LuaType::Intersection(_)
+        | LuaType::DocFunction(_)
+        | LuaType::Signature(_) => format!("({})", text),
+        _ => text,
+    }
+}
+
+fn join_types(db: &DbIndex, types: &[LuaType], separator: &str) -> String {
+    types
+        .iter()
+        .map(|typ| match typ {
+            LuaType::DocFunction(_) | LuaType::Signature(_) if separator != ", " => {
+                render_wrapped_type(db, typ)
+            }
+            _ => render_type(db, typ),
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
@@ -0,0 +1,49 @@
+(function () {
+  var input = document.getElementById("search");
+  var results = document.getElementById("search-results");
+  var index = window.SEARCH_INDEX || [];
+
+  function render(query) {
+    results.innerHTML = "";
+    query = query.trim().toLowerCase();
+    if (!query) {
+      return;
+    }
+
+    var matches = index.filter(function (item) {
+      return item.name.toLowerCase().indexOf(query) !== -1;
+    });
+    matches.sort(function (a, b) {
+      var aStarts = a.name.toLowerCase().indexOf(query) === 0 ? 0 : 1;
+      var bStarts = b.name.toLowerCase().indexOf(query) === 0 ? 0 : 1;
+      return aStarts - bStarts || a.name.length - b.name.length;
+    });
+    matches.slice(0, 50).forEach(function (item) {
+      var li = document.createElement("li");
+      var a = document.createElement("a");
+      a.href = window.DOC_ROOT + item.url;
+      a.textContent = item.name;
+      var kind = document.createElement("span");
+      kind.className = "kind";
+      kind.textContent = item.kind;
+      a.appendChild(kind);
+      li.appendChild(a);
+      results.appendChild(li);
+    });
+  }
+
+  input.addEventListener("input", function () {
+    render(input.value);
+  });
+  input.addEventListener("keydown", function (e) {
+    if (e.key === "Enter") {
+      var first = results.querySelector("a");
+      if (first) {
+        window.location.href = first.href;
+      }
+    } else if (e.key === "Escape") {
+      input.value = "";
+      render("");
+    }
+  });
+})();
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use structopt::StructOpt;
 
 #[allow(unused)]
@@ -23,6 +25,14 @@ pub struct CmdArgs {
     /// Logging level (e.g., "error", "warn", "info", "debug", "trace")
     #[structopt(long = "log-level", help = "Logging level", default_value = "info")]
     pub log_level: LogLevel,
+
+    /// Directory of user locale files, eg `std/math/zh_CN.yaml`
+    #[structopt(parse(from_os_str), long = "locale-dir", help = "Directory of user locale files")]
+    pub locale_dir: Option<PathBuf>,
+
+    /// Dump the english text of all translatable keys into the directory and exit
+    #[structopt(parse(from_os_str), long = "dump-locale", help = "Dump the english locale files into the directory and exit")]
+    pub dump_locale: Option<PathBuf>,
 }
 
 /// Logging level enum
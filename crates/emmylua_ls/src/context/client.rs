@@ -6,7 +6,8 @@ use std::{
 use lsp_server::{Connection, Message, Notification, RequestId, Response};
 use lsp_types::{
     ApplyWorkspaceEditParams, ApplyWorkspaceEditResponse, ConfigurationParams,
-    PublishDiagnosticsParams, RegistrationParams, ShowMessageParams, UnregistrationParams,
+    PublishDiagnosticsParams, RegistrationParams, ShowDocumentParams, ShowMessageParams,
+    UnregistrationParams,
 };
 use serde::de::DeserializeOwned;
 use tokio::{
@@ -118,6 +119,11 @@ impl ClientProxy {
         self.send_notification("window/showMessage", message);
     }
 
+    pub fn show_document(&self, params: ShowDocumentParams) {
+        let request_id = self.next_id();
+        self.send_request_no_wait(request_id, "window/showDocument", params);
+    }
+
     pub fn publish_diagnostics(&self, params: PublishDiagnosticsParams) {
         self.send_notification("textDocument/publishDiagnostics", params);
     }
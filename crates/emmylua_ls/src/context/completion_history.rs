@@ -0,0 +1,33 @@
+use std::collections::VecDeque;
+
+use lsp_types::CompletionItemKind;
+use serde::{Deserialize, Serialize};
+
+/// identifies an accepted completion, `owner` is the class of a member so `Foo.x` and
+/// `Bar.x` are remembered apart
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletionHistoryKey {
+    pub label: String,
+    pub kind: Option<CompletionItemKind>,
+    pub owner: Option<String>,
+}
+
+/// the completions accepted in this session, the most recent first
+#[derive(Debug, Default)]
+pub struct CompletionHistory {
+    keys: VecDeque<CompletionHistoryKey>,
+}
+
+impl CompletionHistory {
+    pub const LIMIT: usize = 100;
+
+    pub fn record(&mut self, key: CompletionHistoryKey) {
+        self.keys.retain(|it| *it != key);
+        self.keys.push_front(key);
+        self.keys.truncate(Self::LIMIT);
+    }
+
+    pub fn get_recency(&self, key: &CompletionHistoryKey) -> Option<usize> {
+        self.keys.iter().position(|it| it == key)
+    }
+}
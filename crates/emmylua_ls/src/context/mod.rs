@@ -1,5 +1,6 @@
 mod client;
 mod client_id;
+mod completion_history;
 mod config_manager;
 mod file_diagnostic;
 mod snapshot;
@@ -7,6 +8,7 @@ mod status_bar;
 
 pub use client::ClientProxy;
 pub use client_id::{get_client_id, ClientId};
+pub use completion_history::{CompletionHistory, CompletionHistoryKey};
 use emmylua_code_analysis::EmmyLuaAnalysis;
 pub use config_manager::load_emmy_config;
 pub use config_manager::ConfigManager;
@@ -28,6 +30,7 @@ pub struct ServerContext {
     file_diagnostic: Arc<FileDiagnostic>,
     config_manager: Arc<RwLock<ConfigManager>>,
     status_bar: Arc<StatusBar>,
+    completion_history: Arc<RwLock<CompletionHistory>>,
 }
 
 impl ServerContext {
@@ -61,6 +64,7 @@ impl ServerContext {
             cancllations: Arc::new(Mutex::new(HashMap::new())),
             config_manager,
             status_bar,
+            completion_history: Arc::new(RwLock::new(CompletionHistory::default())),
         }
     }
 
@@ -71,6 +75,7 @@ impl ServerContext {
             file_diagnostic: self.file_diagnostic.clone(),
             config_manager: self.config_manager.clone(),
             status_bar: self.status_bar.clone(),
+            completion_history: self.completion_history.clone(),
         }
     }
 
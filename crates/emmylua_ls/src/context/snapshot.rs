@@ -4,8 +4,8 @@ use tokio::sync::RwLock;
 use emmylua_code_analysis::EmmyLuaAnalysis;
 
 use super::{
-    client::ClientProxy, config_manager::ConfigManager, file_diagnostic::FileDiagnostic,
-    status_bar::StatusBar,
+    client::ClientProxy, completion_history::CompletionHistory, config_manager::ConfigManager,
+    file_diagnostic::FileDiagnostic, status_bar::StatusBar,
 };
 
 #[derive(Clone)]
@@ -15,4 +15,5 @@ pub struct ServerContextSnapshot {
     pub file_diagnostic: Arc<FileDiagnostic>,
     pub config_manager: Arc<RwLock<ConfigManager>>,
     pub status_bar: Arc<StatusBar>,
+    pub completion_history: Arc<RwLock<CompletionHistory>>,
 }
@@ -0,0 +1,168 @@
+use emmylua_code_analysis::SemanticModel;
+use emmylua_parser::{
+    LuaAstNode, LuaClosureExpr, LuaExpr, LuaFuncStat, LuaIndexKey, LuaParamList, LuaStat,
+    LuaSyntaxNode, LuaVarExpr,
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand, Range, TextEdit};
+use rowan::{TextRange, TextSize};
+
+use super::make_edit_action;
+
+/// convert between `function M.f() end` and `M.f = function() end`
+pub fn add_convert_function_action(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let offset = document.to_rowan_range(range)?.start();
+    let root = semantic_model.get_root().syntax();
+    let token = root.token_at_offset(offset).right_biased()?;
+
+    let (stat_range, title, new_text) = match token.parent_ancestors().find_map(LuaStat::cast)? {
+        LuaStat::FuncStat(func_stat) => {
+            let closure = func_stat.get_closure()?;
+            if !is_on_header(offset, &closure) {
+                return None;
+            }
+            (
+                func_stat.get_range(),
+                "Convert to function assignment",
+                build_assign_text(&func_stat, &closure)?,
+            )
+        }
+        LuaStat::AssignStat(assign_stat) => {
+            let (vars, exprs) = assign_stat.get_var_and_expr_list();
+            if vars.len() != 1 || exprs.len() != 1 {
+                return None;
+            }
+            let LuaExpr::ClosureExpr(closure) = &exprs[0] else {
+                return None;
+            };
+            if !is_on_header(offset, closure) {
+                return None;
+            }
+            (
+                assign_stat.get_range(),
+                "Convert to function statement",
+                build_func_stat_text(&vars[0], closure)?,
+            )
+        }
+        _ => return None,
+    };
+
+    actions.push(make_edit_action(
+        semantic_model,
+        title.to_string(),
+        CodeActionKind::REFACTOR_REWRITE,
+        vec![TextEdit {
+            range: document.to_lsp_range(stat_range)?,
+            new_text,
+        }],
+    ));
+
+    Some(())
+}
+
+/// the cursor is before the body of the function
+fn is_on_header(offset: TextSize, closure: &LuaClosureExpr) -> bool {
+    match closure.get_block() {
+        Some(block) => offset < block.get_position(),
+        None => true,
+    }
+}
+
+/// `M.f = function(self, a) ... end` from `function M:f(a) ... end`
+fn build_assign_text(func_stat: &LuaFuncStat, closure: &LuaClosureExpr) -> Option<String> {
+    let (name, is_colon) = match func_stat.get_func_name()? {
+        LuaVarExpr::NameExpr(name_expr) => (name_expr.syntax().text().to_string(), false),
+        LuaVarExpr::IndexExpr(index_expr) => {
+            let index_token = index_expr.get_index_token()?;
+            let name_token = index_expr.get_name_token()?;
+            (
+                format!(
+                    "{}.{}",
+                    index_expr.get_prefix_expr()?.syntax().text(),
+                    name_token.get_name_text()
+                ),
+                index_token.is_colon(),
+            )
+        }
+    };
+
+    let params_list = closure.get_params_list()?;
+    let params = if is_colon {
+        let names = get_param_texts(&params_list);
+        if names.is_empty() {
+            "(self)".to_string()
+        } else {
+            format!("(self, {})", names.join(", "))
+        }
+    } else {
+        params_list.syntax().text().to_string()
+    };
+
+    Some(format!(
+        "{} = function{}{}",
+        name,
+        params,
+        get_text_after(closure.syntax(), params_list.get_range().end())
+    ))
+}
+
+/// `function M:f(a) ... end` from `M.f = function(self, a) ... end`
+fn build_func_stat_text(var: &LuaVarExpr, closure: &LuaClosureExpr) -> Option<String> {
+    let params_list = closure.get_params_list()?;
+    let rest = get_text_after(closure.syntax(), params_list.get_range().end());
+    match var {
+        LuaVarExpr::NameExpr(name_expr) => Some(format!(
+            "function {}{}{}",
+            name_expr.syntax().text(),
+            params_list.syntax().text(),
+            rest
+        )),
+        LuaVarExpr::IndexExpr(index_expr) => {
+            // `M["f"]` and `M[1]` have no function statement form
+            let LuaIndexKey::Name(name_token) = index_expr.get_index_key()? else {
+                return None;
+            };
+            let prefix = index_expr.get_prefix_expr()?.syntax().text().to_string();
+            let names = get_param_texts(&params_list);
+            if names.first().is_some_and(|name| name == "self") {
+                Some(format!(
+                    "function {}:{}({}){}",
+                    prefix,
+                    name_token.get_name_text(),
+                    names[1..].join(", "),
+                    rest
+                ))
+            } else {
+                Some(format!(
+                    "function {}.{}{}{}",
+                    prefix,
+                    name_token.get_name_text(),
+                    params_list.syntax().text(),
+                    rest
+                ))
+            }
+        }
+    }
+}
+
+fn get_param_texts(params_list: &LuaParamList) -> Vec<String> {
+    params_list
+        .get_params()
+        .map(|param| param.syntax().text().to_string())
+        .collect()
+}
+
+fn get_text_after(node: &LuaSyntaxNode, offset: TextSize) -> String {
+    let range = node.text_range();
+    if offset >= range.end() {
+        return String::new();
+    }
+
+    node.text()
+        .slice(TextRange::new(offset - range.start(), range.len()))
+        .to_string()
+}
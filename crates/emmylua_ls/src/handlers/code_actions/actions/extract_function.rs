@@ -0,0 +1,247 @@
+use emmylua_code_analysis::{LuaDeclId, SemanticModel};
+use emmylua_parser::{
+    LuaAstNode, LuaBlock, LuaLiteralExpr, LuaLiteralToken, LuaNameExpr, LuaStat, LuaSyntaxKind,
+    LuaSyntaxNode,
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand, Range, TextEdit};
+use rowan::{NodeOrToken, TextRange};
+
+use super::{get_line_indent, get_unique_local_name, make_edit_action};
+
+/// move the selected statements into a new local function
+pub fn add_extract_function_action(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let selection = document.to_rowan_range(range)?;
+    if selection.is_empty() {
+        return None;
+    }
+
+    let stats = get_selected_stats(semantic_model, selection)?;
+    let stats_range = TextRange::new(
+        stats.first()?.get_range().start(),
+        stats.last()?.get_range().end(),
+    );
+    if stats.iter().any(|stat| !can_extract(stat.syntax())) {
+        return None;
+    }
+
+    let file_id = semantic_model.get_file_id();
+    let db = semantic_model.get_db();
+    let decl_tree = db.get_decl_index().get_decl_tree(&file_id)?;
+    let reference_index = db.get_reference_index();
+    let is_used_after = |decl_id: &LuaDeclId| {
+        reference_index
+            .get_decl_references(&file_id, decl_id)
+            .is_some_and(|references| {
+                references
+                    .iter()
+                    .any(|reference| reference.range.start() >= stats_range.end())
+            })
+    };
+
+    // locals from outside the selection become parameters
+    let mut params: Vec<String> = Vec::new();
+    let mut outer_written = Vec::new();
+    for stat in &stats {
+        for name_expr in stat.syntax().descendants().filter_map(LuaNameExpr::cast) {
+            let Some(name) = name_expr.get_name_text() else {
+                continue;
+            };
+            let decl = decl_tree.find_local_decl(&name, name_expr.get_position());
+            match decl {
+                Some(decl) if decl.is_local() && !stats_range.contains(decl.get_position()) => {
+                    if params.contains(&name) {
+                        continue;
+                    }
+                    let decl_id = decl.get_id();
+                    let is_written = reference_index
+                        .get_decl_references(&file_id, &decl_id)
+                        .is_some_and(|references| {
+                            references.iter().any(|reference| {
+                                reference.is_write && stats_range.contains_range(reference.range)
+                            })
+                        });
+                    if is_written && is_used_after(&decl_id) {
+                        outer_written.push(name.clone());
+                    }
+                    params.push(name);
+                }
+                // `self` of the enclosing method has no declaration
+                None if name == "self" && !params.contains(&name) => params.push(name),
+                _ => {}
+            }
+        }
+    }
+
+    // locals declared in the selection and still used after it are returned
+    let mut inner_decls = decl_tree
+        .get_decls()
+        .values()
+        .filter(|decl| {
+            decl.is_local()
+                && stats_range.contains(decl.get_position())
+                && is_used_after(&decl.get_id())
+        })
+        .collect::<Vec<_>>();
+    inner_decls.sort_by_key(|decl| decl.get_position());
+    let declared = inner_decls
+        .iter()
+        .map(|decl| decl.get_name().to_string())
+        .collect::<Vec<_>>();
+    let mut returns = declared.clone();
+    returns.extend(outer_written.iter().cloned());
+
+    let indent = get_line_indent(&document, stats_range.start())?;
+    let indent_unit = if indent.contains('\t') { "\t" } else { "    " };
+    let func_name = get_unique_local_name(decl_tree, "extracted_function", stats_range.start());
+    let params_text = params.join(", ");
+
+    let mut text = format!("local function {}({})\n", func_name, params_text);
+    let body = document.get_text_slice(stats_range);
+    for (idx, line) in body.lines().enumerate() {
+        if idx == 0 {
+            text.push_str(&format!("{}{}{}\n", indent, indent_unit, line));
+        } else if line.trim().is_empty() {
+            text.push('\n');
+        } else {
+            text.push_str(&format!("{}{}\n", indent_unit, line));
+        }
+    }
+    if !returns.is_empty() {
+        text.push_str(&format!(
+            "{}{}return {}\n",
+            indent,
+            indent_unit,
+            returns.join(", ")
+        ));
+    }
+    text.push_str(&format!("{}end\n", indent));
+
+    let call = format!("{}({})", func_name, params_text);
+    match (declared.is_empty(), outer_written.is_empty()) {
+        (true, true) => text.push_str(&format!("{}{}", indent, call)),
+        (false, true) => text.push_str(&format!(
+            "{}local {} = {}",
+            indent,
+            declared.join(", "),
+            call
+        )),
+        (true, false) => text.push_str(&format!(
+            "{}{} = {}",
+            indent,
+            outer_written.join(", "),
+            call
+        )),
+        (false, false) => text.push_str(&format!(
+            "{}local {}\n{}{} = {}",
+            indent,
+            declared.join(", "),
+            indent,
+            returns.join(", "),
+            call
+        )),
+    }
+
+    actions.push(make_edit_action(
+        semantic_model,
+        "Extract to local function".to_string(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        vec![TextEdit {
+            range: document.to_lsp_range(stats_range)?,
+            new_text: text,
+        }],
+    ));
+
+    Some(())
+}
+
+/// the whole statements of one block covered by the selection
+fn get_selected_stats(
+    semantic_model: &SemanticModel,
+    selection: TextRange,
+) -> Option<Vec<LuaStat>> {
+    let root = semantic_model.get_root().syntax();
+    let covering = match root.covering_element(selection) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let block = std::iter::once(covering.clone())
+        .chain(covering.ancestors())
+        .find_map(LuaBlock::cast)?;
+
+    let mut stats = Vec::new();
+    for stat in block.get_stats() {
+        let stat_range = stat.get_range();
+        if selection.contains_range(stat_range) {
+            stats.push(stat);
+        } else if selection
+            .intersect(stat_range)
+            .is_some_and(|it| !it.is_empty())
+        {
+            // a statement cut in the middle
+            return None;
+        }
+    }
+
+    if stats.is_empty() {
+        None
+    } else {
+        Some(stats)
+    }
+}
+
+/// control flow leaving the selection and varargs can not move into a function
+fn can_extract(stat: &LuaSyntaxNode) -> bool {
+    for node in stat.descendants() {
+        let kind: LuaSyntaxKind = node.kind().into();
+        let is_break = kind == LuaSyntaxKind::BreakStat;
+        let is_dots = LuaLiteralExpr::cast(node.clone())
+            .and_then(|literal| literal.get_literal())
+            .is_some_and(|literal| matches!(literal, LuaLiteralToken::Dots(_)));
+        if !is_break
+            && !is_dots
+            && !matches!(
+                kind,
+                LuaSyntaxKind::ReturnStat | LuaSyntaxKind::GotoStat | LuaSyntaxKind::LabelStat
+            )
+        {
+            continue;
+        }
+
+        if node == *stat {
+            return false;
+        }
+
+        // fine when a function, or a loop for `break`, inside the selection encloses it
+        let mut is_enclosed = false;
+        for ancestor in node.ancestors().skip(1) {
+            let ancestor_kind: LuaSyntaxKind = ancestor.kind().into();
+            if ancestor_kind == LuaSyntaxKind::ClosureExpr || (is_break && is_loop(ancestor_kind)) {
+                is_enclosed = true;
+                break;
+            }
+            if ancestor == *stat {
+                break;
+            }
+        }
+        if !is_enclosed {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn is_loop(kind: LuaSyntaxKind) -> bool {
+    matches!(
+        kind,
+        LuaSyntaxKind::WhileStat
+            | LuaSyntaxKind::ForStat
+            | LuaSyntaxKind::ForRangeStat
+            | LuaSyntaxKind::RepeatStat
+    )
+}
@@ -0,0 +1,182 @@
+use emmylua_code_analysis::SemanticModel;
+use emmylua_parser::{
+    BinaryOperator, LuaAstNode, LuaBinaryExpr, LuaCommentOwner, LuaExpr, LuaLiteralToken,
+    LuaNameExpr, LuaStat, LuaSyntaxKind, LuaSyntaxNode, LuaTokenKind,
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand, Range, TextEdit};
+use rowan::{NodeOrToken, TextRange, TextSize};
+
+use super::{get_line_indent, get_unique_local_name, make_edit_action};
+
+/// move the selected expression into a local declared before its statement
+pub fn add_extract_local_action(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let selection = document.to_rowan_range(range)?;
+    let selection = trim_selection(document.get_text_slice(selection), selection)?;
+    let root = semantic_model.get_root().syntax();
+    let covering = match root.covering_element(selection) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let expr = std::iter::once(covering.clone())
+        .chain(covering.ancestors())
+        .take_while(|node| node.text_range() == selection)
+        .filter_map(LuaExpr::cast)
+        .last()?;
+    if !can_extract(&expr) {
+        return None;
+    }
+
+    let stat = find_enclosing_stat(expr.syntax())?;
+    let insert_offset = match stat.get_left_comment() {
+        Some(comment) => comment.get_position(),
+        None => stat.get_position(),
+    };
+
+    // every name must still mean the same variable before the statement
+    let file_id = semantic_model.get_file_id();
+    let decl_tree = semantic_model
+        .get_db()
+        .get_decl_index()
+        .get_decl_tree(&file_id)?;
+    for name_expr in expr.syntax().descendants().filter_map(LuaNameExpr::cast) {
+        let name = name_expr.get_name_text()?;
+        let used_decl = decl_tree
+            .find_local_decl(&name, name_expr.get_position())
+            .map(|decl| decl.get_id());
+        let moved_decl = decl_tree
+            .find_local_decl(&name, insert_offset)
+            .map(|decl| decl.get_id());
+        if used_decl != moved_decl {
+            return None;
+        }
+    }
+
+    let name = get_unique_local_name(decl_tree, "extracted", insert_offset);
+    let indent = get_line_indent(&document, insert_offset)?;
+    let insert_position = document.to_lsp_position(insert_offset)?;
+    actions.push(make_edit_action(
+        semantic_model,
+        "Extract to local variable".to_string(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        vec![
+            TextEdit {
+                range: Range {
+                    start: insert_position,
+                    end: insert_position,
+                },
+                new_text: format!("local {} = {}\n{}", name, expr.syntax().text(), indent),
+            },
+            TextEdit {
+                range: document.to_lsp_range(selection)?,
+                new_text: name,
+            },
+        ],
+    ));
+
+    Some(())
+}
+
+fn trim_selection(text: &str, selection: TextRange) -> Option<TextRange> {
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    if start >= end {
+        return None;
+    }
+
+    Some(TextRange::new(
+        selection.start() + TextSize::from(start as u32),
+        selection.start() + TextSize::from(end as u32),
+    ))
+}
+
+fn can_extract(expr: &LuaExpr) -> bool {
+    let Some(parent) = expr.syntax().parent() else {
+        return false;
+    };
+    let parent_kind: LuaSyntaxKind = parent.kind().into();
+    match parent_kind {
+        // assignment targets and function names are not values
+        LuaSyntaxKind::AssignStat
+            if expr.get_position()
+                < parent
+                    .children_with_tokens()
+                    .find(|it| it.kind() == LuaTokenKind::TkAssign.into())
+                    .map(|it| it.text_range().start())
+                    .unwrap_or_default() =>
+        {
+            return false;
+        }
+        LuaSyntaxKind::FuncStat | LuaSyntaxKind::LocalFuncStat | LuaSyntaxKind::CallExprStat => {
+            return false
+        }
+        _ => {}
+    }
+
+    // a call or `...` at the end of a list may produce several values
+    let is_multi_value = match expr {
+        LuaExpr::CallExpr(_) => true,
+        LuaExpr::LiteralExpr(literal) => {
+            matches!(literal.get_literal(), Some(LuaLiteralToken::Dots(_)))
+        }
+        _ => false,
+    };
+    let is_last = if parent_kind == LuaSyntaxKind::TableFieldValue {
+        parent.next_sibling().is_none()
+    } else {
+        expr.syntax().next_sibling().is_none()
+    };
+    if is_multi_value
+        && is_last
+        && matches!(
+            parent_kind,
+            LuaSyntaxKind::CallArgList
+                | LuaSyntaxKind::ReturnStat
+                | LuaSyntaxKind::LocalStat
+                | LuaSyntaxKind::AssignStat
+                | LuaSyntaxKind::TableFieldValue
+        )
+    {
+        return false;
+    }
+
+    true
+}
+
+/// the statement in front of which the expression is evaluated exactly once
+fn find_enclosing_stat(node: &LuaSyntaxNode) -> Option<LuaStat> {
+    let mut child = node.clone();
+    for ancestor in node.ancestors().skip(1) {
+        let kind: LuaSyntaxKind = ancestor.kind().into();
+        match kind {
+            LuaSyntaxKind::ClosureExpr | LuaSyntaxKind::ElseIfClauseStat => return None,
+            _ => {}
+        }
+
+        // the right side of `and` / `or` is not always evaluated
+        if let Some(binary_expr) = LuaBinaryExpr::cast(ancestor.clone()) {
+            let op = binary_expr.get_op_token()?.get_op();
+            let (_, right) = binary_expr.get_exprs()?;
+            if matches!(op, BinaryOperator::OpAnd | BinaryOperator::OpOr)
+                && right.syntax() == &child
+            {
+                return None;
+            }
+        }
+
+        if let Some(stat) = LuaStat::cast(ancestor.clone()) {
+            // loop conditions run once per iteration
+            return match stat {
+                LuaStat::WhileStat(_) | LuaStat::RepeatStat(_) => None,
+                _ => Some(stat),
+            };
+        }
+        child = ancestor;
+    }
+
+    None
+}
@@ -0,0 +1,148 @@
+use emmylua_code_analysis::{LuaType, SemanticModel};
+use emmylua_parser::{LuaAst, LuaAstNode, LuaComment, LuaDocTagClass, LuaVarExpr};
+use lsp_types::{CodeActionKind, CodeActionOrCommand, Range, TextEdit};
+use rowan::TokenAtOffset;
+
+use crate::util::collect_super_methods;
+
+use super::{get_line_indent, make_edit_action};
+
+/// on `---@class Foo : IBar`, stub every method of `IBar` that `Foo` is missing
+pub fn add_implement_interface_action(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(range.start.line as usize, range.start.character as usize)?;
+    let token = match semantic_model.get_root().syntax().token_at_offset(offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, _) => left,
+        TokenAtOffset::None => return None,
+    };
+    let class_tag = token.parent_ancestors().find_map(LuaDocTagClass::cast)?;
+    let name_token = class_tag.get_name_token()?;
+    let db = semantic_model.get_db();
+    let type_index = db.get_type_index();
+    let type_id = type_index
+        .find_type_decl(semantic_model.get_file_id(), name_token.get_name_text())?
+        .get_id();
+
+    let comment = class_tag.get_parent::<LuaComment>()?;
+    let (owner_name, insert_offset) = match comment.get_owner() {
+        Some(LuaAst::LuaLocalStat(local_stat)) => (
+            local_stat
+                .get_local_name_list()
+                .next()?
+                .get_name_token()?
+                .get_name_text()
+                .to_string(),
+            local_stat.get_range().end(),
+        ),
+        Some(LuaAst::LuaAssignStat(assign_stat)) => (
+            assign_stat
+                .child::<LuaVarExpr>()?
+                .syntax()
+                .text()
+                .to_string(),
+            assign_stat.get_range().end(),
+        ),
+        _ => (
+            name_token.get_name_text().to_string(),
+            comment.get_range().end(),
+        ),
+    };
+    // the stubs line up with the statement declaring the class
+    let indent = get_line_indent(&document, comment.get_position())?;
+    let insert_position = document.to_lsp_position(insert_offset)?;
+
+    for super_type in type_index.get_super_types(&type_id).unwrap_or_default() {
+        let super_id = match super_type {
+            LuaType::Ref(id) => id,
+            LuaType::Generic(generic) => generic.get_base_type_id(),
+            _ => continue,
+        };
+        let mut super_ids = vec![super_id.clone()];
+        super_ids.extend(type_index.get_all_super_type_ids(&super_id));
+        let methods = collect_super_methods(db, &type_id, &super_ids, true);
+        if methods.is_empty() {
+            continue;
+        }
+
+        let definitions = methods
+            .iter()
+            .map(|(_, stub)| stub.build_definition(db, &owner_name, &indent))
+            .collect::<Vec<_>>();
+        let new_text = format!("\n\n{}", definitions.join("\n").trim_end());
+        actions.push(make_edit_action(
+            semantic_model,
+            format!("Implement missing methods of `{}`", super_id.get_name()),
+            CodeActionKind::REFACTOR,
+            vec![TextEdit {
+                range: Range {
+                    start: insert_position,
+                    end: insert_position,
+                },
+                new_text,
+            }],
+        ));
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use lsp_types::{CodeActionOrCommand, Position, Range};
+
+    use super::add_implement_interface_action;
+
+    /// the titles and inserted texts of the actions offered with the cursor on `line`
+    fn get_stubs(code: &str, line: u32) -> Vec<(String, String)> {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(code);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let position = Position::new(line, 14);
+        let mut actions = Vec::new();
+        add_implement_interface_action(
+            &semantic_model,
+            &mut actions,
+            Range::new(position, position),
+        );
+        actions
+            .into_iter()
+            .filter_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => {
+                    let edit = action.edit?.changes?.into_values().next()?.pop()?;
+                    Some((action.title, edit.new_text))
+                }
+                CodeActionOrCommand::Command(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_implement_interface() {
+        let code = "---@class IBar\n---@field draw fun(self: IBar, x: number): boolean\n---@field size fun(self: IBar): integer\n\n---@class Foo : IBar\nlocal Foo = {}\n\nfunction Foo:size()\n    return 1\nend\n";
+        assert_eq!(
+            get_stubs(code, 4),
+            vec![(
+                "Implement missing methods of `IBar`".to_string(),
+                "\n\n---@param x number\n---@return boolean\nfunction Foo:draw(x)\nend".to_string()
+            )]
+        );
+
+        let code = "---@class IBar\n---@field size fun(self: IBar): integer\n\n---@class Foo : IBar\nlocal Foo = {}\n\nfunction Foo:size()\n    return 1\nend\n";
+        assert!(get_stubs(code, 3).is_empty());
+    }
+
+    #[test]
+    fn test_implement_interface_indent() {
+        let code = "---@class IBar\n---@field size fun(self: IBar): integer\n\ndo\n    ---@class Foo : IBar\n    local Foo = {}\nend\n";
+        assert_eq!(
+            get_stubs(code, 4)[0].1,
+            "\n\n    ---@return integer\n    function Foo:size()\n    end"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use emmylua_code_analysis::{LuaDeclId, SemanticModel};
+use emmylua_parser::{
+    LuaAstNode, LuaCallExpr, LuaCommentOwner, LuaExpr, LuaIndexExpr, LuaLiteralToken, LuaLocalName,
+    LuaLocalStat, LuaNameExpr, LuaStat, LuaSyntaxKind, LuaSyntaxNode, LuaTokenKind,
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand, Range, TextEdit};
+use rowan::{NodeOrToken, TextRange, TokenAtOffset};
+
+use super::make_edit_action;
+
+/// replace every read of a local assigned once by its value
+pub fn add_inline_variable_action(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let offset = document.to_rowan_range(range)?.start();
+    let root = semantic_model.get_root().syntax();
+    let token = match root.token_at_offset(offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if left.kind() == LuaTokenKind::TkName.into() {
+                left
+            } else {
+                right
+            }
+        }
+        TokenAtOffset::None => return None,
+    };
+
+    let file_id = semantic_model.get_file_id();
+    let db = semantic_model.get_db();
+    let decl_tree = db.get_decl_index().get_decl_tree(&file_id)?;
+    let parent = token.parent()?;
+    let decl_id = if let Some(local_name) = LuaLocalName::cast(parent.clone()) {
+        LuaDeclId::new(file_id, local_name.get_position())
+    } else if let Some(name_expr) = LuaNameExpr::cast(parent) {
+        decl_tree
+            .find_local_decl(&name_expr.get_name_text()?, name_expr.get_position())?
+            .get_id()
+    } else {
+        return None;
+    };
+    let decl = decl_tree.get_decl(&decl_id)?;
+    if !decl.is_local() || decl.is_param() {
+        return None;
+    }
+
+    let local_name = root
+        .covering_element(decl.get_range())
+        .ancestors()
+        .find_map(LuaLocalName::cast)?;
+    if local_name.get_attrib().is_some() {
+        return None;
+    }
+    let local_stat = local_name.get_parent::<LuaLocalStat>()?;
+    if local_stat.get_local_name_list().count() != 1 {
+        return None;
+    }
+    let mut value_exprs = local_stat.get_value_exprs();
+    let value = value_exprs.next()?;
+    if value_exprs.next().is_some() {
+        return None;
+    }
+
+    let reference_index = db.get_reference_index();
+    let references = reference_index.get_decl_references(&file_id, &decl_id)?;
+    if references.is_empty() || references.iter().any(|reference| reference.is_write) {
+        return None;
+    }
+    // a call or a table constructor must not run more than once, neither by several uses
+    // nor by a use inside a loop or a function the declaration is not part of, and must not
+    // move past another statement which could observe or change what it does
+    let has_side_effect = value.syntax().descendants().any(|node| {
+        matches!(
+            node.kind().into(),
+            LuaSyntaxKind::CallExpr
+                | LuaSyntaxKind::TableArrayExpr
+                | LuaSyntaxKind::TableObjectExpr
+                | LuaSyntaxKind::TableEmptyExpr
+                | LuaSyntaxKind::ClosureExpr
+        )
+    });
+    if has_side_effect {
+        let next_stat_range = local_stat
+            .syntax()
+            .next_sibling()
+            .and_then(LuaStat::cast)
+            .map(|stat| stat.get_range());
+        if references.len() > 1
+            || references.iter().any(|reference| {
+                is_repeated_use(root, reference.range, local_stat.get_range())
+                    || !next_stat_range.is_some_and(|range| range.contains_range(reference.range))
+            })
+        {
+            return None;
+        }
+    }
+
+    // every name in the value must mean the same variable at each use and keep its value
+    let stat_end = local_stat.get_range().end();
+    let last_use = references
+        .iter()
+        .map(|reference| reference.range.end())
+        .max()?;
+    for name_expr in value.syntax().descendants().filter_map(LuaNameExpr::cast) {
+        let name = name_expr.get_name_text()?;
+        let value_decl = decl_tree.find_local_decl(&name, name_expr.get_position());
+        for reference in references {
+            let use_decl = decl_tree.find_local_decl(&name, reference.range.start());
+            if value_decl.map(|decl| decl.get_id()) != use_decl.map(|decl| decl.get_id()) {
+                return None;
+            }
+        }
+
+        if let Some(value_decl) = value_decl {
+            let is_reassigned = reference_index
+                .get_decl_references(&file_id, &value_decl.get_id())
+                .is_some_and(|value_references| {
+                    value_references.iter().any(|reference| {
+                        reference.is_write
+                            && reference.range.start() >= stat_end
+                            && reference.range.end() <= last_use
+                    })
+                });
+            if is_reassigned {
+                return None;
+            }
+        }
+    }
+
+    let value_text = value.syntax().text().to_string();
+    let mut edits = Vec::new();
+    for reference in references {
+        let name_expr = match root.covering_element(reference.range) {
+            NodeOrToken::Node(node) => LuaNameExpr::cast(node),
+            NodeOrToken::Token(token) => token.parent().and_then(LuaNameExpr::cast),
+        }?;
+        let new_text = if need_paren(&value, name_expr.syntax()) {
+            format!("({})", value_text)
+        } else {
+            value_text.clone()
+        };
+        edits.push(TextEdit {
+            range: document.to_lsp_range(name_expr.get_range())?,
+            new_text,
+        });
+    }
+
+    let delete_start = match local_stat.get_left_comment() {
+        Some(comment) => comment.get_position(),
+        None => local_stat.get_position(),
+    };
+    let line = document.get_line(delete_start)?;
+    let line_start = document.get_offset(line, 0)?;
+    let delete_start = if document
+        .get_text_slice(TextRange::new(line_start, delete_start))
+        .trim()
+        .is_empty()
+    {
+        line_start
+    } else {
+        delete_start
+    };
+    let delete_end = match document.get_offset(document.get_line(stat_end)? + 1, 0) {
+        Some(next_line_start)
+            if document
+                .get_text_slice(TextRange::new(stat_end, next_line_start))
+                .trim()
+                .is_empty() =>
+        {
+            next_line_start
+        }
+        _ => stat_end,
+    };
+    edits.push(TextEdit {
+        range: document.to_lsp_range(TextRange::new(delete_start, delete_end))?,
+        new_text: String::new(),
+    });
+
+    actions.push(make_edit_action(
+        semantic_model,
+        format!("Inline local `{}`", decl.get_name()),
+        CodeActionKind::REFACTOR_INLINE,
+        edits,
+    ));
+
+    Some(())
+}
+
+/// whether the use at `range` sits in a loop or a closure which does not contain the declaration
+fn is_repeated_use(root: &LuaSyntaxNode, range: TextRange, decl_range: TextRange) -> bool {
+    let element = root.covering_element(range);
+    let parent = match element {
+        NodeOrToken::Node(node) => Some(node),
+        NodeOrToken::Token(token) => token.parent(),
+    };
+    parent
+        .into_iter()
+        .flat_map(|node| node.ancestors())
+        .any(|node| {
+            matches!(
+                node.kind().into(),
+                LuaSyntaxKind::WhileStat
+                    | LuaSyntaxKind::RepeatStat
+                    | LuaSyntaxKind::ForStat
+                    | LuaSyntaxKind::ForRangeStat
+                    | LuaSyntaxKind::ClosureExpr
+            ) && !node.text_range().contains_range(decl_range)
+        })
+}
+
+/// whether the value needs parentheses to keep its meaning in place of `name_node`
+fn need_paren(value: &LuaExpr, name_node: &LuaSyntaxNode) -> bool {
+    let Some(parent) = name_node.parent() else {
+        return false;
+    };
+
+    match value {
+        LuaExpr::BinaryExpr(_) | LuaExpr::UnaryExpr(_) => matches!(
+            parent.kind().into(),
+            LuaSyntaxKind::BinaryExpr
+                | LuaSyntaxKind::UnaryExpr
+                | LuaSyntaxKind::IndexExpr
+                | LuaSyntaxKind::CallExpr
+        ),
+        LuaExpr::NameExpr(_) | LuaExpr::IndexExpr(_) | LuaExpr::ParenExpr(_) => false,
+        LuaExpr::CallExpr(_) => {
+            // a call at the end of a list would spread all of its results
+            match parent.kind().into() {
+                LuaSyntaxKind::CallArgList
+                | LuaSyntaxKind::ReturnStat
+                | LuaSyntaxKind::LocalStat
+                | LuaSyntaxKind::AssignStat => name_node.next_sibling().is_none(),
+                LuaSyntaxKind::TableFieldValue => parent.next_sibling().is_none(),
+                _ => false,
+            }
+        }
+        LuaExpr::LiteralExpr(literal)
+            if matches!(literal.get_literal(), Some(LuaLiteralToken::Dots(_))) =>
+        {
+            true
+        }
+        _ => {
+            LuaIndexExpr::cast(parent.clone())
+                .and_then(|index_expr| index_expr.get_prefix_expr())
+                .is_some_and(|prefix| prefix.syntax() == name_node)
+                || LuaCallExpr::cast(parent)
+                    .and_then(|call_expr| call_expr.get_prefix_expr())
+                    .is_some_and(|prefix| prefix.syntax() == name_node)
+        }
+    }
+}
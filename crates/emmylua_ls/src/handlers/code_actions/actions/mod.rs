@@ -0,0 +1,200 @@
+mod convert_function;
+mod extract_function;
+mod extract_local;
+mod implement_interface;
+mod inline_variable;
+
+use std::collections::HashMap;
+
+use emmylua_code_analysis::{LuaDeclarationTree, LuaDocument, SemanticModel};
+use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, TextEdit, WorkspaceEdit};
+use rowan::{TextRange, TextSize};
+
+pub use convert_function::add_convert_function_action;
+pub use extract_function::add_extract_function_action;
+pub use extract_local::add_extract_local_action;
+pub use implement_interface::add_implement_interface_action;
+pub use inline_variable::add_inline_variable_action;
+
+fn make_edit_action(
+    semantic_model: &SemanticModel,
+    title: String,
+    kind: CodeActionKind,
+    edits: Vec<TextEdit>,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(kind),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(
+                semantic_model.get_document().get_uri(),
+                edits,
+            )])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+/// the whitespace before the first character of the line holding `offset`
+fn get_line_indent(document: &LuaDocument, offset: TextSize) -> Option<String> {
+    let line = document.get_line(offset)?;
+    let line_start = document.get_offset(line, 0)?;
+    let text = document.get_text_slice(TextRange::new(line_start, offset));
+    Some(
+        text.chars()
+            .take_while(|c| c.is_whitespace())
+            .collect::<String>(),
+    )
+}
+
+/// `name`, or `name1`, `name2`... when a local of that name is visible at `position`
+fn get_unique_local_name(decl_tree: &LuaDeclarationTree, name: &str, position: TextSize) -> String {
+    if decl_tree.find_local_decl(name, position).is_none() {
+        return name.to_string();
+    }
+
+    let mut idx = 1;
+    loop {
+        let candidate = format!("{}{}", name, idx);
+        if decl_tree.find_local_decl(&candidate, position).is_none() {
+            return candidate;
+        }
+        idx += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::{SemanticModel, VirtualWorkspace};
+    use lsp_types::{CodeActionOrCommand, Range};
+    use rowan::{TextRange, TextSize};
+
+    use super::*;
+
+    type AddAction = fn(&SemanticModel, &mut Vec<CodeActionOrCommand>, Range) -> Option<()>;
+
+    /// apply the first action produced for the range of `selected` in `code`
+    fn apply(code: &str, selected: &str, add_action: AddAction) -> Option<String> {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(code);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id)?;
+        let document = semantic_model.get_document();
+        let start = code.find(selected)?;
+        let range = document.to_lsp_range(TextRange::new(
+            TextSize::from(start as u32),
+            TextSize::from((start + selected.len()) as u32),
+        ))?;
+
+        let mut actions = Vec::new();
+        add_action(&semantic_model, &mut actions, range);
+        let CodeActionOrCommand::CodeAction(action) = actions.into_iter().next()? else {
+            return None;
+        };
+        let mut edits = action.edit?.changes?.into_values().next()?;
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.range.start));
+        let mut text = code.to_string();
+        for edit in edits {
+            let range = document.to_rowan_range(edit.range)?;
+            text.replace_range(
+                usize::from(range.start())..usize::from(range.end()),
+                &edit.new_text,
+            );
+        }
+        Some(text)
+    }
+
+    #[test]
+    fn test_extract_function() {
+        let code = "local a = 1\nlocal b = a + 1\nlocal c = b * 2\nprint(c)\n";
+        assert_eq!(
+            apply(code, "local b = a + 1\nlocal c = b * 2", add_extract_function_action)
+                .as_deref(),
+            Some(
+                "local a = 1\nlocal function extracted_function(a)\n    local b = a + 1\n    local c = b * 2\n    return c\nend\nlocal c = extracted_function(a)\nprint(c)\n"
+            )
+        );
+
+        let code =
+            "local function f(x)\n    if x then\n        return 1\n    end\n    return 2\nend\n";
+        assert_eq!(
+            apply(
+                code,
+                "if x then\n        return 1\n    end",
+                add_extract_function_action
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_local() {
+        let code = "local a = 1\nprint(a + 2)\n";
+        assert_eq!(
+            apply(code, "a + 2", add_extract_local_action).as_deref(),
+            Some("local a = 1\nlocal extracted = a + 2\nprint(extracted)\n")
+        );
+
+        let code = "local a\nwhile a + 1 do end\n";
+        assert_eq!(apply(code, "a + 1", add_extract_local_action), None);
+    }
+
+    #[test]
+    fn test_inline_variable() {
+        let code = "local a = 1\nlocal b = a + 2\nprint(b * 3)\n";
+        assert_eq!(
+            apply(code, "b =", add_inline_variable_action).as_deref(),
+            Some("local a = 1\nprint((a + 2) * 3)\n")
+        );
+
+        let code = "local b = 1\nb = 2\nprint(b)\n";
+        assert_eq!(apply(code, "b = 1", add_inline_variable_action), None);
+
+        let code = "local t = f()\nfor i = 1, 10 do\n    use(t)\nend\n";
+        assert_eq!(apply(code, "t =", add_inline_variable_action), None);
+
+        let code = "local t = {}\nlocal g = function()\n    return t\nend\n";
+        assert_eq!(apply(code, "t =", add_inline_variable_action), None);
+
+        let code = "for i = 1, 10 do\n    local t = f()\n    use(t, i)\nend\n";
+        assert_eq!(
+            apply(code, "t =", add_inline_variable_action).as_deref(),
+            Some("for i = 1, 10 do\n    use(f(), i)\nend\n")
+        );
+
+        let code = "local b = f()\nlocal t = {1, b}\n";
+        assert_eq!(
+            apply(code, "b =", add_inline_variable_action).as_deref(),
+            Some("local t = {1, (f())}\n")
+        );
+
+        let code = "local b = f()\nlocal t = {b, 1}\n";
+        assert_eq!(
+            apply(code, "b =", add_inline_variable_action).as_deref(),
+            Some("local t = {f(), 1}\n")
+        );
+
+        let code = "local b = f()\ng()\nprint(b)\n";
+        assert_eq!(apply(code, "b =", add_inline_variable_action), None);
+
+        let code = "local a = 1\nlocal b = a + 2\nprint(a)\nprint(b)\n";
+        assert_eq!(
+            apply(code, "b =", add_inline_variable_action).as_deref(),
+            Some("local a = 1\nprint(a)\nprint(a + 2)\n")
+        );
+    }
+
+    #[test]
+    fn test_convert_function() {
+        let code = "local M = {}\nfunction M:f(a)\n    return a\nend\n";
+        let converted = apply(code, "function", add_convert_function_action);
+        assert_eq!(
+            converted.as_deref(),
+            Some("local M = {}\nM.f = function(self, a)\n    return a\nend\n")
+        );
+        assert_eq!(
+            apply(&converted.unwrap(), "M.f", add_convert_function_action).as_deref(),
+            Some(code)
+        );
+    }
+}
@@ -5,9 +5,15 @@ use lsp_types::{CodeActionOrCommand, CodeActionResponse, Diagnostic, NumberOrStr
 
 use crate::handlers::command::{make_disable_code_command, DisableAction};
 
+use super::actions::{
+    add_convert_function_action, add_extract_function_action, add_extract_local_action,
+    add_implement_interface_action, add_inline_variable_action,
+};
+
 pub fn build_actions(
     semantic_model: &SemanticModel,
     diagnostics: Vec<Diagnostic>,
+    range: Range,
 ) -> Option<CodeActionResponse> {
     let mut actions = Vec::new();
     let file_id = semantic_model.get_file_id();
@@ -36,6 +42,12 @@ pub fn build_actions(
         }
     }
 
+    add_implement_interface_action(semantic_model, &mut actions, range);
+    add_extract_function_action(semantic_model, &mut actions, range);
+    add_extract_local_action(semantic_model, &mut actions, range);
+    add_inline_variable_action(semantic_model, &mut actions, range);
+    add_convert_function_action(semantic_model, &mut actions, range);
+
     Some(actions)
 }
 
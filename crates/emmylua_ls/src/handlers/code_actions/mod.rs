@@ -22,7 +22,7 @@ pub async fn on_code_action_handler(
     let file_id = analysis.get_file_id(&uri)?;
     let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
 
-    build_actions(&mut semantic_model, diagnostics)
+    build_actions(&mut semantic_model, diagnostics, params.range)
 }
 
 pub fn register_capabilities(
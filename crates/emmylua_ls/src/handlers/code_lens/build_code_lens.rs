@@ -2,16 +2,48 @@ use emmylua_code_analysis::{LuaDeclId, LuaMemberId, SemanticModel};
 use emmylua_parser::{LuaAst, LuaAstNode, LuaAstToken, LuaFuncStat, LuaLocalFuncStat, LuaVarExpr};
 use lsp_types::CodeLens;
 
-use super::CodeLensData;
+use crate::context::ClientId;
 
-pub fn build_code_lens(semantic_model: &SemanticModel) -> Option<Vec<CodeLens>> {
+use super::{
+    build_implementation_lens::{add_override_code_lens, add_sub_class_code_lens},
+    build_test_lens::{add_busted_test_code_lens, add_luaunit_test_code_lens},
+    CodeLensData,
+};
+
+pub fn build_code_lens(
+    semantic_model: &SemanticModel,
+    client_id: ClientId,
+) -> Option<Vec<CodeLens>> {
     let mut result = Vec::new();
+    let config = &semantic_model.get_emmyrc().code_lens;
     let root = semantic_model.get_root().clone();
     for node in root.descendants::<LuaAst>() {
         match node {
             LuaAst::LuaFuncStat(func_stat) => {
+                if config.implementation {
+                    add_override_code_lens(
+                        semantic_model,
+                        &mut result,
+                        func_stat.clone(),
+                        client_id,
+                    );
+                }
+                if config.test_enable {
+                    add_luaunit_test_code_lens(semantic_model, &mut result, func_stat.clone());
+                }
                 add_func_stat_code_lens(semantic_model, &mut result, func_stat)?;
             }
+            LuaAst::LuaDocTagClass(class_tag) if config.implementation => {
+                add_sub_class_code_lens(semantic_model, &mut result, class_tag, client_id);
+            }
+            LuaAst::LuaCallExpr(call_expr) if config.test_enable => {
+                add_busted_test_code_lens(
+                    semantic_model,
+                    &mut result,
+                    call_expr,
+                    &config.test_functions,
+                );
+            }
             LuaAst::LuaLocalFuncStat(local_func_stat) => {
                 add_local_func_stat_code_lens(semantic_model, &mut result, local_func_stat)?;
             }
@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+
+use emmylua_code_analysis::{
+    InferGuard, LuaMemberId, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId, SemanticModel,
+};
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaDocTagClass, LuaFuncStat, LuaIndexExpr, LuaVarExpr,
+};
+use lsp_types::{CodeLens, Location};
+
+use crate::context::ClientId;
+
+use super::resolve_code_lens::make_show_locations_command;
+
+pub fn add_sub_class_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    class_tag: LuaDocTagClass,
+    client_id: ClientId,
+) -> Option<()> {
+    let name_token = class_tag.get_name_token()?;
+    let type_index = semantic_model.get_db().get_type_index();
+    let type_decl =
+        type_index.find_type_decl(semantic_model.get_file_id(), name_token.get_name_text())?;
+    let sub_types = type_index.get_sub_types(&type_decl.get_id());
+    if sub_types.is_empty() {
+        return Some(());
+    }
+
+    let locations = sub_types
+        .iter()
+        .filter_map(|type_id| get_type_location(semantic_model, type_id))
+        .collect::<Vec<_>>();
+    let title = if sub_types.len() == 1 {
+        "1 subclass".to_string()
+    } else {
+        format!("{} subclasses", sub_types.len())
+    };
+
+    let document = semantic_model.get_document();
+    let range = document.to_lsp_range(name_token.get_range())?;
+    let command =
+        make_show_locations_command(title, document.get_uri(), range, client_id, locations);
+    result.push(CodeLens {
+        range,
+        command: Some(command),
+        data: None,
+    });
+
+    Some(())
+}
+
+// `overrides Parent:method` and `N overrides` on methods of classes
+pub fn add_override_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    func_stat: LuaFuncStat,
+    client_id: ClientId,
+) -> Option<()> {
+    let LuaVarExpr::IndexExpr(index_expr) = func_stat.get_func_name()? else {
+        return Some(());
+    };
+    let prefix_type = semantic_model.infer_expr(index_expr.get_prefix_expr()?)?;
+    let LuaType::Def(type_id) = prefix_type else {
+        return Some(());
+    };
+
+    let member_key: LuaMemberKey = index_expr.get_index_key()?.into();
+    let document = semantic_model.get_document();
+    let uri = document.get_uri();
+    let range = document.to_lsp_range(index_expr.get_index_name_token()?.text_range())?;
+    let type_index = semantic_model.get_db().get_type_index();
+
+    let infer_guard = &mut InferGuard::new();
+    for super_type in type_index.get_super_types(&type_id).unwrap_or_default() {
+        let Some((super_type_id, member_id)) =
+            find_super_member(semantic_model, super_type, &member_key, infer_guard)
+        else {
+            continue;
+        };
+
+        let separator = match index_expr.get_index_token() {
+            Some(index_token) if index_token.is_colon() => ":",
+            _ => ".",
+        };
+        let title = format!(
+            "overrides {}{}{}",
+            super_type_id.get_name(),
+            separator,
+            member_key.get_name().unwrap_or_default()
+        );
+        let locations = get_member_location(semantic_model, member_id)
+            .into_iter()
+            .collect();
+        let command = make_show_locations_command(title, uri.clone(), range, client_id, locations);
+        result.push(CodeLens {
+            range,
+            command: Some(command),
+            data: None,
+        });
+        break;
+    }
+
+    let mut locations = Vec::new();
+    collect_sub_type_overrides(
+        semantic_model,
+        &type_id,
+        &member_key,
+        &mut HashSet::new(),
+        &mut locations,
+    );
+    if !locations.is_empty() {
+        let title = if locations.len() == 1 {
+            "1 override".to_string()
+        } else {
+            format!("{} overrides", locations.len())
+        };
+        let command = make_show_locations_command(title, uri, range, client_id, locations);
+        result.push(CodeLens {
+            range,
+            command: Some(command),
+            data: None,
+        });
+    }
+
+    Some(())
+}
+
+fn find_super_member(
+    semantic_model: &SemanticModel,
+    super_type: LuaType,
+    member_key: &LuaMemberKey,
+    infer_guard: &mut InferGuard,
+) -> Option<(LuaTypeDeclId, LuaMemberId)> {
+    let LuaType::Ref(super_type_id) = super_type else {
+        return None;
+    };
+    infer_guard.check(&super_type_id)?;
+
+    let db = semantic_model.get_db();
+    let member_owner = LuaMemberOwner::Type(super_type_id.clone());
+    if let Some(member_id) = db
+        .get_member_index()
+        .get_member_map(member_owner)
+        .and_then(|member_map| member_map.get(member_key))
+    {
+        return Some((super_type_id, *member_id));
+    }
+
+    for super_type in db
+        .get_type_index()
+        .get_super_types(&super_type_id)
+        .unwrap_or_default()
+    {
+        if let Some(result) = find_super_member(semantic_model, super_type, member_key, infer_guard)
+        {
+            return Some(result);
+        }
+    }
+
+    None
+}
+
+fn collect_sub_type_overrides(
+    semantic_model: &SemanticModel,
+    type_id: &LuaTypeDeclId,
+    member_key: &LuaMemberKey,
+    visited: &mut HashSet<LuaTypeDeclId>,
+    locations: &mut Vec<Location>,
+) {
+    let db = semantic_model.get_db();
+    for sub_type_id in db.get_type_index().get_sub_types(type_id) {
+        if !visited.insert(sub_type_id.clone()) {
+            continue;
+        }
+
+        let member_owner = LuaMemberOwner::Type(sub_type_id.clone());
+        if let Some(member_id) = db
+            .get_member_index()
+            .get_member_map(member_owner)
+            .and_then(|member_map| member_map.get(member_key))
+        {
+            if let Some(location) = get_member_location(semantic_model, *member_id) {
+                locations.push(location);
+            }
+        }
+
+        collect_sub_type_overrides(semantic_model, &sub_type_id, member_key, visited, locations);
+    }
+}
+
+fn get_member_location(semantic_model: &SemanticModel, member_id: LuaMemberId) -> Option<Location> {
+    let member = semantic_model
+        .get_db()
+        .get_member_index()
+        .get_member(&member_id)?;
+    let file_id = member.get_file_id();
+    let document = semantic_model.get_document_by_file_id(file_id)?;
+    let root = semantic_model.get_root_by_file_id(file_id)?;
+    let node = member.get_syntax_id().to_node_from_root(root.syntax())?;
+    let range = if let Some(index_expr) = LuaIndexExpr::cast(node.clone()) {
+        index_expr.get_index_name_token()?.text_range()
+    } else {
+        node.text_range()
+    };
+
+    document.to_lsp_location(range)
+}
+
+fn get_type_location(semantic_model: &SemanticModel, type_id: &LuaTypeDeclId) -> Option<Location> {
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(type_id)?;
+    let location = type_decl.get_locations().first()?;
+    let document = semantic_model.get_document_by_file_id(location.file_id)?;
+    document.to_lsp_location(location.range)
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use emmylua_parser::{LuaAstNode, LuaDocTagClass, LuaFuncStat};
+    use lsp_types::{CodeLens, Location};
+
+    use crate::context::ClientId;
+
+    use super::{add_override_code_lens, add_sub_class_code_lens};
+
+    /// the title of each implementation lens of `code` and the lines of its locations
+    fn get_lenses(code: &str) -> Vec<(String, Vec<u32>)> {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(code);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let root = semantic_model.get_root().clone();
+
+        let mut result: Vec<CodeLens> = Vec::new();
+        for class_tag in root.descendants::<LuaDocTagClass>() {
+            add_sub_class_code_lens(&semantic_model, &mut result, class_tag, ClientId::Other);
+        }
+        for func_stat in root.descendants::<LuaFuncStat>() {
+            add_override_code_lens(&semantic_model, &mut result, func_stat, ClientId::Other);
+        }
+
+        result
+            .into_iter()
+            .map(|lens| {
+                let command = lens.command.unwrap();
+                let locations: Vec<Location> =
+                    serde_json::from_value(command.arguments.unwrap()[2].clone()).unwrap();
+                let mut lines = locations
+                    .iter()
+                    .map(|location| location.range.start.line)
+                    .collect::<Vec<_>>();
+                lines.sort();
+                (command.title, lines)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sub_class_lens() {
+        let lenses = get_lenses(
+            r#"
+            ---@class Shape
+            ---@class Circle: Shape
+            ---@class Square: Shape
+            ---@class Ball: Circle
+            "#,
+        );
+        assert_eq!(
+            lenses,
+            vec![
+                ("2 subclasses".to_string(), vec![2, 3]),
+                ("1 subclass".to_string(), vec![4]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_override_lens() {
+        let lenses = get_lenses(
+            r#"
+            ---@class Animal
+            local Animal = {}
+            function Animal:speak() end
+
+            ---@class Dog: Animal
+            local Dog = {}
+            function Dog:speak() end
+
+            ---@class Puppy: Dog
+            local Puppy = {}
+            function Puppy:speak() end
+            "#,
+        );
+        assert_eq!(
+            lenses,
+            vec![
+                ("1 subclass".to_string(), vec![5]),
+                ("1 subclass".to_string(), vec![9]),
+                ("2 overrides".to_string(), vec![7, 11]),
+                ("overrides Animal:speak".to_string(), vec![3]),
+                ("1 override".to_string(), vec![11]),
+                ("overrides Dog:speak".to_string(), vec![7]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaFuncStat, LuaLiteralToken, LuaVarExpr};
+use lsp_types::{CodeLens, Command, Range, Uri};
+use rowan::TextRange;
+
+use emmylua_code_analysis::SemanticModel;
+
+const RUN_TEST_COMMAND: &str = "emmy.runTest";
+const DEBUG_TEST_COMMAND: &str = "emmy.debugTest";
+
+// busted: `describe("name", function() it("name", function() end) end)`
+pub fn add_busted_test_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    call_expr: LuaCallExpr,
+    test_functions: &[String],
+) -> Option<()> {
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return Some(());
+    };
+    if !test_functions.contains(&name_expr.get_name_text()?) {
+        return Some(());
+    }
+
+    // busted filters by the full name, which joins the names of the enclosing blocks
+    let mut names = Vec::new();
+    for call_expr in call_expr.ancestors::<LuaCallExpr>() {
+        if let Some(name) = get_busted_test_name(&call_expr, test_functions) {
+            names.push(name);
+        }
+    }
+    if names.is_empty() {
+        return Some(());
+    }
+    names.reverse();
+
+    push_test_code_lens(
+        semantic_model,
+        result,
+        name_expr.get_range(),
+        names.join(" "),
+    )
+}
+
+fn get_busted_test_name(call_expr: &LuaCallExpr, test_functions: &[String]) -> Option<String> {
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    if !test_functions.contains(&name_expr.get_name_text()?) {
+        return None;
+    }
+
+    let first_arg = call_expr.get_args_list()?.get_args().next()?;
+    let LuaExpr::LiteralExpr(literal_expr) = first_arg else {
+        return None;
+    };
+    match literal_expr.get_literal()? {
+        LuaLiteralToken::String(string_token) => Some(string_token.get_value()),
+        _ => None,
+    }
+}
+
+// luaunit: global `test*` functions and `test*` methods of `Test*` tables
+pub fn add_luaunit_test_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    func_stat: LuaFuncStat,
+) -> Option<()> {
+    let (test_name, range) = match func_stat.get_func_name()? {
+        LuaVarExpr::NameExpr(name_expr) => {
+            let name = name_expr.get_name_text()?;
+            if !is_luaunit_test_name(&name) {
+                return Some(());
+            }
+            (name, name_expr.get_range())
+        }
+        LuaVarExpr::IndexExpr(index_expr) => {
+            let LuaExpr::NameExpr(prefix_expr) = index_expr.get_prefix_expr()? else {
+                return Some(());
+            };
+            let table_name = prefix_expr.get_name_text()?;
+            let name_token = index_expr.get_index_name_token()?;
+            if !is_luaunit_test_name(&table_name) || !is_luaunit_test_name(name_token.text()) {
+                return Some(());
+            }
+            (
+                format!("{}.{}", table_name, name_token.text()),
+                name_token.text_range(),
+            )
+        }
+    };
+
+    push_test_code_lens(semantic_model, result, range, test_name)
+}
+
+// `testFoo`, `test_foo`, `TestFoo` and `Test1`, but not `testament` or `Testify`
+fn is_luaunit_test_name(name: &str) -> bool {
+    let Some(rest) = name
+        .strip_prefix("test")
+        .or_else(|| name.strip_prefix("Test"))
+    else {
+        return false;
+    };
+
+    !rest.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+fn push_test_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    range: TextRange,
+    test_name: String,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let range = document.to_lsp_range(range)?;
+    let uri = document.get_uri();
+    result.push(make_test_code_lens(
+        "Run test",
+        RUN_TEST_COMMAND,
+        range,
+        &uri,
+        &test_name,
+    )?);
+    result.push(make_test_code_lens(
+        "Debug test",
+        DEBUG_TEST_COMMAND,
+        range,
+        &uri,
+        &test_name,
+    )?);
+    Some(())
+}
+
+fn make_test_code_lens(
+    title: &str,
+    command: &str,
+    range: Range,
+    uri: &Uri,
+    test_name: &str,
+) -> Option<CodeLens> {
+    let args = vec![
+        serde_json::to_value(uri).ok()?,
+        serde_json::to_value(test_name).ok()?,
+    ];
+
+    Some(CodeLens {
+        range,
+        command: Some(Command {
+            title: title.to_string(),
+            command: command.to_string(),
+            arguments: Some(args),
+        }),
+        data: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaFuncStat};
+
+    use super::{add_busted_test_code_lens, add_luaunit_test_code_lens, is_luaunit_test_name};
+
+    /// the test names of the "Run test" lenses in `code`
+    fn get_test_names(code: &str) -> Vec<String> {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(code);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let test_functions = vec!["describe".to_string(), "it".to_string()];
+        let root = semantic_model.get_root().clone();
+
+        let mut result = Vec::new();
+        for func_stat in root.descendants::<LuaFuncStat>() {
+            add_luaunit_test_code_lens(&semantic_model, &mut result, func_stat);
+        }
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            add_busted_test_code_lens(&semantic_model, &mut result, call_expr, &test_functions);
+        }
+
+        result
+            .into_iter()
+            .filter_map(|lens| lens.command)
+            .filter(|command| command.title == "Run test")
+            .map(|command| command.arguments.unwrap()[1].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_luaunit_test_name() {
+        assert!(is_luaunit_test_name("testAdd"));
+        assert!(is_luaunit_test_name("test_add"));
+        assert!(is_luaunit_test_name("TestMath"));
+        assert!(is_luaunit_test_name("Test1"));
+        assert!(is_luaunit_test_name("test"));
+        assert!(!is_luaunit_test_name("testament"));
+        assert!(!is_luaunit_test_name("Testify"));
+        assert!(!is_luaunit_test_name("TESTING"));
+        assert!(!is_luaunit_test_name("mytest"));
+    }
+
+    #[test]
+    fn test_luaunit_lens() {
+        let names = get_test_names(
+            r#"
+            function testAdd() end
+            function testament() end
+
+            TestMath = {}
+            function TestMath.testSub() end
+            function TestMath:test_mul() end
+            function TestMath.helper() end
+
+            Testify = {}
+            function Testify.testDiv() end
+            "#,
+        );
+        assert_eq!(
+            names,
+            vec!["testAdd", "TestMath.testSub", "TestMath.test_mul"]
+        );
+    }
+
+    #[test]
+    fn test_busted_lens() {
+        let names = get_test_names(
+            r#"
+            describe("math", function()
+                it("adds", function() end)
+                describe("div", function()
+                    it("by zero", function() end)
+                end)
+            end)
+            other("skipped", function() end)
+            "#,
+        );
+        assert_eq!(
+            names,
+            vec!["math", "math adds", "math div", "math div by zero"]
+        );
+    }
+}
@@ -1,4 +1,6 @@
 mod build_code_lens;
+mod build_implementation_lens;
+mod build_test_lens;
 mod resolve_code_lens;
 
 use build_code_lens::build_code_lens;
@@ -19,6 +21,7 @@ pub async fn on_code_lens_handler(
 ) -> Option<Vec<CodeLens>> {
     let uri = params.text_document.uri;
     let analysis = context.analysis.read().await;
+    let client_id = context.config_manager.read().await.client_config.client_id;
     let file_id = analysis.get_file_id(&uri)?;
     let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
 
@@ -26,7 +29,7 @@ pub async fn on_code_lens_handler(
         return None;
     }
 
-    build_code_lens(&mut semantic_model)
+    build_code_lens(&mut semantic_model, client_id)
 }
 
 pub async fn on_resolve_code_lens_handler(
@@ -72,6 +72,16 @@ fn make_usage_command(
     refs: Vec<Location>,
 ) -> Command {
     let title = format!("{} usage", ref_count);
+    make_show_locations_command(title, uri, range, client_id, refs)
+}
+
+pub fn make_show_locations_command(
+    title: String,
+    uri: Uri,
+    range: Range,
+    client_id: ClientId,
+    refs: Vec<Location>,
+) -> Command {
     let mut args = Vec::new();
     args.push(serde_json::to_value(uri).unwrap());
     args.push(serde_json::to_value(range.start).unwrap());
@@ -0,0 +1,21 @@
+use lsp_types::Command;
+use serde_json::Value;
+
+use crate::context::{CompletionHistoryKey, ServerContextSnapshot};
+
+pub const COMMAND: &str = "emmy.completion.accept";
+
+pub async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+    let key: CompletionHistoryKey = serde_json::from_value(args.first()?.clone()).ok()?;
+    context.completion_history.write().await.record(key);
+    Some(())
+}
+
+/// the client runs the command once the item is inserted, which is what the ranking remembers
+pub fn make_accept_completion_command(key: &CompletionHistoryKey) -> Command {
+    Command {
+        title: "accept completion".to_string(),
+        command: COMMAND.to_string(),
+        arguments: Some(vec![serde_json::to_value(key).unwrap()]),
+    }
+}
@@ -0,0 +1,132 @@
+use emmylua_code_analysis::{
+    DbIndex, FileId, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId,
+};
+use lsp_types::ShowDocumentParams;
+use rowan::TextRange;
+use serde_json::Value;
+
+use crate::context::ServerContextSnapshot;
+
+pub const COMMAND: &str = "emmy.goto.declaration";
+
+pub async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+    let name: String = serde_json::from_value(args.first()?.clone()).ok()?;
+    let from_file_id: Option<FileId> = args
+        .get(1)
+        .and_then(|arg| serde_json::from_value(arg.clone()).ok());
+
+    let analysis = context.analysis.read().await;
+    let db = analysis.compilation.get_db();
+    let (file_id, range) = find_declaration(db, from_file_id, &name)?;
+    let document = db.get_vfs().get_document(&file_id)?;
+    let selection = document.to_lsp_range(range)?;
+
+    context.client.show_document(ShowDocumentParams {
+        uri: document.get_uri(),
+        external: None,
+        take_focus: Some(true),
+        selection: Some(selection),
+    });
+
+    Some(())
+}
+
+/// resolve `Type`, `global`, `Type.member`, `Type:member` or `Type#member` to a location
+fn find_declaration(
+    db: &DbIndex,
+    from_file_id: Option<FileId>,
+    name: &str,
+) -> Option<(FileId, TextRange)> {
+    if let Some(location) = find_type(db, from_file_id, name) {
+        return Some(location);
+    }
+    if let Some(location) = find_global(db, name) {
+        return Some(location);
+    }
+
+    let split = name.rfind(['.', ':', '#'])?;
+    let (owner_name, member_name) = (&name[..split], &name[split + 1..]);
+    let owner = match from_file_id {
+        Some(file_id) => db
+            .get_type_index()
+            .find_type_decl(file_id, owner_name)
+            .map(|type_decl| LuaMemberOwner::Type(type_decl.get_id())),
+        None => db
+            .get_type_index()
+            .get_type_decl(&LuaTypeDeclId::new(owner_name))
+            .map(|type_decl| LuaMemberOwner::Type(type_decl.get_id())),
+    };
+    // a global table such as `M.func` has its members on the table itself
+    let owner = owner.or_else(|| {
+        let decl_id = db
+            .get_decl_index()
+            .get_global_decl_id(&LuaMemberKey::Name(owner_name.into()))?;
+        match db.get_decl_index().get_decl(&decl_id)?.get_type()? {
+            LuaType::TableConst(table) => Some(LuaMemberOwner::Element(table.clone())),
+            LuaType::Def(id) | LuaType::Ref(id) => Some(LuaMemberOwner::Type(id.clone())),
+            _ => None,
+        }
+    })?;
+
+    let member_map = db.get_member_index().get_member_map(owner)?;
+    let member_id = member_map.get(&LuaMemberKey::Name(member_name.into()))?;
+    let member = db.get_member_index().get_member(member_id)?;
+    Some((member.get_file_id(), member.get_range()))
+}
+
+fn find_type(
+    db: &DbIndex,
+    from_file_id: Option<FileId>,
+    name: &str,
+) -> Option<(FileId, TextRange)> {
+    let type_decl = match from_file_id {
+        Some(file_id) => db.get_type_index().find_type_decl(file_id, name),
+        None => db.get_type_index().get_type_decl(&LuaTypeDeclId::new(name)),
+    }?;
+    let location = type_decl.get_locations().first()?;
+    Some((location.file_id, location.range))
+}
+
+fn find_global(db: &DbIndex, name: &str) -> Option<(FileId, TextRange)> {
+    let decl_id = db
+        .get_decl_index()
+        .get_global_decl_id(&LuaMemberKey::Name(name.into()))?;
+    let decl = db.get_decl_index().get_decl(&decl_id)?;
+    Some((decl.get_file_id(), decl.get_range()))
+}
+
+/// a markdown link target that runs the command, usable inside hover text
+pub fn make_goto_declaration_uri(name: &str, from_file_id: FileId) -> String {
+    let args = vec![
+        Value::String(name.to_string()),
+        serde_json::to_value(from_file_id).unwrap(),
+    ];
+    format!(
+        "command:{}?{}",
+        COMMAND,
+        percent_encode(&Value::Array(args).to_string())
+    )
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut result = String::new();
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            result.push(byte as char);
+        } else {
+            result.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::percent_encode;
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode(r#"["Player"]"#), "%5B%22Player%22%5D");
+        assert_eq!(percent_encode("a.b_c"), "a.b_c");
+    }
+}
@@ -3,11 +3,15 @@ use serde_json::Value;
 use crate::context::ServerContextSnapshot;
 
 mod emmy_auto_require;
+mod emmy_completion_accept;
 mod emmy_disable_code;
 mod emmy_fix_format;
+mod emmy_goto_declaration;
 
 pub use emmy_disable_code::{make_disable_code_command, DisableAction};
 pub use emmy_auto_require::make_auto_require;
+pub use emmy_completion_accept::make_accept_completion_command;
+pub use emmy_goto_declaration::make_goto_declaration_uri;
 
 pub fn get_commands_list() -> Vec<String> {
     let mut commands = Vec::new();
@@ -21,8 +25,10 @@ pub fn get_commands_list() -> Vec<String> {
     }
 
     command_from!(emmy_auto_require);
+    command_from!(emmy_completion_accept);
     command_from!(emmy_disable_code);
     command_from!(emmy_fix_format);
+    command_from!(emmy_goto_declaration);
 
     commands
 }
@@ -49,7 +55,13 @@ pub async fn dispatch_command(
         command_name,
         context,
         args,
-        [emmy_auto_require, emmy_disable_code, emmy_fix_format]
+        [
+            emmy_auto_require,
+            emmy_completion_accept,
+            emmy_disable_code,
+            emmy_fix_format,
+            emmy_goto_declaration
+        ]
     );
 
     Some(())
@@ -1,14 +1,22 @@
 use std::collections::HashSet;
 
-use emmylua_code_analysis::SemanticModel;
+use emmylua_code_analysis::{LuaType, SemanticModel};
 use emmylua_parser::LuaSyntaxToken;
 use lsp_types::CompletionItem;
 use tokio_util::sync::CancellationToken;
 
+use crate::context::CompletionHistory;
+
 pub struct CompletionBuilder<'a> {
     pub trigger_token: LuaSyntaxToken,
     pub semantic_model: SemanticModel<'a>,
     pub env_duplicate_name: HashSet<String>,
+    /// parent methods offered as overrides, the member provider leaves them out
+    pub override_names: HashSet<String>,
+    /// the types expected at the trigger token, filled in by the type special provider
+    pub token_should_types: Vec<LuaType>,
+    /// the accepted completions the ranking prefers, none when the server keeps no history
+    pub completion_history: Option<&'a CompletionHistory>,
     completion_items: Vec<CompletionItem>,
     cancel_token: CancellationToken,
     stopped: bool,
@@ -24,6 +32,9 @@ impl<'a> CompletionBuilder<'a> {
             trigger_token,
             semantic_model,
             env_duplicate_name: HashSet::new(),
+            override_names: HashSet::new(),
+            token_should_types: Vec::new(),
+            completion_history: None,
             completion_items: Vec::new(),
             cancel_token,
             stopped: false,
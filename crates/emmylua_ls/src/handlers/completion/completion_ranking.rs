@@ -0,0 +1,385 @@
+use emmylua_code_analysis::{LuaDeclId, LuaMemberId, LuaMemberOwner, LuaPropertyOwnerId, LuaType};
+use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaIndexExpr, LuaSyntaxToken};
+
+use crate::{
+    context::{CompletionHistory, CompletionHistoryKey},
+    handlers::command::make_accept_completion_command,
+};
+
+use super::{add_completions::CompletionData, completion_builder::CompletionBuilder};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ScopeDistance {
+    Local,
+    Upvalue,
+    Module,
+    Global,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TypeMatch {
+    Match,
+    Unknown,
+    Mismatch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RankInfo {
+    type_match: TypeMatch,
+    recency: Option<usize>,
+    scope: ScopeDistance,
+    usage: usize,
+}
+
+/// items with a `sortText` set by their provider keep it, they are meant to go first
+pub fn rank_completion_items(builder: &mut CompletionBuilder) {
+    let expected_types = builder.token_should_types.clone();
+    let token = builder.trigger_token.clone();
+    let prefix_type = token
+        .parent()
+        .and_then(LuaIndexExpr::cast)
+        .and_then(|index_expr| index_expr.get_prefix_expr())
+        .and_then(|prefix_expr| builder.semantic_model.infer_expr(prefix_expr));
+
+    let items = builder
+        .get_completion_items_mut()
+        .iter()
+        .map(|item| {
+            (
+                item.sort_text.is_some(),
+                item.data.clone(),
+                item.label.clone(),
+                item.kind,
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut rank_infos = Vec::new();
+    for (has_sort_text, data, label, kind) in items {
+        if has_sort_text {
+            rank_infos.push(None);
+            continue;
+        }
+
+        let data = data.and_then(|data| serde_json::from_value::<CompletionData>(data).ok());
+        let property_owner_id = match data {
+            Some(CompletionData::PropertyOwnerId(id)) | Some(CompletionData::Overload((id, _))) => {
+                Some(id)
+            }
+            _ => None,
+        };
+
+        let (scope, usage, typ) = match &property_owner_id {
+            Some(LuaPropertyOwnerId::LuaDecl(decl_id)) => get_decl_rank(builder, &token, decl_id),
+            Some(LuaPropertyOwnerId::Member(member_id)) => {
+                get_member_rank(builder, prefix_type.as_ref(), member_id)
+            }
+            _ => (ScopeDistance::Module, 0, None),
+        };
+
+        let type_match = match typ {
+            Some(typ) if !expected_types.is_empty() && !typ.is_unknown() && !typ.is_any() => {
+                if expected_types
+                    .iter()
+                    .any(|expected| builder.semantic_model.type_check(expected, &typ).is_ok())
+                {
+                    TypeMatch::Match
+                } else {
+                    TypeMatch::Mismatch
+                }
+            }
+            _ => TypeMatch::Unknown,
+        };
+
+        let history_key = CompletionHistoryKey {
+            label,
+            kind,
+            owner: get_history_owner(builder, property_owner_id.as_ref()),
+        };
+        let recency = builder
+            .completion_history
+            .and_then(|history| history.get_recency(&history_key));
+        rank_infos.push(Some((
+            RankInfo {
+                type_match,
+                recency,
+                scope,
+                usage,
+            },
+            history_key,
+        )));
+    }
+
+    for (index, (item, rank_info)) in builder
+        .get_completion_items_mut()
+        .iter_mut()
+        .zip(rank_infos)
+        .enumerate()
+    {
+        let Some((rank_info, history_key)) = rank_info else {
+            continue;
+        };
+
+        item.sort_text = Some(build_sort_text(&rank_info, index));
+        if item.command.is_none() {
+            item.command = Some(make_accept_completion_command(&history_key));
+        }
+    }
+}
+
+/// the class a member belongs to, so the history tells `Foo.x` from `Bar.x`
+fn get_history_owner(
+    builder: &CompletionBuilder,
+    property_owner_id: Option<&LuaPropertyOwnerId>,
+) -> Option<String> {
+    let Some(LuaPropertyOwnerId::Member(member_id)) = property_owner_id else {
+        return None;
+    };
+    let member = builder
+        .semantic_model
+        .get_db()
+        .get_member_index()
+        .get_member(member_id)?;
+    match member.get_owner() {
+        LuaMemberOwner::Type(type_id) => Some(type_id.get_name().to_string()),
+        _ => None,
+    }
+}
+
+fn get_decl_rank(
+    builder: &CompletionBuilder,
+    token: &LuaSyntaxToken,
+    decl_id: &LuaDeclId,
+) -> (ScopeDistance, usize, Option<LuaType>) {
+    let db = builder.semantic_model.get_db();
+    let Some(decl) = db.get_decl_index().get_decl(decl_id) else {
+        return (ScopeDistance::Module, 0, None);
+    };
+    let typ = decl.get_type().cloned();
+    if decl.is_global() {
+        let usage = db
+            .get_reference_index()
+            .get_global_reference_count(decl.get_name());
+        return (ScopeDistance::Global, usage, typ);
+    }
+
+    let usage = db
+        .get_reference_index()
+        .get_decl_references(&decl.get_file_id(), decl_id)
+        .map(|references| references.len())
+        .unwrap_or(0);
+    if decl.get_file_id() != builder.semantic_model.get_file_id() {
+        return (ScopeDistance::Module, usage, typ);
+    }
+
+    let token_closure = token.parent_ancestors().find_map(LuaClosureExpr::cast);
+    let decl_closure = builder
+        .semantic_model
+        .get_root()
+        .syntax()
+        .token_at_offset(decl.get_position())
+        .right_biased()
+        .and_then(|decl_token| decl_token.parent_ancestors().find_map(LuaClosureExpr::cast));
+    let scope = match (decl_closure, token_closure) {
+        (None, _) => ScopeDistance::Module,
+        (Some(decl_closure), Some(token_closure)) if decl_closure == token_closure => {
+            ScopeDistance::Local
+        }
+        _ => ScopeDistance::Upvalue,
+    };
+
+    (scope, usage, typ)
+}
+
+/// members of the indexed type itself come before inherited ones, members offered outside
+/// an index expression, e.g. table fields, rank like module level names
+fn get_member_rank(
+    builder: &CompletionBuilder,
+    prefix_type: Option<&LuaType>,
+    member_id: &LuaMemberId,
+) -> (ScopeDistance, usize, Option<LuaType>) {
+    let db = builder.semantic_model.get_db();
+    let Some(member) = db.get_member_index().get_member(member_id) else {
+        return (ScopeDistance::Module, 0, None);
+    };
+
+    let usage = db
+        .get_reference_index()
+        .get_index_reference_count(member.get_key());
+    let scope = match (prefix_type, member.get_owner()) {
+        (None, _) => ScopeDistance::Module,
+        (Some(LuaType::Ref(type_id) | LuaType::Def(type_id)), LuaMemberOwner::Type(owner_id))
+            if *type_id != owner_id =>
+        {
+            ScopeDistance::Upvalue
+        }
+        _ => ScopeDistance::Local,
+    };
+
+    (scope, usage, Some(member.get_decl_type().clone()))
+}
+
+/// the order is type match, accepted recently, scope distance, usage count, provider order
+fn build_sort_text(rank_info: &RankInfo, index: usize) -> String {
+    let recency = rank_info
+        .recency
+        .unwrap_or(CompletionHistory::LIMIT)
+        .min(999);
+    let usage = 99999 - rank_info.usage.min(99999);
+    format!(
+        "1{}{:03}{}{:05}{:04}",
+        rank_info.type_match as u8, recency, rank_info.scope as u8, usage, index
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::CompletionItemKind;
+
+    use super::*;
+    use crate::handlers::{
+        command::make_accept_completion_command,
+        completion::providers::add_completions,
+        test_lib::{def_with_cursor, get_completion_items, get_token_at},
+    };
+    use emmylua_code_analysis::VirtualWorkspace;
+    use tokio_util::sync::CancellationToken;
+
+    fn get_item<'a>(
+        items: &'a [lsp_types::CompletionItem],
+        label: &str,
+    ) -> &'a lsp_types::CompletionItem {
+        items.iter().find(|item| item.label == label).unwrap()
+    }
+
+    fn get_sort_text(items: &[lsp_types::CompletionItem], label: &str) -> String {
+        get_item(items, label).sort_text.clone().unwrap()
+    }
+
+    #[test]
+    fn test_type_match_first() {
+        let items = get_completion_items(
+            r#"
+            ---@param n number
+            local function f(n) end
+
+            local value_str = "x"
+            local value_num = 1
+            f(value<|>)
+            "#,
+            add_completions,
+        );
+
+        let num_sort_text = get_sort_text(&items, "value_num");
+        let str_sort_text = get_sort_text(&items, "value_str");
+        assert!(num_sort_text < str_sort_text);
+        // the second digit is the type match
+        assert_eq!(&num_sort_text[1..2], "0");
+        assert_eq!(&str_sort_text[1..2], "2");
+    }
+
+    #[test]
+    fn test_recently_accepted_first() {
+        let mut ws = VirtualWorkspace::new();
+        let (file_id, offset) = def_with_cursor(
+            &mut ws,
+            r#"
+            ---@class Foo
+            ---@field alpha number
+            ---@field beta number
+
+            ---@class Bar
+            ---@field beta number
+
+            ---@type Foo
+            local foo
+            foo.<|>
+            "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let token = get_token_at(&semantic_model, offset);
+        let mut history = CompletionHistory::default();
+        // the same label accepted on another class does not count
+        history.record(CompletionHistoryKey {
+            label: "beta".to_string(),
+            kind: Some(CompletionItemKind::VARIABLE),
+            owner: Some("Bar".to_string()),
+        });
+        let mut builder = CompletionBuilder::new(token, semantic_model, CancellationToken::new());
+        builder.completion_history = Some(&history);
+        add_completions(&mut builder);
+        let items = builder.get_completion_items();
+        // the third to fifth digits are the recency
+        assert_eq!(&get_sort_text(&items, "beta")[2..5], "100");
+
+        let beta = get_item(&items, "beta");
+        let beta_key = CompletionHistoryKey {
+            label: "beta".to_string(),
+            kind: beta.kind,
+            owner: Some("Foo".to_string()),
+        };
+        assert_eq!(
+            beta.command,
+            Some(make_accept_completion_command(&beta_key))
+        );
+
+        history.record(beta_key);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let token = get_token_at(&semantic_model, offset);
+        let mut builder = CompletionBuilder::new(token, semantic_model, CancellationToken::new());
+        builder.completion_history = Some(&history);
+        add_completions(&mut builder);
+        let items = builder.get_completion_items();
+        assert_eq!(&get_sort_text(&items, "beta")[2..5], "000");
+        assert!(get_sort_text(&items, "beta") < get_sort_text(&items, "alpha"));
+    }
+
+    #[test]
+    fn test_completion_history() {
+        let key = |label: &str, owner: Option<&str>| CompletionHistoryKey {
+            label: label.to_string(),
+            kind: None,
+            owner: owner.map(|owner| owner.to_string()),
+        };
+        let mut history = CompletionHistory::default();
+        history.record(key("a", None));
+        history.record(key("b", None));
+        history.record(key("a", None));
+        assert_eq!(history.get_recency(&key("a", None)), Some(0));
+        assert_eq!(history.get_recency(&key("b", None)), Some(1));
+        assert_eq!(history.get_recency(&key("a", Some("Foo"))), None);
+        assert_eq!(history.get_recency(&key("c", None)), None);
+    }
+
+    #[test]
+    fn test_sort_text_order() {
+        let rank = |type_match, recency, scope, usage| RankInfo {
+            type_match,
+            recency,
+            scope,
+            usage,
+        };
+        let base = rank(TypeMatch::Unknown, None, ScopeDistance::Module, 0);
+        let mut sort_texts = vec![
+            build_sort_text(&base, 0),
+            build_sort_text(
+                &rank(TypeMatch::Unknown, None, ScopeDistance::Module, 10),
+                1,
+            ),
+            build_sort_text(&rank(TypeMatch::Unknown, None, ScopeDistance::Local, 0), 2),
+            build_sort_text(
+                &rank(TypeMatch::Unknown, Some(3), ScopeDistance::Global, 0),
+                3,
+            ),
+            build_sort_text(&rank(TypeMatch::Match, None, ScopeDistance::Global, 0), 4),
+        ];
+        let expected = vec![
+            sort_texts[4].clone(),
+            sort_texts[3].clone(),
+            sort_texts[2].clone(),
+            sort_texts[1].clone(),
+            sort_texts[0].clone(),
+        ];
+        sort_texts.sort();
+        assert_eq!(sort_texts, expected);
+    }
+}
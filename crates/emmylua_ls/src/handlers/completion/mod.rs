@@ -1,11 +1,12 @@
 mod add_completions;
 mod completion_builder;
+mod completion_ranking;
 mod data;
 mod providers;
 mod resolve_completion;
 
 use add_completions::CompletionData;
-use completion_builder::CompletionBuilder;
+pub use completion_builder::CompletionBuilder;
 use emmylua_code_analysis::LuaPropertyOwnerId;
 use emmylua_parser::LuaAstNode;
 use log::error;
@@ -28,6 +29,7 @@ pub async fn on_completion_handler(
     let uri = params.text_document_position.text_document.uri;
     let position = params.text_document_position.position;
     let analysis = context.analysis.read().await;
+    let completion_history = context.completion_history.read().await;
     let file_id = analysis.get_file_id(&uri)?;
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     if !semantic_model.get_emmyrc().completion.enable {
@@ -53,6 +55,7 @@ pub async fn on_completion_handler(
     };
 
     let mut builder = CompletionBuilder::new(token, semantic_model, cancel_token);
+    builder.completion_history = Some(&completion_history);
     add_completions(&mut builder);
     Some(CompletionResponse::Array(builder.get_completion_items()))
 }
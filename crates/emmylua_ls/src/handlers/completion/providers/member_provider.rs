@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use emmylua_code_analysis::LuaMemberKey;
 use emmylua_parser::{LuaAstNode, LuaAstToken, LuaIndexExpr, LuaStringToken};
 
 use crate::handlers::completion::{
@@ -32,6 +33,12 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
         if duplicated_set.contains(&member_info.key) {
             continue;
         }
+        // already offered as an override stub
+        if let LuaMemberKey::Name(name) = &member_info.key {
+            if builder.override_names.contains(name.as_str()) {
+                continue;
+            }
+        }
 
         duplicated_set.insert(member_info.key.clone());
         add_member_completion(builder, member_info, completion_status);
@@ -7,11 +7,12 @@ mod file_path_provider;
 mod keywords_provider;
 mod member_provider;
 mod module_path_provider;
+mod override_provider;
 mod postfix_provider;
 mod table_decl_field_provider;
 mod type_special_provider;
 
-use super::completion_builder::CompletionBuilder;
+use super::{completion_builder::CompletionBuilder, completion_ranking::rank_completion_items};
 
 pub fn add_completions(builder: &mut CompletionBuilder) -> Option<()> {
     module_path_provider::add_completion(builder);
@@ -19,6 +20,7 @@ pub fn add_completions(builder: &mut CompletionBuilder) -> Option<()> {
     keywords_provider::add_completion(builder);
     type_special_provider::add_completion(builder);
     env_provider::add_completion(builder);
+    override_provider::add_completion(builder);
     member_provider::add_completion(builder);
     table_decl_field_provider::add_completion(builder);
     auto_require_provider::add_completion(builder);
@@ -27,11 +29,7 @@ pub fn add_completions(builder: &mut CompletionBuilder) -> Option<()> {
     doc_name_token_provider::add_completion(builder);
     postfix_provider::add_completion(builder);
 
-    for (index, item) in builder.get_completion_items_mut().iter_mut().enumerate() {
-        if item.sort_text.is_none() {
-            item.sort_text = Some(format!("{:04}", index));
-        }
-    }
+    rank_completion_items(builder);
 
     Some(())
 }
@@ -0,0 +1,144 @@
+use emmylua_code_analysis::LuaType;
+use emmylua_parser::{LuaAstNode, LuaFuncStat, LuaIndexExpr};
+use lsp_types::{CompletionItem, InsertTextFormat, TextEdit};
+use rowan::TextRange;
+
+use crate::{
+    handlers::completion::completion_builder::CompletionBuilder,
+    util::{collect_super_methods, get_indent_unit},
+};
+
+/// parent methods not overridden yet, after `function Child:` or `function Child.`
+pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
+    if builder.is_cancelled() {
+        return None;
+    }
+
+    let index_expr = LuaIndexExpr::cast(builder.trigger_token.parent()?)?;
+    let func_stat = index_expr.get_parent::<LuaFuncStat>()?;
+    let is_colon = index_expr.get_index_token()?.is_colon();
+    let prefix_type = builder
+        .semantic_model
+        .infer_expr(index_expr.get_prefix_expr()?)?;
+    let LuaType::Def(type_id) = prefix_type else {
+        return None;
+    };
+
+    let db = builder.semantic_model.get_db();
+    let super_ids = db.get_type_index().get_all_super_type_ids(&type_id);
+    let methods = collect_super_methods(db, &type_id, &super_ids, false);
+    if methods.is_empty() {
+        return None;
+    }
+
+    let document = builder.semantic_model.get_document();
+    let stat_position = func_stat.get_position();
+    let line = document.get_line(stat_position)?;
+    let line_start = document.get_offset(line, 0)?;
+    let indent = document.get_text_slice(TextRange::new(line_start, stat_position));
+    let indent = if indent.trim().is_empty() {
+        indent.to_string()
+    } else {
+        String::new()
+    };
+    let indent_unit = get_indent_unit(document.get_text());
+    let doc_position = document.to_lsp_position(stat_position)?;
+
+    let mut completion_items = Vec::new();
+    for (super_id, mut stub) in methods {
+        // `function Child.method(self, ...)` when the parent uses `:`
+        if stub.is_colon && !is_colon {
+            stub.params.insert(0, ("self".to_string(), None, false));
+        }
+
+        let doc_lines = stub.build_doc_lines(db);
+        let additional_text_edits = if doc_lines.is_empty() {
+            None
+        } else {
+            let new_text = doc_lines
+                .iter()
+                .map(|line| format!("{}\n{}", line, indent))
+                .collect::<String>();
+            Some(vec![TextEdit {
+                range: lsp_types::Range {
+                    start: doc_position,
+                    end: doc_position,
+                },
+                new_text,
+            }])
+        };
+
+        let params = stub.get_param_names().join(", ");
+        completion_items.push(CompletionItem {
+            label: stub.name.clone(),
+            kind: Some(lsp_types::CompletionItemKind::METHOD),
+            label_details: Some(lsp_types::CompletionItemLabelDetails {
+                detail: Some(format!("({})", params)),
+                description: Some(format!("override {}", super_id.get_name())),
+            }),
+            sort_text: Some("0".to_string()),
+            insert_text: Some(format!(
+                "{}({})\n{}{}$0\n{}end",
+                stub.name, params, indent, indent_unit, indent
+            )),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            additional_text_edits,
+            ..Default::default()
+        });
+    }
+
+    for completion_item in completion_items {
+        builder.override_names.insert(completion_item.label.clone());
+        builder.add_completion_item(completion_item);
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::handlers::{completion::providers::add_completions, test_lib::get_completion_items};
+
+    #[test]
+    fn test_override_completion() {
+        let items = get_completion_items(
+            r#"
+            ---@class Base
+            local Base = {}
+
+            function Base:greet()
+            end
+
+            ---@class Foo : Base
+            ---@field draw fun(self: Foo)
+            local Foo = {}
+
+            function Foo:x<|>()
+            end
+            "#,
+            add_completions,
+        );
+
+        // the inherited method only once, as an override stub
+        let greets = items
+            .iter()
+            .filter(|item| item.label == "greet")
+            .collect::<Vec<_>>();
+        assert_eq!(greets.len(), 1);
+        assert_eq!(
+            greets[0].insert_text.as_deref(),
+            Some("greet()\n                $0\n            end")
+        );
+        // the class's own declared method without a body yet
+        assert!(items.iter().any(|item| item.label == "draw"));
+    }
+
+    #[test]
+    fn test_override_indent_from_code() {
+        let items = get_completion_items(
+            "---@class Base\nlocal Base = {}\n\nfunction Base:greet()\n\tprint(1)\nend\n\n---@class Foo : Base\nlocal Foo = {}\n\nfunction Foo:<|>\n",
+            add_completions,
+        );
+        let greet = items.iter().find(|item| item.label == "greet").unwrap();
+        assert_eq!(greet.insert_text.as_deref(), Some("greet()\n\t$0\nend"));
+    }
+}
@@ -1,12 +1,16 @@
 use emmylua_code_analysis::{
-    InferGuard, LuaDeclLocation, LuaFunctionType, LuaMemberId, LuaMemberKey, LuaMemberOwner, LuaMultiLineUnion, LuaPropertyOwnerId, LuaType, LuaTypeDeclId, LuaUnionType, RenderLevel
+    instantiate_type, InferGuard, LuaDeclLocation, LuaFunctionType, LuaMemberId, LuaMemberKey,
+    LuaMemberOwner, LuaMultiLineUnion, LuaOperatorMetaMethod, LuaPropertyOwnerId, LuaType,
+    LuaTypeDeclId, LuaUnionType, RenderLevel, TypeSubstitutor,
 };
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaAstToken, LuaCallArgList, LuaCallExpr, LuaComment, LuaExpr,
-    LuaNameToken, LuaSyntaxId, LuaSyntaxKind, LuaSyntaxToken, LuaTokenKind, LuaVarExpr,
+    BinaryOperator, LuaAst, LuaAstNode, LuaAstToken, LuaBinaryExpr, LuaCallArgList, LuaCallExpr,
+    LuaComment, LuaExpr, LuaIndexExpr, LuaIndexKey, LuaNameToken, LuaSyntaxId, LuaSyntaxKind,
+    LuaSyntaxToken, LuaTableExpr, LuaTableField, LuaTokenKind, LuaVarExpr,
 };
 use itertools::Itertools;
-use lsp_types::{CompletionItem, Documentation};
+use lsp_types::{CompletionItem, CompletionTextEdit, Documentation, TextEdit};
+use rowan::{TextRange, TextSize};
 
 use crate::handlers::{
     completion::completion_builder::CompletionBuilder, signature_helper::get_current_param_index,
@@ -19,9 +23,15 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
     }
 
     let types = get_token_should_type(builder)?;
+    builder.token_should_types = types.clone();
+    let start = builder.get_completion_items_mut().len();
     for typ in types {
         dispatch_type(builder, typ, &mut InferGuard::new());
     }
+
+    if is_in_string(builder) {
+        add_string_text_edits(builder, start);
+    }
     Some(())
 }
 
@@ -40,7 +50,7 @@ fn dispatch_type(
         LuaType::Nullable(typ) => {
             dispatch_type(builder, (*typ).clone(), infer_guard);
         }
-        LuaType::DocFunction(func) => {
+        LuaType::DocFunction(func) if !is_in_string(builder) => {
             add_lambda_completion(builder, &func);
         }
         LuaType::DocStringConst(key) => {
@@ -49,6 +59,14 @@ fn dispatch_type(
         LuaType::MultiLineUnion(multi_union) => {
             add_multi_line_union_member_completion(builder, &multi_union, infer_guard);
         }
+        // `keyof T` and the other alias calls
+        LuaType::Call(_) => {
+            let db = builder.semantic_model.get_db();
+            let typ = instantiate_type(db, &typ, &TypeSubstitutor::new());
+            if !typ.is_call() {
+                dispatch_type(builder, typ, infer_guard);
+            }
+        }
         _ => {}
     }
 
@@ -102,7 +120,7 @@ fn add_type_ref_completion(
             for completion_item in completion_items {
                 builder.add_completion_item(completion_item);
             }
-        } else {
+        } else if !is_in_string(builder) {
             let locations = type_decl
                 .get_locations()
                 .iter()
@@ -126,7 +144,8 @@ fn add_union_member_completion(
     for union_sub_typ in union_typ.get_types() {
         let name = match union_sub_typ {
             LuaType::DocStringConst(s) => to_enum_label(builder, s),
-            LuaType::DocIntegerConst(i) => i.to_string(),
+            LuaType::DocIntegerConst(i) if !is_in_string(builder) => i.to_string(),
+            LuaType::DocIntegerConst(_) => continue,
             _ => {
                 dispatch_type(builder, union_sub_typ.clone(), infer_guard);
                 continue;
@@ -156,9 +175,15 @@ fn add_string_completion(builder: &mut CompletionBuilder, str: &str) -> Option<(
     Some(())
 }
 
+/// the types expected at the completion position, e.g. the parameter of a call argument
 fn get_token_should_type(builder: &mut CompletionBuilder) -> Option<Vec<LuaType>> {
     let token = builder.trigger_token.clone();
     let mut parent_node = token.parent()?;
+    // `a == ` without the right operand yet
+    if let Some(binary_expr) = LuaBinaryExpr::cast(parent_node.clone()) {
+        return infer_binary_expr(builder, binary_expr, token);
+    }
+
     if LuaExpr::can_cast(parent_node.kind().into()) {
         parent_node = parent_node.parent()?;
     }
@@ -168,7 +193,13 @@ fn get_token_should_type(builder: &mut CompletionBuilder) -> Option<Vec<LuaType>
             return infer_call_arg_list(builder, LuaCallArgList::cast(parent_node)?, token);
         }
         LuaSyntaxKind::BinaryExpr => {
-            // infer_binary_expr(builder, binary_expr)?;
+            return infer_binary_expr(builder, LuaBinaryExpr::cast(parent_node)?, token);
+        }
+        LuaSyntaxKind::IndexExpr => {
+            return infer_index_key(builder, LuaIndexExpr::cast(parent_node)?, token);
+        }
+        LuaSyntaxKind::TableFieldAssign => {
+            return infer_table_field_key(builder, LuaTableField::cast(parent_node)?, token);
         }
         _ => {}
     }
@@ -176,6 +207,122 @@ fn get_token_should_type(builder: &mut CompletionBuilder) -> Option<Vec<LuaType>
     None
 }
 
+/// the other side of `==` and `~=`
+fn infer_binary_expr(
+    builder: &mut CompletionBuilder,
+    binary_expr: LuaBinaryExpr,
+    token: LuaSyntaxToken,
+) -> Option<Vec<LuaType>> {
+    let op_token = binary_expr.get_op_token()?;
+    if !matches!(
+        op_token.get_op(),
+        BinaryOperator::OpEq | BinaryOperator::OpNe
+    ) {
+        return None;
+    }
+
+    let left_expr = binary_expr.child::<LuaExpr>()?;
+    if token.text_range().start() < op_token.get_position() {
+        return None;
+    }
+
+    let typ = builder.semantic_model.infer_expr(left_expr)?;
+    Some(vec![typ])
+}
+
+/// `t["..."]`, members are offered by the member provider, this adds the indexed key types
+fn infer_index_key(
+    builder: &mut CompletionBuilder,
+    index_expr: LuaIndexExpr,
+    token: LuaSyntaxToken,
+) -> Option<Vec<LuaType>> {
+    let LuaIndexKey::String(key_token) = index_expr.get_index_key()? else {
+        return None;
+    };
+    if key_token.syntax() != &token {
+        return None;
+    }
+
+    let prefix_type = builder
+        .semantic_model
+        .infer_expr(index_expr.get_prefix_expr()?)?;
+    let types = get_index_key_types(builder, &prefix_type);
+    if types.is_empty() {
+        None
+    } else {
+        Some(types)
+    }
+}
+
+/// `{ ["..."] = value }` inside a table whose type is known
+fn infer_table_field_key(
+    builder: &mut CompletionBuilder,
+    table_field: LuaTableField,
+    token: LuaSyntaxToken,
+) -> Option<Vec<LuaType>> {
+    let LuaIndexKey::String(key_token) = table_field.get_field_key()? else {
+        return None;
+    };
+    if key_token.syntax() != &token {
+        return None;
+    }
+
+    let table_expr = table_field.get_parent::<LuaTableExpr>()?;
+    let table_type = builder.semantic_model.infer_table_should_be(table_expr)?;
+    let mut types = get_index_key_types(builder, &table_type);
+    let member_names = builder
+        .semantic_model
+        .infer_member_infos(&table_type)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|member_info| match member_info.key {
+            LuaMemberKey::Name(name) => Some(LuaType::DocStringConst(name.into())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if !member_names.is_empty() {
+        types.push(LuaType::Union(LuaUnionType::new(member_names).into()));
+    }
+
+    if types.is_empty() {
+        None
+    } else {
+        Some(types)
+    }
+}
+
+/// key types of `---@field [K] V` and `table<K, V>`
+fn get_index_key_types(builder: &CompletionBuilder, typ: &LuaType) -> Vec<LuaType> {
+    let db = builder.semantic_model.get_db();
+    let mut types = Vec::new();
+    match typ {
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => {
+            let operator_index = db.get_operator_index();
+            if let Some(operator_ids) = operator_index
+                .get_operators_by_type(type_decl_id)
+                .and_then(|operators| operators.get(&LuaOperatorMetaMethod::Index))
+            {
+                for operator_id in operator_ids {
+                    if let Some(operator) = operator_index.get_operator(operator_id) {
+                        types.extend(operator.get_operands(db).into_iter().take(1));
+                    }
+                }
+            }
+        }
+        LuaType::TableGeneric(params) => {
+            if let Some(key_type) = params.first() {
+                types.push(key_type.clone());
+            }
+        }
+        LuaType::Nullable(typ) => {
+            types = get_index_key_types(builder, typ);
+        }
+        _ => {}
+    }
+
+    types
+}
+
 fn infer_call_arg_list(
     builder: &mut CompletionBuilder,
     call_arg_list: LuaCallArgList,
@@ -285,7 +432,8 @@ fn add_multi_line_union_member_completion(
     for (union_sub_typ, description) in union_typ.get_unions() {
         let name = match union_sub_typ {
             LuaType::DocStringConst(s) => to_enum_label(builder, s),
-            LuaType::DocIntegerConst(i) => i.to_string(),
+            LuaType::DocIntegerConst(i) if !is_in_string(builder) => i.to_string(),
+            LuaType::DocIntegerConst(_) => continue,
             _ => {
                 dispatch_type(builder, union_sub_typ.clone(), infer_guard);
                 continue;
@@ -321,17 +469,54 @@ fn add_multi_line_union_member_completion(
     Some(())
 }
 
-fn to_enum_label(builder: &CompletionBuilder, str: &str) -> String {
-    if matches!(
+fn is_in_string(builder: &CompletionBuilder) -> bool {
+    matches!(
         builder.trigger_token.kind().into(),
         LuaTokenKind::TkString | LuaTokenKind::TkLongString
-    ) {
+    )
+}
+
+fn to_enum_label(builder: &CompletionBuilder, str: &str) -> String {
+    if is_in_string(builder) {
         str.to_string()
     } else {
         format!("\"{}\"", str)
     }
 }
 
+/// replace the whole content between the quotes, values like `on-click` are not a single word
+fn add_string_text_edits(builder: &mut CompletionBuilder, start: usize) -> Option<()> {
+    let token = builder.trigger_token.clone();
+    if token.kind() != LuaTokenKind::TkString.into() {
+        return None;
+    }
+
+    let text = token.text();
+    let quote = text.chars().next()?;
+    let range = token.text_range();
+    let content_start = range.start() + TextSize::from(1);
+    let content_end = if text.len() > 1 && text.ends_with(quote) {
+        range.end() - TextSize::from(1)
+    } else {
+        range.end()
+    };
+    let lsp_range = builder
+        .semantic_model
+        .get_document()
+        .to_lsp_range(TextRange::new(content_start, content_end))?;
+
+    for item in builder.get_completion_items_mut().iter_mut().skip(start) {
+        if item.text_edit.is_none() && item.insert_text.is_none() {
+            item.text_edit = Some(CompletionTextEdit::Edit(TextEdit {
+                range: lsp_range,
+                new_text: item.label.clone(),
+            }));
+        }
+    }
+
+    Some(())
+}
+
 fn add_lambda_completion(builder: &mut CompletionBuilder, func: &LuaFunctionType) -> Option<()> {
     let params_str = func
         .get_params()
@@ -484,3 +669,102 @@ fn get_enum_decl_variable_name(
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{CompletionItem, CompletionTextEdit};
+
+    use super::add_completion;
+    use crate::handlers::test_lib::get_completion_items;
+
+    /// items of this provider with the cursor at `<|>`
+    fn get_items(code: &str) -> Vec<CompletionItem> {
+        get_completion_items(code, add_completion)
+    }
+
+    fn get_labels(code: &str) -> Vec<String> {
+        let mut labels = get_items(code)
+            .into_iter()
+            .map(|item| item.label)
+            .collect::<Vec<_>>();
+        labels.sort();
+        labels
+    }
+
+    #[test]
+    fn test_literal_union_param() {
+        let defs = r#"
+        ---@param event "on-click" | "on-hover"
+        local function on(event) end
+        "#;
+        assert_eq!(
+            get_labels(&format!("{defs}on(<|>)")),
+            vec!["\"on-click\"", "\"on-hover\""]
+        );
+
+        let items = get_items(&format!("{defs}on('on-<|>')"));
+        let labels = items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["on-click", "on-hover"]);
+        // the whole content between the quotes is replaced, not only the word before the cursor
+        let Some(CompletionTextEdit::Edit(edit)) = &items[0].text_edit else {
+            panic!("expected a text edit");
+        };
+        assert_eq!(edit.new_text, "on-click");
+        assert_eq!(edit.range.start.character, 12);
+        assert_eq!(edit.range.end.character, 15);
+    }
+
+    #[test]
+    fn test_keyof_param() {
+        let code = r#"
+        ---@class Options
+        ---@field width number
+        ---@field height number
+
+        ---@param key keyof Options
+        local function get(key) end
+
+        get("<|>")
+        "#;
+        assert_eq!(get_labels(code), vec!["height", "width"]);
+    }
+
+    #[test]
+    fn test_index_key() {
+        let code = r#"
+        ---@type table<"red" | "green", number>
+        local colors
+
+        local c = colors["<|>"]
+        "#;
+        assert_eq!(get_labels(code), vec!["green", "red"]);
+    }
+
+    #[test]
+    fn test_table_field_key() {
+        let code = r#"
+        ---@class Style
+        ---@field color string
+        ---@field size number
+
+        ---@type Style
+        local style = { ["<|>"] = 1 }
+        "#;
+        assert_eq!(get_labels(code), vec!["color", "size"]);
+    }
+
+    #[test]
+    fn test_binary_compare() {
+        let code = r#"
+        ---@type "up" | "down"
+        local dir
+
+        if dir == "<|>" then
+        end
+        "#;
+        assert_eq!(get_labels(code), vec!["down", "up"]);
+    }
+}
@@ -66,7 +66,7 @@ pub fn build_hover_content<'a>(
 ) -> Option<HoverBuilder<'a>> {
     let semantic_model = semantic_model?;
     let mut builder = HoverBuilder::new(semantic_model, token, is_completion);
-    match property_id {
+    match property_id.clone() {
         LuaPropertyOwnerId::LuaDecl(decl_id) => {
             let effective_typ = match typ {
                 Some(t) => t,
@@ -92,6 +92,9 @@ pub fn build_hover_content<'a>(
         }
         _ => return None,
     }
+    builder.add_see_links(property_id.clone());
+    add_doc_meta_description(db, &mut builder.annotation_description, property_id.clone());
+    builder.set_source_footer(&property_id);
     Some(builder)
 }
 
@@ -170,6 +173,7 @@ fn build_decl_hover(
                 .and_then(|d| builder.add_description(LuaPropertyOwnerId::LuaDecl(d.get_id())))
         });
 
+    builder.set_type_links(&typ);
     builder.add_signature_params_rets_description(typ);
     Some(())
 }
@@ -251,6 +255,7 @@ fn build_member_hover(
                 .and_then(|m| builder.add_description(LuaPropertyOwnerId::Member(m.get_id())))
         });
 
+    builder.set_type_links(&typ);
     builder.add_signature_params_rets_description(typ);
     Some(())
 }
@@ -336,6 +341,47 @@ pub fn add_signature_ret_description(
     Some(())
 }
 
+/// `@since`, `@throws`, `@author` and `@example` of the owner
+pub fn add_doc_meta_description(
+    db: &DbIndex,
+    marked_strings: &mut Vec<MarkedString>,
+    property_owner: LuaPropertyOwnerId,
+) -> Option<()> {
+    let property = db.get_property_index().get_property(property_owner)?;
+    let mut s = String::new();
+    if let Some(since) = &property.since {
+        s.push_str(&format!("@*since* {}\n\n", since));
+    }
+    if let Some(throws) = &property.throws {
+        for (typ, description) in throws.iter() {
+            let type_text = humanize_type(db, typ, RenderLevel::Simple);
+            match description {
+                Some(description) => {
+                    s.push_str(&format!("@*throws* `{}` — {}\n\n", type_text, description))
+                }
+                None => s.push_str(&format!("@*throws* `{}`\n\n", type_text)),
+            }
+        }
+    }
+    if let Some(authors) = &property.authors {
+        s.push_str(&format!("@*author* {}\n\n", authors.join(", ")));
+    }
+    if !s.is_empty() {
+        marked_strings.push(MarkedString::from_markdown(s));
+    }
+
+    if let Some(examples) = &property.examples {
+        for example in examples.iter() {
+            marked_strings.push(MarkedString::from_markdown("**Example**".to_string()));
+            marked_strings.push(MarkedString::from_language_code(
+                "lua".to_string(),
+                example.clone(),
+            ));
+        }
+    }
+    Some(())
+}
+
 // 获取`decl`可能的来源
 fn get_decl_owner(
     semantic_model: &SemanticModel,
@@ -8,6 +8,7 @@ use crate::handlers::hover::std_hover::{hover_std_description, is_std_by_name};
 
 use super::{
     build_hover::{add_signature_param_description, add_signature_ret_description},
+    hover_links::{build_see_links, build_source_footer, link_signature_types, render_description},
     std_hover::is_std_by_path,
 };
 
@@ -23,6 +24,8 @@ pub struct HoverBuilder<'a> {
     pub annotation_description: Vec<MarkedString>,
     /// 类型展开, 常用于 alias 类型
     pub type_expansion: Option<Vec<String>>,
+    /// 声明来源, 如 std 或第三方库
+    pub footer: Option<MarkedString>,
 
     pub is_completion: bool,
     trigger_token: Option<LuaSyntaxToken>,
@@ -44,6 +47,7 @@ impl<'a> HoverBuilder<'a> {
             is_completion,
             trigger_token: token,
             type_expansion: None,
+            footer: None,
         }
     }
 
@@ -67,6 +71,35 @@ impl<'a> HoverBuilder<'a> {
         }
     }
 
+    /// link the types named in the signature to their declarations
+    pub fn set_type_links(&mut self, typ: &LuaType) {
+        // command links are only useful in a hover the user can click
+        if self.is_completion {
+            return;
+        }
+        let MarkedString::LanguageString(signature) = &self.type_description else {
+            return;
+        };
+        let file_id = self.semantic_model.get_file_id();
+        if let Some(linked) =
+            link_signature_types(self.semantic_model.get_db(), &signature.value, typ, file_id)
+        {
+            self.type_description = MarkedString::String(linked);
+        }
+    }
+
+    pub fn add_see_links(&mut self, property_owner: LuaPropertyOwnerId) -> Option<()> {
+        let file_id = self.semantic_model.get_file_id();
+        let see_links = build_see_links(self.semantic_model.get_db(), property_owner, file_id)?;
+        self.add_annotation_description(see_links);
+        Some(())
+    }
+
+    pub fn set_source_footer(&mut self, property_owner: &LuaPropertyOwnerId) {
+        self.footer = build_source_footer(self.semantic_model.get_db(), property_owner)
+            .map(MarkedString::from_markdown);
+    }
+
     pub fn add_signature_overload(&mut self, signature_overload: String) {
         if self.signature_overload.is_none() {
             self.signature_overload = Some(Vec::new());
@@ -156,7 +189,7 @@ impl<'a> HoverBuilder<'a> {
             _ => {}
         }
 
-        self.add_annotation_description(description);
+        self.add_annotation_description(render_description(&description));
         Some(())
     }
 
@@ -229,7 +262,6 @@ impl<'a> HoverBuilder<'a> {
                 _ => {}
             }
         }
-
         for marked_string in &self.annotation_description {
             match marked_string {
                 MarkedString::String(s) => {
@@ -261,6 +293,10 @@ impl<'a> HoverBuilder<'a> {
             }
         }
 
+        if let Some(MarkedString::String(footer)) = &self.footer {
+            result.push_str(&format!("\n---\n\n{}\n", footer));
+        }
+
         Some(Hover {
             contents: HoverContents::Markup(MarkupContent {
                 kind: lsp_types::MarkupKind::Markdown,
@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+
+use emmylua_code_analysis::{DbIndex, FileId, LuaPropertyOwnerId, LuaType, LuaTypeDeclId};
+
+use crate::handlers::command::make_goto_declaration_uri;
+
+/// `signature` as markdown with every named type used by `typ` linked to its declaration,
+/// a code fence cannot hold links
+pub fn link_signature_types(
+    db: &DbIndex,
+    signature: &str,
+    typ: &LuaType,
+    file_id: FileId,
+) -> Option<String> {
+    let mut type_ids = Vec::new();
+    collect_type_ids(db, typ, &mut type_ids, 0);
+    let type_names = type_ids
+        .iter()
+        .filter(|id| db.get_type_index().get_type_decl(id).is_some())
+        .map(|id| id.get_name())
+        .collect::<HashSet<_>>();
+    if type_names.is_empty() {
+        return None;
+    }
+
+    let mut lines = Vec::new();
+    for line in signature.lines() {
+        let mut rest = line.trim_start();
+        let mut text = "&nbsp;".repeat(line.len() - rest.len());
+        while let Some(ch) = rest.chars().next() {
+            let end = if is_type_name_char(ch) {
+                rest.find(|c| !is_type_name_char(c)).unwrap_or(rest.len())
+            } else {
+                ch.len_utf8()
+            };
+            let word = &rest[..end];
+            if type_names.contains(word) {
+                text.push_str(&format!(
+                    "[`{}`]({})",
+                    word,
+                    make_goto_declaration_uri(word, file_id)
+                ));
+            } else {
+                text.push_str(&escape_markdown(word));
+            }
+            rest = &rest[end..];
+        }
+        lines.push(text);
+    }
+
+    Some(lines.join("  \n"))
+}
+
+fn is_type_name_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '.'
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut result = String::new();
+    for ch in text.chars() {
+        if matches!(
+            ch,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+        ) {
+            result.push('\\');
+        }
+        result.push(ch);
+    }
+    result
+}
+
+fn collect_type_ids(db: &DbIndex, typ: &LuaType, result: &mut Vec<LuaTypeDeclId>, depth: usize) {
+    if depth > 8 {
+        return;
+    }
+
+    let mut push = |id: &LuaTypeDeclId| {
+        if !result.contains(id) {
+            result.push(id.clone());
+        }
+    };
+    match typ {
+        LuaType::Ref(id) | LuaType::Def(id) => push(id),
+        LuaType::Generic(generic) => {
+            push(&generic.get_base_type_id());
+            for param in generic.get_params() {
+                collect_type_ids(db, param, result, depth + 1);
+            }
+        }
+        LuaType::Array(base) | LuaType::Nullable(base) | LuaType::Variadic(base) => {
+            collect_type_ids(db, base, result, depth + 1)
+        }
+        LuaType::Union(union) => {
+            for typ in union.get_types() {
+                collect_type_ids(db, typ, result, depth + 1);
+            }
+        }
+        LuaType::Intersection(intersection) => {
+            for typ in intersection.get_types() {
+                collect_type_ids(db, typ, result, depth + 1);
+            }
+        }
+        LuaType::Tuple(tuple) => {
+            for typ in tuple.get_types() {
+                collect_type_ids(db, typ, result, depth + 1);
+            }
+        }
+        LuaType::TableGeneric(params) => {
+            for typ in params.iter() {
+                collect_type_ids(db, typ, result, depth + 1);
+            }
+        }
+        LuaType::MultiLineUnion(multi) => {
+            for (typ, _) in multi.get_unions() {
+                collect_type_ids(db, typ, result, depth + 1);
+            }
+        }
+        LuaType::DocFunction(func) => {
+            for (_, typ) in func.get_params() {
+                if let Some(typ) = typ {
+                    collect_type_ids(db, typ, result, depth + 1);
+                }
+            }
+            for typ in func.get_ret() {
+                collect_type_ids(db, typ, result, depth + 1);
+            }
+        }
+        LuaType::Signature(signature_id) => {
+            let Some(signature) = db.get_signature_index().get(signature_id) else {
+                return;
+            };
+            for idx in 0..signature.params.len() {
+                if let Some(param_info) = signature.get_param_info_by_id(idx) {
+                    collect_type_ids(db, &param_info.type_ref, result, depth + 1);
+                }
+            }
+            for ret in &signature.return_docs {
+                collect_type_ids(db, &ret.type_ref, result, depth + 1);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// one line per `---@see`, urls are linked directly and names open their declaration
+pub fn build_see_links(
+    db: &DbIndex,
+    property_owner: LuaPropertyOwnerId,
+    file_id: FileId,
+) -> Option<String> {
+    let property = db.get_property_index().get_property(property_owner)?;
+    let see = property.see.as_ref()?;
+    let mut lines = Vec::new();
+    for (target, description) in see.iter() {
+        let link = if target.starts_with("http://") || target.starts_with("https://") {
+            format!("[{}]({})", target, target)
+        } else {
+            format!(
+                "[`{}`]({})",
+                target,
+                make_goto_declaration_uri(target, file_id)
+            )
+        };
+        match description {
+            Some(description) => lines.push(format!("@*see* {} — {}", link, description)),
+            None => lines.push(format!("@*see* {}", link)),
+        }
+    }
+
+    Some(lines.join("\n\n"))
+}
+
+/// highlight bare code fences in descriptions as lua
+pub fn render_description(description: &str) -> String {
+    let mut lines = Vec::new();
+    let mut in_fence = false;
+    for line in description.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            if !in_fence && trimmed == "```" {
+                lines.push("```lua".to_string());
+            } else {
+                lines.push(line.to_string());
+            }
+            in_fence = !in_fence;
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// `Defined in std` or `Defined in library <name>` for declarations outside the main workspace
+pub fn build_source_footer(db: &DbIndex, property_owner: &LuaPropertyOwnerId) -> Option<String> {
+    let file_id = match property_owner {
+        LuaPropertyOwnerId::TypeDecl(id) => {
+            db.get_type_index()
+                .get_type_decl(id)?
+                .get_locations()
+                .first()?
+                .file_id
+        }
+        _ => property_owner.get_file_id()?,
+    };
+    let workspace_id = db.get_module_index().get_module(file_id)?.workspace_id;
+    if workspace_id.is_std() {
+        return Some("Defined in std".to_string());
+    }
+    if !workspace_id.is_library() {
+        return None;
+    }
+
+    let name = db
+        .get_module_index()
+        .get_workspace_root(workspace_id)
+        .and_then(|root| root.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| workspace_id.to_string());
+    Some(format!("Defined in library `{}`", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::{
+        LuaMemberKey, LuaMemberOwner, LuaPropertyOwnerId, LuaTypeDeclId, VirtualWorkspace,
+    };
+
+    use super::{build_see_links, link_signature_types, render_description};
+
+    #[test]
+    fn test_hover_links() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            ---@class Item
+
+            ---@class Player
+            local Player = {}
+
+            ---@see Player#heal
+            ---@see https://example.com/combat combat rules
+            ---@param item Item
+            ---@return Player?
+            function Player:use(item) end
+            "#,
+        );
+
+        let db = ws.analysis.compilation.get_db();
+        let member_map = db
+            .get_member_index()
+            .get_member_map(LuaMemberOwner::Type(LuaTypeDeclId::new("Player")))
+            .unwrap();
+        let member_id = *member_map.get(&LuaMemberKey::Name("use".into())).unwrap();
+        let member = db.get_member_index().get_member(&member_id).unwrap();
+
+        let signature = link_signature_types(
+            db,
+            "(method) Player:use(item: Item)\n  -> Player?",
+            member.get_decl_type(),
+            file_id,
+        )
+        .unwrap();
+        assert!(signature.starts_with("(method) [`Player`](command:emmy.goto.declaration?"));
+        assert!(signature.contains(":use(item: [`Item`](command:emmy.goto.declaration?"));
+        assert!(signature.contains("  \n&nbsp;&nbsp;-\\> [`Player`]("));
+        assert!(signature.ends_with(")?"));
+
+        let see_links =
+            build_see_links(db, LuaPropertyOwnerId::Member(member_id), file_id).unwrap();
+        assert!(see_links.contains("[`Player.heal`](command:emmy.goto.declaration?"));
+        assert!(see_links
+            .contains("[https://example.com/combat](https://example.com/combat) — combat rules"));
+    }
+
+    #[test]
+    fn test_render_description() {
+        assert_eq!(
+            render_description("Heal.\n```\nplayer:heal(1)\n```"),
+            "Heal.\n```lua\nplayer:heal(1)\n```"
+        );
+        assert_eq!(render_description("```js\nx\n```"), "```js\nx\n```");
+    }
+}
@@ -1,6 +1,7 @@
 mod build_hover;
 mod hover_builder;
 mod hover_humanize;
+mod hover_links;
 mod keyword_hover;
 mod std_hover;
 
@@ -78,3 +79,91 @@ pub fn register_capabilities(
     server_capabilities.hover_provider = Some(HoverProviderCapability::Simple(true));
     Some(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::{Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+    use lsp_types::HoverContents;
+
+    use super::build_semantic_info_hover;
+    use crate::handlers::test_lib::{def_with_cursor, get_token_at};
+
+    /// the markdown shown when hovering the name at `<|>` in `code`
+    fn get_hover_text(ws: &mut VirtualWorkspace, code: &str) -> String {
+        let (file_id, offset) = def_with_cursor(ws, code);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let token = get_token_at(&semantic_model, offset);
+        let semantic_info = semantic_model
+            .get_semantic_info(token.clone().into())
+            .unwrap();
+        let hover = build_semantic_info_hover(
+            &semantic_model,
+            semantic_model.get_db(),
+            &semantic_model.get_document(),
+            token,
+            semantic_info,
+        )
+        .unwrap();
+        match hover.contents {
+            HoverContents::Markup(markup) => markup.value,
+            _ => panic!("the hover is not markdown"),
+        }
+    }
+
+    #[test]
+    fn test_hover_doc_meta_tags() {
+        let mut ws = VirtualWorkspace::new();
+        let text = get_hover_text(
+            &mut ws,
+            r#"
+            ---@class IOError
+
+            ---@class File
+            local File = {}
+
+            --- Read the whole file.
+            ---@since 1.2.0
+            ---@author CppCXY
+            ---@throws IOError when the file is closed
+            ---@example
+            ---    print(File:read())
+            function File:read() end
+
+            File:re<|>ad()
+            "#,
+        );
+
+        assert!(text.contains("@*since* 1.2.0"));
+        assert!(text.contains("@*author* CppCXY"));
+        assert!(text.contains("@*throws* `IOError` — when the file is closed"));
+        assert!(text.contains("**Example**\n\n```lua\nprint(File:read())\n```"));
+    }
+
+    #[test]
+    fn test_hover_hides_unavailable_members() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua51;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        let text = get_hover_text(
+            &mut ws,
+            r#"
+            ---@class Lib
+            lib = {}
+
+            lib.old = 1
+
+            ---@version >5.3
+            lib.new = 2
+
+            ---@type Lib
+            local l<|>ib2
+            "#,
+        );
+
+        assert!(text.contains("old"), "{}", text);
+        assert!(!text.contains("new"), "{}", text);
+    }
+}
@@ -13,6 +13,7 @@ use crate::{
     },
     handlers::text_document::register_files_watch,
     logger::init_logger,
+    meta_text::load_user_locales,
 };
 pub use client_config::{get_client_config, ClientConfig};
 use codestyle::load_editorconfig;
@@ -40,6 +41,9 @@ pub async fn initialized_handler(
 
     // init logger
     init_logger(main_root, &cmd_args);
+    if let Some(locale_dir) = &cmd_args.locale_dir {
+        load_user_locales(locale_dir);
+    }
     info!("client_id: {:?}", client_id);
     let params_json = serde_json::to_string_pretty(&params).unwrap();
     info!("initialization_params: {}", params_json);
@@ -86,6 +90,7 @@ pub async fn init_analysis(
     let mut mut_analysis = analysis.write().await;
     // update config
     mut_analysis.update_config(emmyrc.clone());
+    mut_analysis.init_framework_libs(true);
 
     let emmyrc_json = serde_json::to_string_pretty(emmyrc.as_ref()).unwrap();
     info!("current config : {}", emmyrc_json);
@@ -123,7 +128,10 @@ pub async fn init_analysis(
     );
 
     // load files
-    let files = collect_files(&workspace_folders, &emmyrc);
+    let mut files = collect_files(&workspace_folders, &emmyrc);
+    let addon_files = mut_analysis.load_luals_addons(&files, collect_files);
+    files.extend(addon_files);
+
     let files: Vec<(PathBuf, Option<String>)> =
         files.into_iter().map(|file| file.into_tuple()).collect();
 
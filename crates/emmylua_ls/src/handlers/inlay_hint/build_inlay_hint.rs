@@ -1,25 +1,30 @@
 use std::collections::HashMap;
 
 use emmylua_code_analysis::{
-    FileId, InferGuard, LuaFunctionType, LuaMemberId, LuaMemberKey, LuaMemberOwner,
+    FileId, InferGuard, LuaDeclExtra, LuaFunctionType, LuaMemberId, LuaMemberKey, LuaMemberOwner,
     LuaPropertyOwnerId, LuaSignatureId, LuaType, RenderLevel, SemanticModel,
 };
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaExpr, LuaFuncStat, LuaIndexExpr,
-    LuaLocalName, LuaSyntaxId, LuaVarExpr,
+    LuaAssignStat, LuaAst, LuaAstNode, LuaAstToken, LuaCallExpr, LuaClosureExpr, LuaExpr,
+    LuaFuncStat, LuaIndexExpr, LuaLocalFuncStat, LuaLocalName, LuaNameExpr, LuaSyntaxId,
+    LuaSyntaxNode, LuaTokenKind, LuaVarExpr,
 };
-use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintLabelPart};
-use rowan::NodeOrToken;
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, InlayHintLabelPart, Location};
+use rowan::{NodeOrToken, TextSize};
 
 use emmylua_code_analysis::humanize_type;
 
+use super::InlayHintData;
+
 pub fn build_inlay_hints(semantic_model: &SemanticModel) -> Option<Vec<InlayHint>> {
     let mut result = Vec::new();
     let root = semantic_model.get_root();
     for node in root.clone().descendants::<LuaAst>() {
         match node {
             LuaAst::LuaClosureExpr(closure) => {
-                build_closure_hint(semantic_model, &mut result, closure);
+                build_closure_hint(semantic_model, &mut result, closure.clone());
+                build_closure_return_hint(semantic_model, &mut result, closure.clone());
+                build_end_hint(semantic_model, &mut result, closure.syntax().clone());
             }
             LuaAst::LuaCallExpr(call_expr) => {
                 build_call_expr_param_hint(semantic_model, &mut result, call_expr.clone());
@@ -31,6 +36,20 @@ pub fn build_inlay_hints(semantic_model: &SemanticModel) -> Option<Vec<InlayHint
             LuaAst::LuaFuncStat(func_stat) => {
                 build_func_stat_override_hint(semantic_model, &mut result, func_stat);
             }
+            LuaAst::LuaIndexExpr(index_expr) => {
+                build_chain_hint(semantic_model, &mut result, index_expr.clone());
+                build_enum_value_hint(semantic_model, &mut result, index_expr);
+            }
+            LuaAst::LuaNameExpr(name_expr) => {
+                build_const_value_hint(semantic_model, &mut result, name_expr);
+            }
+            LuaAst::LuaIfStat(_)
+            | LuaAst::LuaWhileStat(_)
+            | LuaAst::LuaForStat(_)
+            | LuaAst::LuaForRangeStat(_)
+            | LuaAst::LuaDoStat(_) => {
+                build_end_hint(semantic_model, &mut result, node.syntax().clone());
+            }
             _ => {}
         }
     }
@@ -66,21 +85,20 @@ fn build_closure_hint(
     }
 
     let document = semantic_model.get_document();
-    let db = semantic_model.get_db();
     for (signature_param_name, typ) in &signature_params {
         if let Some(typ) = typ {
             if let Some(lua_param) = lua_params_map.get(signature_param_name) {
                 let lsp_range = document.to_lsp_range(lua_param.get_range())?;
-                let typ_desc = format!(": {}", humanize_type(db, &typ, RenderLevel::Simple));
+                let data = InlayHintData::Syntax(file_id, lua_param.get_syntax_id());
                 let hint = InlayHint {
                     kind: Some(InlayHintKind::PARAMETER),
-                    label: InlayHintLabel::String(typ_desc),
+                    label: build_type_label(semantic_model, ": ", std::slice::from_ref(typ)),
                     position: lsp_range.end,
                     text_edits: None,
                     tooltip: None,
                     padding_left: Some(true),
                     padding_right: None,
-                    data: None,
+                    data: serde_json::to_value(data).ok(),
                 };
                 result.push(hint);
             }
@@ -260,26 +278,26 @@ fn build_local_name_hint(
         return Some(());
     }
 
+    let name_token = local_name.get_name_token()?;
     let typ = semantic_model
-        .get_semantic_info(NodeOrToken::Node(local_name.syntax().clone()))?
+        .get_semantic_info(NodeOrToken::Token(name_token.syntax().clone()))?
         .typ
         .clone();
 
     let document = semantic_model.get_document();
-    let db = semantic_model.get_db();
     let range = local_name.get_range();
     let lsp_range = document.to_lsp_range(range)?;
 
-    let typ_desc = humanize_type(db, &typ, RenderLevel::Simple);
+    let data = InlayHintData::Syntax(semantic_model.get_file_id(), local_name.get_syntax_id());
     let hint = InlayHint {
         kind: Some(InlayHintKind::TYPE),
-        label: InlayHintLabel::String(format!(": {}", typ_desc)),
+        label: build_type_label(semantic_model, ": ", &[typ]),
         position: lsp_range.end,
         text_edits: None,
         tooltip: None,
         padding_left: Some(true),
         padding_right: None,
-        data: None,
+        data: serde_json::to_value(data).ok(),
     };
     result.push(hint);
 
@@ -353,6 +371,305 @@ fn build_func_stat_override_hint(
     Some(())
 }
 
+fn build_closure_return_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    closure: LuaClosureExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.return_hint {
+        return Some(());
+    }
+
+    let signature_id = LuaSignatureId::from_closure(semantic_model.get_file_id(), &closure);
+    let signature = semantic_model
+        .get_db()
+        .get_signature_index()
+        .get(&signature_id)?;
+    if !signature.is_return_inferred() {
+        return Some(());
+    }
+
+    let document = semantic_model.get_document();
+    let params_end = closure.get_params_list()?.get_range().end();
+    let data = InlayHintData::Signature(signature_id);
+    let hint = InlayHint {
+        kind: Some(InlayHintKind::TYPE),
+        label: build_type_label(semantic_model, "-> ", &signature.get_return_types()),
+        position: document.to_lsp_position(params_end)?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: serde_json::to_value(data).ok(),
+    };
+    result.push(hint);
+
+    Some(())
+}
+
+// obj:a()   -- A
+//    :b()   -- B
+//    :c()
+fn build_chain_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    index_expr: LuaIndexExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.chain_hint {
+        return Some(());
+    }
+
+    let prefix_expr = index_expr.get_prefix_expr()?;
+    if !matches!(prefix_expr, LuaExpr::CallExpr(_)) {
+        return Some(());
+    }
+
+    let document = semantic_model.get_document();
+    let index_token = index_expr.get_index_token()?;
+    let prefix_end = prefix_expr.get_range().end();
+    if document.get_line(prefix_end)? == document.get_line(index_token.get_position())? {
+        return Some(());
+    }
+
+    let typ = semantic_model.infer_expr(prefix_expr.clone())?;
+    if typ.is_unknown() {
+        return Some(());
+    }
+
+    let data = InlayHintData::Syntax(semantic_model.get_file_id(), prefix_expr.get_syntax_id());
+    let hint = InlayHint {
+        kind: Some(InlayHintKind::TYPE),
+        label: build_type_label(semantic_model, "", &[typ]),
+        position: document.to_lsp_position(prefix_end)?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: serde_json::to_value(data).ok(),
+    };
+    result.push(hint);
+
+    Some(())
+}
+
+fn build_enum_value_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    index_expr: LuaIndexExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.value_hint || is_assign_target(index_expr.syntax()) {
+        return Some(());
+    }
+
+    let prefix_type = semantic_model.infer_expr(index_expr.get_prefix_expr()?)?;
+    let type_decl_id = match &prefix_type {
+        LuaType::Def(id) | LuaType::Ref(id) => id,
+        _ => return Some(()),
+    };
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(type_decl_id)?;
+    if !type_decl.is_enum() {
+        return Some(());
+    }
+
+    let typ = semantic_model.infer_expr(LuaExpr::IndexExpr(index_expr.clone()))?;
+    build_value_hint(semantic_model, result, index_expr.syntax(), &typ)
+}
+
+fn build_const_value_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    name_expr: LuaNameExpr,
+) -> Option<()> {
+    if !semantic_model.get_emmyrc().hint.value_hint || is_assign_target(name_expr.syntax()) {
+        return Some(());
+    }
+
+    let property_owner =
+        semantic_model.get_property_owner_id(NodeOrToken::Node(name_expr.syntax().clone()))?;
+    let LuaPropertyOwnerId::LuaDecl(decl_id) = property_owner else {
+        return Some(());
+    };
+    let decl = semantic_model
+        .get_db()
+        .get_decl_index()
+        .get_decl(&decl_id)?;
+    if !matches!(
+        decl.extra,
+        LuaDeclExtra::Local {
+            attrib: Some(_),
+            ..
+        }
+    ) {
+        return Some(());
+    }
+
+    let typ = semantic_model.infer_expr(LuaExpr::NameExpr(name_expr.clone()))?;
+    build_value_hint(semantic_model, result, name_expr.syntax(), &typ)
+}
+
+fn build_value_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    node: &LuaSyntaxNode,
+    typ: &LuaType,
+) -> Option<()> {
+    if !matches!(
+        typ,
+        LuaType::IntegerConst(_)
+            | LuaType::FloatConst(_)
+            | LuaType::StringConst(_)
+            | LuaType::BooleanConst(_)
+            | LuaType::DocIntegerConst(_)
+            | LuaType::DocStringConst(_)
+    ) {
+        return Some(());
+    }
+
+    let document = semantic_model.get_document();
+    let value = humanize_type(semantic_model.get_db(), typ, RenderLevel::Simple);
+    let hint = InlayHint {
+        kind: None,
+        label: InlayHintLabel::String(format!("= {}", value)),
+        position: document.to_lsp_position(node.text_range().end())?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+
+    Some(())
+}
+
+fn is_assign_target(node: &LuaSyntaxNode) -> bool {
+    let Some(assign_stat) = node.parent().and_then(LuaAssignStat::cast) else {
+        return false;
+    };
+
+    let (vars, _) = assign_stat.get_var_and_expr_list();
+    vars.iter().any(|var| var.syntax() == node)
+}
+
+fn build_end_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    node: LuaSyntaxNode,
+) -> Option<()> {
+    let emmyrc = semantic_model.get_emmyrc();
+    if !emmyrc.hint.end_hint {
+        return Some(());
+    }
+
+    let end_token = node
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| it.kind() == LuaTokenKind::TkEnd.into())?;
+
+    let (label, start) = match LuaAst::cast(node.clone())? {
+        LuaAst::LuaClosureExpr(closure) => get_function_end_label(closure)?,
+        LuaAst::LuaIfStat(_) => ("if".to_string(), node.text_range().start()),
+        LuaAst::LuaWhileStat(_) => ("while".to_string(), node.text_range().start()),
+        LuaAst::LuaForStat(_) | LuaAst::LuaForRangeStat(_) => {
+            ("for".to_string(), node.text_range().start())
+        }
+        LuaAst::LuaDoStat(_) => ("do".to_string(), node.text_range().start()),
+        _ => return None,
+    };
+
+    let document = semantic_model.get_document();
+    let end_position = end_token.text_range().end();
+    let lines = document.get_line(end_position)? - document.get_line(start)?;
+    if lines < emmyrc.hint.end_hint_min_lines {
+        return Some(());
+    }
+
+    let hint = InlayHint {
+        kind: None,
+        label: InlayHintLabel::String(format!("-- end of {}", label)),
+        position: document.to_lsp_position(end_position)?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+
+    Some(())
+}
+
+fn get_function_end_label(closure: LuaClosureExpr) -> Option<(String, TextSize)> {
+    let parent = closure.syntax().parent()?;
+    if let Some(func_stat) = LuaFuncStat::cast(parent.clone()) {
+        let name = func_stat.get_func_name()?.syntax().text().to_string();
+        return Some((format!("function {}", name), func_stat.get_position()));
+    }
+
+    if let Some(local_func_stat) = LuaLocalFuncStat::cast(parent) {
+        let name = local_func_stat.get_local_name()?.get_name_token()?;
+        return Some((
+            format!("function {}", name.get_name_text()),
+            local_func_stat.get_position(),
+        ));
+    }
+
+    Some(("function".to_string(), closure.get_position()))
+}
+
+// every type is its own label part, so clicking it jumps to its declaration
+fn build_type_label(
+    semantic_model: &SemanticModel,
+    prefix: &str,
+    types: &[LuaType],
+) -> InlayHintLabel {
+    let db = semantic_model.get_db();
+    let mut parts = Vec::new();
+    if !prefix.is_empty() {
+        parts.push(InlayHintLabelPart {
+            value: prefix.to_string(),
+            ..Default::default()
+        });
+    }
+
+    for (i, typ) in types.iter().enumerate() {
+        if i > 0 {
+            parts.push(InlayHintLabelPart {
+                value: ", ".to_string(),
+                ..Default::default()
+            });
+        }
+
+        parts.push(InlayHintLabelPart {
+            value: humanize_type(db, typ, RenderLevel::Simple),
+            location: get_type_location(semantic_model, typ),
+            ..Default::default()
+        });
+    }
+
+    InlayHintLabel::LabelParts(parts)
+}
+
+fn get_type_location(semantic_model: &SemanticModel, typ: &LuaType) -> Option<Location> {
+    let type_decl_id = match typ {
+        LuaType::Ref(id) | LuaType::Def(id) => id.clone(),
+        LuaType::Generic(generic) => generic.get_base_type_id(),
+        LuaType::Nullable(inner) => return get_type_location(semantic_model, inner),
+        _ => return None,
+    };
+
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(&type_decl_id)?;
+    let location = type_decl.get_locations().first()?;
+    let document = semantic_model.get_document_by_file_id(location.file_id)?;
+    document.to_lsp_location(location.range)
+}
+
 fn get_super_member_id(
     semantic_model: &SemanticModel,
     super_type: LuaType,
@@ -404,3 +721,162 @@ fn get_override_lsp_location(
     let lsp_range = document.to_lsp_location(range)?;
     Some(lsp_range)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::{Emmyrc, VirtualWorkspace};
+    use lsp_types::InlayHintLabel;
+
+    use super::build_inlay_hints;
+
+    /// labels of the hints for `code`, every hint kind is off unless `enable` turns it on
+    fn get_hint_labels(code: &str, enable: fn(&mut Emmyrc)) -> Vec<String> {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.hint.param_hint = false;
+        emmyrc.hint.index_hint = false;
+        emmyrc.hint.local_hint = false;
+        emmyrc.hint.override_hint = false;
+        enable(&mut emmyrc);
+        ws.analysis.update_config(Arc::new(emmyrc));
+
+        let file_id = ws.def(code);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        build_inlay_hints(&semantic_model)
+            .unwrap()
+            .into_iter()
+            .map(|hint| match hint.label {
+                InlayHintLabel::String(label) => label,
+                InlayHintLabel::LabelParts(parts) => {
+                    parts.into_iter().map(|part| part.value).collect()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_return_hint() {
+        let code = r#"
+        local function inferred()
+            return "a"
+        end
+
+        ---@return string
+        local function documented()
+            return "a"
+        end
+        "#;
+
+        let labels = get_hint_labels(code, |emmyrc| emmyrc.hint.return_hint = true);
+        assert_eq!(labels, vec!["-> \"a\""]);
+        assert!(get_hint_labels(code, |_| {}).is_empty());
+    }
+
+    #[test]
+    fn test_chain_hint() {
+        let code = r#"
+        ---@class Builder
+        local Builder = {}
+
+        ---@return Builder
+        function Builder:add()
+        end
+
+        local b = Builder:add()
+            :add()
+        local c = Builder:add():add()
+        "#;
+
+        let labels = get_hint_labels(code, |emmyrc| emmyrc.hint.chain_hint = true);
+        assert_eq!(labels, vec!["Builder"]);
+        assert!(get_hint_labels(code, |_| {}).is_empty());
+    }
+
+    #[test]
+    fn test_end_hint() {
+        let code = r#"
+        local function long()
+            if true then
+            end
+        end
+        "#;
+
+        let labels = get_hint_labels(code, |emmyrc| {
+            emmyrc.hint.end_hint = true;
+            emmyrc.hint.end_hint_min_lines = 2;
+        });
+        assert_eq!(labels, vec!["-- end of function long"]);
+
+        let labels = get_hint_labels(code, |emmyrc| {
+            emmyrc.hint.end_hint = true;
+            emmyrc.hint.end_hint_min_lines = 1;
+        });
+        assert_eq!(labels, vec!["-- end of function long", "-- end of if"]);
+        assert!(get_hint_labels(code, |_| {}).is_empty());
+    }
+
+    #[test]
+    fn test_value_hint() {
+        let code = r#"
+        ---@enum Color
+        local Color = {
+            Red = 1,
+        }
+
+        local max <const> = 10
+        local a = Color.Red
+        local b = max
+        "#;
+
+        let labels = get_hint_labels(code, |emmyrc| emmyrc.hint.value_hint = true);
+        assert_eq!(labels, vec!["= 1", "= 10"]);
+        assert!(get_hint_labels(code, |_| {}).is_empty());
+    }
+
+    #[test]
+    fn test_type_label_locations() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+        ---@class Point
+        local Point = {}
+
+        ---@return Point, integer
+        function Point.new()
+        end
+
+        local p, n = Point.new()
+        "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let document = semantic_model.get_document();
+        let hints = build_inlay_hints(&semantic_model).unwrap();
+
+        let labels = hints
+            .into_iter()
+            .filter(|hint| hint.position.line == 8)
+            .map(|hint| match hint.label {
+                InlayHintLabel::LabelParts(parts) => parts,
+                InlayHintLabel::String(label) => panic!("expected label parts, got {}", label),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(labels.len(), 2);
+
+        // `: ` is plain text, the class part points at `---@class Point`
+        let point_parts = &labels[0];
+        assert_eq!(point_parts.len(), 2);
+        assert_eq!(point_parts[0].value, ": ");
+        assert!(point_parts[0].location.is_none());
+        assert_eq!(point_parts[1].value, "Point");
+        let location = point_parts[1].location.as_ref().unwrap();
+        assert_eq!(location.uri, document.get_uri());
+        assert_eq!(location.range.start.line, 1);
+
+        // builtin types have nowhere to jump to
+        let integer_parts = &labels[1];
+        assert_eq!(integer_parts[1].value, "integer");
+        assert!(integer_parts[1].location.is_none());
+    }
+}
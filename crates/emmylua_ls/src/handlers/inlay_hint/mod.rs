@@ -1,10 +1,15 @@
 mod build_inlay_hint;
+mod resolve_inlay_hint;
 
 use build_inlay_hint::build_inlay_hints;
+use emmylua_code_analysis::{FileId, LuaSignatureId};
+use emmylua_parser::LuaSyntaxId;
 use lsp_types::{
     ClientCapabilities, InlayHint, InlayHintOptions, InlayHintParams, InlayHintServerCapabilities,
     OneOf, ServerCapabilities,
 };
+use resolve_inlay_hint::resolve_inlay_hint;
+use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
 
 use crate::context::ServerContextSnapshot;
@@ -21,13 +26,21 @@ pub async fn on_inlay_hint_handler(
     build_inlay_hints(&mut semantic_model)
 }
 
-#[allow(unused_variables)]
 pub async fn on_resolve_inlay_hint(
     context: ServerContextSnapshot,
     inlay_hint: InlayHint,
     _: CancellationToken,
 ) -> InlayHint {
-    inlay_hint
+    let analysis = context.analysis.read().await;
+    let compilation = &analysis.compilation;
+
+    resolve_inlay_hint(compilation, inlay_hint.clone()).unwrap_or(inlay_hint)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum InlayHintData {
+    Syntax(FileId, LuaSyntaxId),
+    Signature(LuaSignatureId),
 }
 
 pub fn register_capabilities(
@@ -36,7 +49,7 @@ pub fn register_capabilities(
 ) -> Option<()> {
     server_capabilities.inlay_hint_provider = Some(OneOf::Right(
         InlayHintServerCapabilities::Options(InlayHintOptions {
-            resolve_provider: Some(false),
+            resolve_provider: Some(true),
             work_done_progress_options: Default::default(),
         }),
     ));
@@ -0,0 +1,132 @@
+use emmylua_code_analysis::{humanize_type, LuaCompilation, RenderLevel};
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaLocalName, LuaParamName};
+use lsp_types::{InlayHint, InlayHintTooltip, MarkupContent, MarkupKind};
+use rowan::NodeOrToken;
+
+use super::InlayHintData;
+
+pub fn resolve_inlay_hint(
+    compilation: &LuaCompilation,
+    inlay_hint: InlayHint,
+) -> Option<InlayHint> {
+    let data = inlay_hint.data.as_ref()?;
+    let data = serde_json::from_value(data.clone()).ok()?;
+    let types = match data {
+        InlayHintData::Syntax(file_id, syntax_id) => {
+            let semantic_model = compilation.get_semantic_model(file_id)?;
+            let node = syntax_id.to_node_from_root(semantic_model.get_root().syntax())?;
+            // names are resolved through their token, like hover does
+            let node_or_token = if let Some(local_name) = LuaLocalName::cast(node.clone()) {
+                NodeOrToken::Token(local_name.get_name_token()?.syntax().clone())
+            } else if let Some(param_name) = LuaParamName::cast(node.clone()) {
+                NodeOrToken::Token(param_name.get_name_token()?.syntax().clone())
+            } else {
+                NodeOrToken::Node(node)
+            };
+            vec![semantic_model.get_semantic_info(node_or_token)?.typ]
+        }
+        InlayHintData::Signature(signature_id) => compilation
+            .get_db()
+            .get_signature_index()
+            .get(&signature_id)?
+            .get_return_types(),
+    };
+
+    let db = compilation.get_db();
+    let value = types
+        .iter()
+        .map(|typ| humanize_type(db, typ, RenderLevel::Detailed))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Some(InlayHint {
+        tooltip: Some(InlayHintTooltip::MarkupContent(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```lua\n{}\n```", value),
+        })),
+        data: None,
+        ..inlay_hint
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::{Emmyrc, VirtualWorkspace};
+    use lsp_types::{InlayHint, InlayHintLabel, InlayHintTooltip};
+
+    use super::super::build_inlay_hint::build_inlay_hints;
+    use super::resolve_inlay_hint;
+
+    fn get_tooltip(ws: &VirtualWorkspace, hint: InlayHint) -> String {
+        let resolved = resolve_inlay_hint(&ws.analysis.compilation, hint).unwrap();
+        assert!(resolved.data.is_none());
+        match resolved.tooltip {
+            Some(InlayHintTooltip::MarkupContent(content)) => content.value,
+            _ => panic!("expected a markdown tooltip"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_inlay_hint() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            ---@class Point
+            ---@field x number
+
+            ---@type Point
+            local p
+
+            local function origin()
+                return p
+            end
+            "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let hints = build_inlay_hints(&semantic_model).unwrap();
+        assert_eq!(hints.len(), 2);
+
+        // the local hint round-trips through `InlayHintData::Syntax`
+        let local_hint = hints[0].clone();
+        assert!(local_hint.data.is_some());
+        let tooltip = get_tooltip(&ws, local_hint);
+        assert!(tooltip.starts_with("```lua\n"));
+        assert!(tooltip.contains("Point"));
+        assert!(tooltip.contains("x: number"));
+
+        // the hint of `origin` round-trips through `InlayHintData::Signature`
+        let mut ws_return = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.hint.return_hint = true;
+        ws_return.analysis.update_config(Arc::new(emmyrc));
+        let file_id = ws_return.def(
+            r#"
+            local function name()
+                return "a", 1
+            end
+            "#,
+        );
+        let semantic_model = ws_return
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .unwrap();
+        let return_hint = build_inlay_hints(&semantic_model)
+            .unwrap()
+            .into_iter()
+            .find(|hint| match &hint.label {
+                InlayHintLabel::LabelParts(parts) => parts[0].value == "-> ",
+                _ => false,
+            })
+            .unwrap();
+        let tooltip = get_tooltip(&ws_return, return_hint);
+        assert_eq!(tooltip, "```lua\n\"a\", 1\n```");
+
+        // a hint without data is left unresolved
+        let mut plain = hints[1].clone();
+        plain.data = None;
+        assert!(resolve_inlay_hint(&ws.analysis.compilation, plain).is_none());
+    }
+}
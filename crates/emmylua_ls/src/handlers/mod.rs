@@ -23,6 +23,8 @@ mod response_handler;
 mod semantic_token;
 mod signature_helper;
 mod text_document;
+#[cfg(test)]
+mod test_lib;
 mod workspace_symbol;
 mod document_range_formatting;
 mod configuration;
@@ -1,14 +1,23 @@
+mod rename_module;
 mod rename_references;
 
-use emmylua_parser::{LuaAstNode, LuaTokenKind};
+use std::str::FromStr;
+
+use emmylua_code_analysis::uri_to_file_path;
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaStringToken, LuaTokenKind};
 use lsp_types::{
-    ClientCapabilities, OneOf, PrepareRenameResponse, RenameOptions, RenameParams,
-    ServerCapabilities, TextDocumentPositionParams, WorkspaceEdit,
+    ClientCapabilities, FileOperationFilter, FileOperationPattern, FileOperationPatternKind,
+    FileOperationRegistrationOptions, OneOf, PrepareRenameResponse, RenameFilesParams,
+    RenameOptions, RenameParams, ServerCapabilities, TextDocumentPositionParams, Uri,
+    WorkspaceEdit, WorkspaceFileOperationsServerCapabilities, WorkspaceServerCapabilities,
 };
+use rename_module::{rename_module_by_file_paths, rename_module_by_require_path};
 use rename_references::rename_references;
 use rowan::TokenAtOffset;
 use tokio_util::sync::CancellationToken;
 
+use super::document_link::is_require_path;
+
 use crate::context::ServerContextSnapshot;
 
 pub async fn on_rename_handler(
@@ -45,6 +54,18 @@ pub async fn on_rename_handler(
         }
     };
 
+    if let Some(string_token) = LuaStringToken::cast(token.clone()) {
+        let emmyrc = semantic_model.get_emmyrc();
+        if is_require_path(string_token.clone(), emmyrc).unwrap_or(false) {
+            return rename_module_by_require_path(
+                &analysis.compilation,
+                emmyrc,
+                string_token,
+                params.new_name,
+            );
+        }
+    }
+
     rename_references(
         &mut semantic_model,
         &analysis.compilation,
@@ -99,6 +120,26 @@ pub async fn on_prepare_rename_handler(
     }
 }
 
+pub async fn on_will_rename_files_handler(
+    context: ServerContextSnapshot,
+    params: RenameFilesParams,
+    _: CancellationToken,
+) -> Option<WorkspaceEdit> {
+    let renames = params
+        .files
+        .iter()
+        .filter_map(|file| {
+            let old_path = uri_to_file_path(&Uri::from_str(&file.old_uri).ok()?)?;
+            let new_path = uri_to_file_path(&Uri::from_str(&file.new_uri).ok()?)?;
+            Some((old_path, new_path))
+        })
+        .collect::<Vec<_>>();
+
+    let analysis = context.analysis.read().await;
+    let emmyrc = analysis.get_emmyrc();
+    rename_module_by_file_paths(&analysis.compilation, &emmyrc, &renames)
+}
+
 pub fn register_capabilities(
     server_capabilities: &mut ServerCapabilities,
     _: &ClientCapabilities,
@@ -107,5 +148,26 @@ pub fn register_capabilities(
         prepare_provider: Some(true),
         work_done_progress_options: Default::default(),
     }));
+
+    let file_filter = |glob: &str, matches: FileOperationPatternKind| FileOperationFilter {
+        scheme: Some("file".to_string()),
+        pattern: FileOperationPattern {
+            glob: glob.to_string(),
+            matches: Some(matches),
+            options: None,
+        },
+    };
+    let workspace = server_capabilities
+        .workspace
+        .get_or_insert_with(WorkspaceServerCapabilities::default);
+    workspace.file_operations = Some(WorkspaceFileOperationsServerCapabilities {
+        will_rename: Some(FileOperationRegistrationOptions {
+            filters: vec![
+                file_filter("**/*.lua", FileOperationPatternKind::File),
+                file_filter("**", FileOperationPatternKind::Folder),
+            ],
+        }),
+        ..Default::default()
+    });
     Some(())
 }
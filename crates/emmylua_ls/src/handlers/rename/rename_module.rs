@@ -0,0 +1,355 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use emmylua_code_analysis::{file_path_to_uri, Emmyrc, FileId, LuaCompilation};
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaStringToken};
+use lsp_types::{
+    DocumentChangeOperation, DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier,
+    RenameFile, ResourceOp, TextDocumentEdit, TextEdit, Uri, WorkspaceEdit,
+};
+
+use crate::handlers::document_link::is_require_path;
+
+/// rename a module through one of its `require` strings, the module file is moved as well
+pub fn rename_module_by_require_path(
+    compilation: &LuaCompilation,
+    emmyrc: &Emmyrc,
+    token: LuaStringToken,
+    new_name: String,
+) -> Option<WorkspaceEdit> {
+    let db = compilation.get_db();
+    let module_info = db.get_module_index().find_module(&token.get_value())?;
+    let new_module_path = new_name.replace(['\\', '/'], ".");
+    let old_path = db.get_vfs().get_file_path(&module_info.file_id)?;
+    let new_path = db
+        .get_module_index()
+        .get_renamed_file_path(old_path.to_str()?, &new_module_path)?;
+
+    let mut new_module_paths = HashMap::new();
+    new_module_paths.insert(module_info.file_id, new_module_path);
+    let require_edits = collect_require_edits(compilation, emmyrc, &new_module_paths);
+
+    let mut operations: Vec<DocumentChangeOperation> = require_edits
+        .into_iter()
+        .map(|(uri, edits)| {
+            DocumentChangeOperation::Edit(TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+                edits: edits.into_iter().map(OneOf::Left).collect(),
+            })
+        })
+        .collect();
+
+    // the edits address the old uri, so the file is moved after them
+    operations.push(DocumentChangeOperation::Op(ResourceOp::Rename(
+        RenameFile {
+            old_uri: file_path_to_uri(old_path)?,
+            new_uri: file_path_to_uri(&new_path)?,
+            options: None,
+            annotation_id: None,
+        },
+    )));
+
+    Some(WorkspaceEdit {
+        changes: None,
+        document_changes: Some(DocumentChanges::Operations(operations)),
+        change_annotations: None,
+    })
+}
+
+/// rewrite the `require` strings of modules below renamed files or directories
+pub fn rename_module_by_file_paths(
+    compilation: &LuaCompilation,
+    emmyrc: &Emmyrc,
+    renames: &[(PathBuf, PathBuf)],
+) -> Option<WorkspaceEdit> {
+    let db = compilation.get_db();
+    let module_index = db.get_module_index();
+    let vfs = db.get_vfs();
+
+    let mut new_module_paths = HashMap::new();
+    for module_info in module_index.get_module_infos() {
+        let Some(file_path) = vfs.get_file_path(&module_info.file_id) else {
+            continue;
+        };
+
+        let Some(new_file_path) = renames.iter().find_map(|(old_path, new_path)| {
+            let relative_path = file_path.strip_prefix(old_path).ok()?;
+            // the renamed file itself, joining an empty path would leave a trailing separator
+            if relative_path.as_os_str().is_empty() {
+                Some(new_path.clone())
+            } else {
+                Some(new_path.join(relative_path))
+            }
+        }) else {
+            continue;
+        };
+        let Some(new_file_path) = new_file_path.to_str() else {
+            continue;
+        };
+
+        if let Some(new_module_path) = module_index.get_module_path_by_file_path(new_file_path) {
+            if new_module_path != module_info.full_module_name {
+                new_module_paths.insert(module_info.file_id, new_module_path);
+            }
+        }
+    }
+
+    if new_module_paths.is_empty() {
+        return None;
+    }
+
+    let require_edits = collect_require_edits(compilation, emmyrc, &new_module_paths);
+    Some(WorkspaceEdit {
+        changes: Some(require_edits.into_iter().collect()),
+        document_changes: None,
+        change_annotations: None,
+    })
+}
+
+fn collect_require_edits(
+    compilation: &LuaCompilation,
+    emmyrc: &Emmyrc,
+    new_module_paths: &HashMap<FileId, String>,
+) -> Vec<(Uri, Vec<TextEdit>)> {
+    let db = compilation.get_db();
+    let module_index = db.get_module_index();
+    let mut result = Vec::new();
+    for file_id in db.get_vfs().get_all_file_ids() {
+        let is_main = module_index
+            .get_module(file_id)
+            .map(|module_info| module_info.workspace_id.is_main())
+            .unwrap_or(true);
+        if !is_main {
+            continue;
+        }
+
+        let Some(semantic_model) = compilation.get_semantic_model(file_id) else {
+            continue;
+        };
+        let document = semantic_model.get_document();
+        let string_tokens = semantic_model
+            .get_root()
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter_map(LuaStringToken::cast);
+
+        let mut edits = Vec::new();
+        for token in string_tokens {
+            if !is_require_path(token.clone(), emmyrc).unwrap_or(false) {
+                continue;
+            }
+
+            let module_path = token.get_value();
+            let Some(module_info) = module_index.find_module(&module_path) else {
+                continue;
+            };
+            let Some(new_module_path) = new_module_paths.get(&module_info.file_id) else {
+                continue;
+            };
+            let Some(new_text) = build_require_text(&token, &module_path, new_module_path) else {
+                continue;
+            };
+            let Some(range) = document.to_lsp_range(token.get_range()) else {
+                continue;
+            };
+
+            edits.push(TextEdit { range, new_text });
+        }
+
+        if !edits.is_empty() {
+            result.push((document.get_uri(), edits));
+        }
+    }
+
+    result
+}
+
+/// keep the quote and the separator style of the original string
+fn build_require_text(
+    token: &LuaStringToken,
+    old_module_path: &str,
+    new_module_path: &str,
+) -> Option<String> {
+    let quote = token.get_text().chars().next()?;
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+
+    let new_module_path = if old_module_path.contains('/') && !old_module_path.contains('.') {
+        new_module_path.replace('.', "/")
+    } else {
+        new_module_path.to_string()
+    };
+
+    Some(format!("{}{}{}", quote, new_module_path, quote))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::{Emmyrc, VirtualWorkspace};
+    use emmylua_parser::{LuaAstNode, LuaAstToken, LuaStringToken};
+    use lsp_types::{
+        DocumentChangeOperation, DocumentChanges, OneOf, ResourceOp, Uri, WorkspaceEdit,
+    };
+
+    use super::{rename_module_by_file_paths, rename_module_by_require_path};
+
+    fn create_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_files(vec![
+            ("a/b.lua", "return {}"),
+            (
+                "main.lua",
+                "local b = require(\"a.b\")\nlocal c = require('a/b')\n",
+            ),
+        ]);
+        ws
+    }
+
+    fn get_new_texts(edit: WorkspaceEdit) -> Vec<String> {
+        let mut texts: Vec<String> = edit
+            .changes
+            .unwrap_or_default()
+            .into_values()
+            .flatten()
+            .map(|edit| edit.new_text)
+            .collect();
+        texts.sort();
+        texts
+    }
+
+    #[test]
+    fn test_rename_file() {
+        let ws = create_workspace();
+        let gen = &ws.virtual_url_generator;
+        let renames = vec![(gen.new_path("a/b.lua"), gen.new_path("a/c.lua"))];
+        let edit =
+            rename_module_by_file_paths(&ws.analysis.compilation, &ws.analysis.emmyrc, &renames)
+                .unwrap();
+
+        assert_eq!(get_new_texts(edit), vec!["\"a.c\"", "'a/c'"]);
+    }
+
+    #[test]
+    fn test_rename_directory() {
+        let ws = create_workspace();
+        let gen = &ws.virtual_url_generator;
+        let renames = vec![(gen.new_path("a"), gen.new_path("x/y"))];
+        let edit =
+            rename_module_by_file_paths(&ws.analysis.compilation, &ws.analysis.emmyrc, &renames)
+                .unwrap();
+
+        assert_eq!(get_new_texts(edit), vec!["\"x.y.b\"", "'x/y/b'"]);
+    }
+
+    /// rename the first require string of "main.lua", returns the new require texts and
+    /// the uri the module file moves to
+    fn rename_by_require_path(ws: &VirtualWorkspace, new_name: &str) -> (Vec<String>, Uri) {
+        let compilation = &ws.analysis.compilation;
+        let main_id = compilation
+            .get_db()
+            .get_module_index()
+            .find_module("main")
+            .unwrap()
+            .file_id;
+        let semantic_model = compilation.get_semantic_model(main_id).unwrap();
+        let token = semantic_model
+            .get_root()
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find_map(LuaStringToken::cast)
+            .unwrap();
+
+        let edit = rename_module_by_require_path(
+            compilation,
+            &ws.analysis.emmyrc,
+            token,
+            new_name.to_string(),
+        )
+        .unwrap();
+        let Some(DocumentChanges::Operations(operations)) = edit.document_changes else {
+            panic!("expected document change operations");
+        };
+
+        let mut new_texts = Vec::new();
+        for operation in &operations {
+            if let DocumentChangeOperation::Edit(edit) = operation {
+                for edit in &edit.edits {
+                    if let OneOf::Left(edit) = edit {
+                        new_texts.push(edit.new_text.clone());
+                    }
+                }
+            }
+        }
+        new_texts.sort();
+
+        let Some(DocumentChangeOperation::Op(ResourceOp::Rename(rename))) = operations.last()
+        else {
+            panic!("expected the module file to be renamed last");
+        };
+        (new_texts, rename.new_uri.clone())
+    }
+
+    #[test]
+    fn test_rename_by_require_path() {
+        let ws = create_workspace();
+        let (new_texts, new_uri) = rename_by_require_path(&ws, "lib.d");
+        assert_eq!(new_texts, vec!["\"lib.d\"", "'lib/d'"]);
+        assert_eq!(new_uri, ws.virtual_url_generator.new_uri("lib/d.lua"));
+    }
+
+    #[test]
+    fn test_rename_single_letter_module() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_files(vec![
+            ("a.lua", "return {}"),
+            ("main.lua", "local a = require('a')"),
+        ]);
+
+        let (new_texts, new_uri) = rename_by_require_path(&ws, "b");
+        assert_eq!(new_texts, vec!["'b'"]);
+        assert_eq!(new_uri, ws.virtual_url_generator.new_uri("b.lua"));
+    }
+
+    #[test]
+    fn test_rename_init_module() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_files(vec![
+            ("foo/init.lua", "return {}"),
+            ("main.lua", "local foo = require('foo')"),
+        ]);
+
+        let (new_texts, new_uri) = rename_by_require_path(&ws, "bar.baz");
+        assert_eq!(new_texts, vec!["'bar.baz'"]);
+        assert_eq!(
+            new_uri,
+            ws.virtual_url_generator.new_uri("bar/baz/init.lua")
+        );
+    }
+
+    #[test]
+    fn test_rename_with_require_pattern() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.require_pattern = vec!["src/?.lua".to_string(), "?.lua".to_string()];
+        ws.analysis.update_config(Arc::new(emmyrc));
+        ws.def_files(vec![
+            ("src/a/b.lua", "return {}"),
+            ("main.lua", "local b = require('a.b')"),
+        ]);
+
+        let (new_texts, new_uri) = rename_by_require_path(&ws, "c");
+        assert_eq!(new_texts, vec!["'c'"]);
+        assert_eq!(new_uri, ws.virtual_url_generator.new_uri("src/c.lua"));
+
+        let gen = &ws.virtual_url_generator;
+        let renames = vec![(gen.new_path("src/a"), gen.new_path("src/x"))];
+        let edit =
+            rename_module_by_file_paths(&ws.analysis.compilation, &ws.analysis.emmyrc, &renames)
+                .unwrap();
+        assert_eq!(get_new_texts(edit), vec!["'x.b'"]);
+    }
+}
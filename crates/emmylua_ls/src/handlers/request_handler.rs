@@ -8,7 +8,9 @@ use lsp_types::request::{
     DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest, Formatting, GotoDefinition,
     HoverRequest, InlayHintRequest, InlayHintResolveRequest, InlineValueRequest,
     PrepareRenameRequest, RangeFormatting, References, Rename, ResolveCompletionItem,
-    SelectionRangeRequest, SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
+    SelectionRangeRequest, SemanticTokensFullDeltaRequest, SemanticTokensFullRequest,
+    SemanticTokensRangeRequest, SignatureHelpRequest, WillRenameFiles, WorkspaceSymbolRequest,
+    WorkspaceSymbolResolve,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use tokio_util::sync::CancellationToken;
@@ -34,10 +36,12 @@ use super::{
     inlay_hint::{on_inlay_hint_handler, on_resolve_inlay_hint},
     inline_values::on_inline_values_handler,
     references::on_references_handler,
-    rename::{on_prepare_rename_handler, on_rename_handler},
-    semantic_token::on_semantic_token_handler,
+    rename::{on_prepare_rename_handler, on_rename_handler, on_will_rename_files_handler},
+    semantic_token::{
+        on_semantic_token_delta_handler, on_semantic_token_handler, on_semantic_token_range_handler,
+    },
     signature_helper::on_signature_helper_handler,
-    workspace_symbol::on_workspace_symbol_handler,
+    workspace_symbol::{on_workspace_symbol_handler, on_workspace_symbol_resolve_handler},
 };
 
 pub async fn on_req_handler(
@@ -79,6 +83,8 @@ pub async fn on_req_handler(
         .await
         .on_parallel::<PrepareRenameRequest, _, _>(on_prepare_rename_handler)
         .await
+        .on_parallel::<WillRenameFiles, _, _>(on_will_rename_files_handler)
+        .await
         .on_parallel::<CodeLensRequest, _, _>(on_code_lens_handler)
         .await
         .on_parallel::<CodeLensResolve, _, _>(on_resolve_code_lens_handler)
@@ -89,6 +95,10 @@ pub async fn on_req_handler(
         .await
         .on_parallel::<SemanticTokensFullRequest, _, _>(on_semantic_token_handler)
         .await
+        .on_parallel::<SemanticTokensFullDeltaRequest, _, _>(on_semantic_token_delta_handler)
+        .await
+        .on_parallel::<SemanticTokensRangeRequest, _, _>(on_semantic_token_range_handler)
+        .await
         .on_parallel::<ExecuteCommand, _, _>(on_execute_command_handler)
         .await
         .on_parallel::<CodeActionRequest, _, _>(on_code_action_handler)
@@ -97,6 +107,8 @@ pub async fn on_req_handler(
         .await
         .on_parallel::<WorkspaceSymbolRequest, _, _>(on_workspace_symbol_handler)
         .await
+        .on_parallel::<WorkspaceSymbolResolve, _, _>(on_workspace_symbol_resolve_handler)
+        .await
         .on_parallel::<Formatting, _, _>(on_formatting_handler)
         .await
         .on_parallel::<RangeFormatting, _, _>(on_range_formatting_handler)
@@ -1,21 +1,23 @@
 use emmylua_code_analysis::{LuaDeclExtra, LuaPropertyOwnerId, LuaType, SemanticModel};
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaAstToken, LuaDocFieldKey, LuaDocObjectFieldKey, LuaExpr, LuaNameToken,
-    LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind, LuaVarExpr,
+    LuaAst, LuaAstNode, LuaAstToken, LuaDocFieldKey, LuaDocObjectFieldKey, LuaExpr, LuaIndexExpr,
+    LuaNameToken, LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind, LuaVarExpr,
 };
 use lsp_types::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
-use rowan::NodeOrToken;
+use rowan::{NodeOrToken, TextRange};
 
 use crate::context::ClientId;
 
 use super::{
-    semantic_token_builder::SemanticBuilder, SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES,
+    semantic_token_builder::{SemanticBuilder, MODIFIER_GLOBAL, MODIFIER_LOCAL},
+    SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES,
 };
 
 pub fn build_semantic_tokens(
     semantic_model: &mut SemanticModel,
     support_muliline_token: bool,
     client_id: ClientId,
+    range: Option<TextRange>,
 ) -> Option<Vec<SemanticToken>> {
     let root = semantic_model.get_root();
     let document = semantic_model.get_document();
@@ -24,9 +26,16 @@ pub fn build_semantic_tokens(
         support_muliline_token,
         SEMANTIC_TOKEN_TYPES.to_vec(),
         SEMANTIC_TOKEN_MODIFIERS.to_vec(),
+        range,
     );
 
     for node_or_token in root.syntax().descendants_with_tokens() {
+        if let Some(range) = range {
+            if range.intersect(node_or_token.text_range()).is_none() {
+                continue;
+            }
+        }
+
         match node_or_token {
             NodeOrToken::Node(node) => {
                 build_node_semantic_token(semantic_model, &mut builder, node, client_id);
@@ -132,7 +141,11 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkTagMapping
         | LuaTokenKind::TkTagNamespace
         | LuaTokenKind::TkTagUsing
-        | LuaTokenKind::TkTagSource => {
+        | LuaTokenKind::TkTagSource
+        | LuaTokenKind::TkTagExample
+        | LuaTokenKind::TkTagThrows
+        | LuaTokenKind::TkTagSince
+        | LuaTokenKind::TkTagAuthor => {
             builder.push_with_modifier(
                 token,
                 SemanticTokenType::KEYWORD,
@@ -147,7 +160,11 @@ fn build_tokens_semantic_token(
             builder.push(token, SemanticTokenType::KEYWORD);
         }
         LuaTokenKind::TkDocDetail => {
-            builder.push(token, SemanticTokenType::COMMENT);
+            builder.push_with_modifier(
+                token,
+                SemanticTokenType::COMMENT,
+                SemanticTokenModifier::DOCUMENTATION,
+            );
         }
         LuaTokenKind::TkDocQuestion => {
             builder.push(token, SemanticTokenType::OPERATOR);
@@ -301,6 +318,7 @@ fn build_node_semantic_token(
                 builder,
                 local_name.syntax().clone(),
                 local_name.get_name_token()?.syntax().clone(),
+                false,
             );
         }
         LuaAst::LuaNameExpr(name_expr) => {
@@ -309,6 +327,7 @@ fn build_node_semantic_token(
                 builder,
                 name_expr.syntax().clone(),
                 name_expr.get_name_token()?.syntax().clone(),
+                false,
             );
         }
         LuaAst::LuaForRangeStat(for_range_stat) => {
@@ -332,8 +351,7 @@ fn build_node_semantic_token(
                     builder.push(name.syntax().clone(), SemanticTokenType::FUNCTION);
                 }
                 LuaVarExpr::IndexExpr(index_expr) => {
-                    let name = index_expr.get_index_name_token()?;
-                    builder.push(name, SemanticTokenType::FUNCTION);
+                    handle_index_name(semantic_model, builder, index_expr, true);
                 }
             }
         }
@@ -343,35 +361,24 @@ fn build_node_semantic_token(
         }
         LuaAst::LuaCallExpr(call_expr) => {
             let prefix = call_expr.get_prefix_expr()?;
-            let prefix_type = semantic_model.infer_expr(prefix.clone());
 
             match prefix {
                 LuaExpr::NameExpr(name_expr) => {
                     let name = name_expr.get_name_token()?;
-                    if let Some(prefix_type) = prefix_type {
-                        match prefix_type {
-                            LuaType::Signature(signature) => {
-                                if semantic_model
-                                    .get_db()
-                                    .get_meta_file()
-                                    .is_meta_file(&signature.get_file_id())
-                                {
-                                    builder.push_with_modifier(
-                                        name.syntax().clone(),
-                                        SemanticTokenType::FUNCTION,
-                                        SemanticTokenModifier::DEFAULT_LIBRARY,
-                                    );
-                                    return Some(());
-                                }
-                            }
-                            _ => {}
-                        }
+                    if handle_name_node(
+                        semantic_model,
+                        builder,
+                        name_expr.syntax().clone(),
+                        name.syntax().clone(),
+                        true,
+                    )
+                    .is_none()
+                    {
+                        builder.push(name.syntax().clone(), SemanticTokenType::FUNCTION);
                     }
-                    builder.push(name.syntax().clone(), SemanticTokenType::FUNCTION);
                 }
                 LuaExpr::IndexExpr(index_expr) => {
-                    let name = index_expr.get_index_name_token()?;
-                    builder.push(name, SemanticTokenType::FUNCTION);
+                    handle_index_name(semantic_model, builder, index_expr, true);
                 }
                 _ => {}
             }
@@ -424,21 +431,8 @@ fn build_node_semantic_token(
             }
         }
         LuaAst::LuaIndexExpr(index_expr) => {
-            let name = index_expr.get_name_token()?;
-            let property_owner =
-                semantic_model.get_property_owner_id(name.syntax().clone().into())?;
-            if let LuaPropertyOwnerId::Member(member_id) = property_owner {
-                let member = semantic_model
-                    .get_db()
-                    .get_member_index()
-                    .get_member(&member_id)?;
-                let decl = member.get_decl_type();
-                if decl.is_function() {
-                    builder.push(name.syntax().clone(), SemanticTokenType::FUNCTION);
-                    return Some(());
-                }
-            }
-            builder.push(name.syntax().clone(), SemanticTokenType::PROPERTY);
+            index_expr.get_name_token()?;
+            handle_index_name(semantic_model, builder, index_expr, false);
         }
         LuaAst::LuaTableField(table_field) => {
             let value_type = semantic_model.infer_expr(table_field.get_value_expr()?.clone())?;
@@ -481,7 +475,13 @@ fn handle_name_node(
     builder: &mut SemanticBuilder,
     node: LuaSyntaxNode,
     name_token: LuaSyntaxToken,
+    is_call: bool,
 ) -> Option<()> {
+    let variable_type = if is_call {
+        SemanticTokenType::FUNCTION
+    } else {
+        SemanticTokenType::VARIABLE
+    };
     if is_class_def(semantic_model, node.clone()).is_some() {
         builder.push(name_token, SemanticTokenType::CLASS);
         return Some(());
@@ -489,14 +489,21 @@ fn handle_name_node(
 
     let owner_id = semantic_model.get_property_owner_id(node.into())?;
 
-    match owner_id {
+    match owner_id.clone() {
         LuaPropertyOwnerId::Member(member_id) => {
             let member = semantic_model
                 .get_db()
                 .get_member_index()
                 .get_member(&member_id)?;
             if matches!(member.get_decl_type(), LuaType::Signature(_)) {
-                builder.push(name_token, SemanticTokenType::FUNCTION);
+                let mut modifiers = Vec::new();
+                collect_property_modifiers(
+                    semantic_model,
+                    owner_id,
+                    Some(member.get_decl_type()),
+                    &mut modifiers,
+                );
+                builder.push_with_modifiers(name_token, SemanticTokenType::FUNCTION, modifiers);
                 return Some(());
             }
         }
@@ -507,43 +514,147 @@ fn handle_name_node(
                 .get_decl_index()
                 .get_decl(&decl_id)?;
 
-            let (token_type, modifier) = match &decl.extra {
-                LuaDeclExtra::Local { decl_type, .. } => match decl_type {
-                    Some(LuaType::Signature(_) | LuaType::DocFunction(_)) => {
-                        builder.push(name_token, SemanticTokenType::FUNCTION);
-                        return Some(());
+            let mut modifiers = Vec::new();
+            let token_type = match &decl.extra {
+                LuaDeclExtra::Local {
+                    decl_type, attrib, ..
+                } => {
+                    modifiers.push(MODIFIER_LOCAL);
+                    // `<const>` and `<close>` locals can not be assigned again
+                    if attrib.is_some() {
+                        modifiers.push(SemanticTokenModifier::READONLY);
+                    }
+                    match decl_type {
+                        Some(LuaType::Signature(_) | LuaType::DocFunction(_)) => {
+                            SemanticTokenType::FUNCTION
+                        }
+                        _ => variable_type,
                     }
-                    _ => (SemanticTokenType::VARIABLE, None),
-                },
+                }
 
-                LuaDeclExtra::Global { decl_type, .. } => match decl_type {
-                    Some(LuaType::Signature(signature)) => {
-                        let is_meta = semantic_model
-                            .get_db()
-                            .get_meta_file()
-                            .is_meta_file(&signature.get_file_id());
-                        (
-                            SemanticTokenType::FUNCTION,
-                            is_meta.then_some(SemanticTokenModifier::DEFAULT_LIBRARY),
-                        )
+                LuaDeclExtra::Global { decl_type, .. } => {
+                    modifiers.push(MODIFIER_GLOBAL);
+                    match decl_type {
+                        Some(LuaType::Signature(signature)) => {
+                            if semantic_model
+                                .get_db()
+                                .get_meta_file()
+                                .is_meta_file(&signature.get_file_id())
+                            {
+                                modifiers.push(SemanticTokenModifier::DEFAULT_LIBRARY);
+                            }
+                            SemanticTokenType::FUNCTION
+                        }
+                        _ => variable_type,
                     }
-                    _ => (SemanticTokenType::VARIABLE, None),
-                },
+                }
 
-                _ => (SemanticTokenType::VARIABLE, None),
+                _ => variable_type,
             };
 
-            if let Some(modifier) = modifier {
-                builder.push_with_modifier(name_token, token_type, modifier);
-            } else {
-                builder.push(name_token, token_type);
-            }
+            collect_property_modifiers(semantic_model, owner_id, decl.get_type(), &mut modifiers);
+            builder.push_with_modifiers(name_token, token_type, modifiers);
             return Some(());
         }
 
         _ => {}
     }
 
-    builder.push(name_token, SemanticTokenType::VARIABLE);
+    builder.push(name_token, variable_type);
+    Some(())
+}
+
+// `obj.field`, `obj.func()` and `obj:method()`
+fn handle_index_name(
+    semantic_model: &SemanticModel,
+    builder: &mut SemanticBuilder,
+    index_expr: LuaIndexExpr,
+    is_function: bool,
+) -> Option<()> {
+    let name = index_expr.get_index_name_token()?;
+    let mut token_type = if is_function {
+        SemanticTokenType::FUNCTION
+    } else {
+        SemanticTokenType::PROPERTY
+    };
+    let mut modifiers = Vec::new();
+
+    if let Some(owner_id) = semantic_model.get_property_owner_id(index_expr.syntax().clone().into())
+    {
+        let member_type = match &owner_id {
+            LuaPropertyOwnerId::Member(member_id) => semantic_model
+                .get_db()
+                .get_member_index()
+                .get_member(member_id)
+                .map(|member| member.get_decl_type().clone()),
+            _ => None,
+        };
+
+        if let Some(member_type) = &member_type {
+            if member_type.is_function() {
+                token_type = SemanticTokenType::FUNCTION;
+                match is_colon_define(semantic_model, member_type) {
+                    Some(true) => token_type = SemanticTokenType::METHOD,
+                    Some(false) => modifiers.push(SemanticTokenModifier::STATIC),
+                    None => {}
+                }
+            }
+        }
+
+        collect_property_modifiers(
+            semantic_model,
+            owner_id,
+            member_type.as_ref(),
+            &mut modifiers,
+        );
+    }
+
+    if is_function
+        && index_expr
+            .get_index_token()
+            .is_some_and(|index_token| index_token.is_colon())
+    {
+        token_type = SemanticTokenType::METHOD;
+        modifiers.retain(|modifier| *modifier != SemanticTokenModifier::STATIC);
+    }
+
+    builder.push_with_modifiers(name, token_type, modifiers);
     Some(())
 }
+
+fn is_colon_define(semantic_model: &SemanticModel, typ: &LuaType) -> Option<bool> {
+    match typ {
+        LuaType::Signature(signature_id) => Some(
+            semantic_model
+                .get_db()
+                .get_signature_index()
+                .get(signature_id)?
+                .is_colon_define,
+        ),
+        LuaType::DocFunction(func) => Some(func.is_colon_define()),
+        _ => None,
+    }
+}
+
+// `---@deprecated` of functions is recorded on their signature
+fn collect_property_modifiers(
+    semantic_model: &SemanticModel,
+    owner_id: LuaPropertyOwnerId,
+    typ: Option<&LuaType>,
+    modifiers: &mut Vec<SemanticTokenModifier>,
+) {
+    let property_index = semantic_model.get_db().get_property_index();
+    let mut properties = vec![property_index.get_property(owner_id)];
+    if let Some(LuaType::Signature(signature_id)) = typ {
+        properties.push(property_index.get_property(LuaPropertyOwnerId::Signature(*signature_id)));
+    }
+
+    for property in properties.into_iter().flatten() {
+        if property.is_deprecated && !modifiers.contains(&SemanticTokenModifier::DEPRECATED) {
+            modifiers.push(SemanticTokenModifier::DEPRECATED);
+        }
+        if property.is_readonly && !modifiers.contains(&SemanticTokenModifier::READONLY) {
+            modifiers.push(SemanticTokenModifier::READONLY);
+        }
+    }
+}
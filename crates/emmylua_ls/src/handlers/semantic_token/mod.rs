@@ -1,18 +1,27 @@
 mod build_semantic_tokens;
 mod semantic_token_builder;
+mod semantic_token_cache;
+
+use std::sync::{LazyLock, Mutex};
 
 use crate::context::ServerContextSnapshot;
 use build_semantic_tokens::build_semantic_tokens;
 use lsp_types::{
-    ClientCapabilities, SemanticTokens, SemanticTokensFullOptions, SemanticTokensLegend,
-    SemanticTokensOptions, SemanticTokensParams, SemanticTokensResult,
-    SemanticTokensServerCapabilities, ServerCapabilities,
+    ClientCapabilities, SemanticToken, SemanticTokens, SemanticTokensDelta,
+    SemanticTokensDeltaParams, SemanticTokensFullDeltaResult, SemanticTokensFullOptions,
+    SemanticTokensLegend, SemanticTokensOptions, SemanticTokensParams, SemanticTokensRangeParams,
+    SemanticTokensRangeResult, SemanticTokensResult, SemanticTokensServerCapabilities,
+    ServerCapabilities, Uri,
 };
 pub use semantic_token_builder::{SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES};
+use semantic_token_cache::{diff_semantic_tokens, SemanticTokenCache};
 use tokio_util::sync::CancellationToken;
 
 static mut SEMANTIC_MULTILINE_SUPPORT: bool = false;
 
+static SEMANTIC_TOKEN_CACHE: LazyLock<Mutex<SemanticTokenCache>> =
+    LazyLock::new(|| Mutex::new(SemanticTokenCache::default()));
+
 pub async fn on_semantic_token_handler(
     context: ServerContextSnapshot,
     params: SemanticTokensParams,
@@ -34,9 +43,116 @@ pub async fn on_semantic_token_handler(
         &mut semantic_model,
         unsafe { SEMANTIC_MULTILINE_SUPPORT },
         client_id,
+        None,
+    )?;
+
+    let mut cache = SEMANTIC_TOKEN_CACHE.lock().ok()?;
+    Some(SemanticTokensResult::Tokens(build_full_result(
+        &mut cache, uri, result,
+    )))
+}
+
+pub async fn on_semantic_token_delta_handler(
+    context: ServerContextSnapshot,
+    params: SemanticTokensDeltaParams,
+    _: CancellationToken,
+) -> Option<SemanticTokensFullDeltaResult> {
+    let uri = params.text_document.uri;
+    let analysis = context.analysis.read().await;
+    let config_manager = context.config_manager.read().await;
+    let client_id = config_manager.client_config.client_id;
+    let _ = config_manager;
+    let file_id = analysis.get_file_id(&uri)?;
+    let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+
+    if !semantic_model.get_emmyrc().semantic_tokens.enable {
+        return None;
+    }
+
+    let result = build_semantic_tokens(
+        &mut semantic_model,
+        unsafe { SEMANTIC_MULTILINE_SUPPORT },
+        client_id,
+        None,
     )?;
 
-    Some(SemanticTokensResult::Tokens(SemanticTokens {
+    let mut cache = SEMANTIC_TOKEN_CACHE.lock().ok()?;
+    Some(build_delta_result(
+        &mut cache,
+        uri,
+        &params.previous_result_id,
+        result,
+    ))
+}
+
+/// drop the cached tokens of a closed document
+pub fn remove_semantic_token_cache(uri: &Uri) {
+    if let Ok(mut cache) = SEMANTIC_TOKEN_CACHE.lock() {
+        cache.remove(uri);
+    }
+}
+
+fn build_full_result(
+    cache: &mut SemanticTokenCache,
+    uri: Uri,
+    tokens: Vec<SemanticToken>,
+) -> SemanticTokens {
+    let result_id = cache.insert(uri, tokens.clone());
+    SemanticTokens {
+        result_id: Some(result_id),
+        data: tokens,
+    }
+}
+
+fn build_delta_result(
+    cache: &mut SemanticTokenCache,
+    uri: Uri,
+    previous_result_id: &str,
+    tokens: Vec<SemanticToken>,
+) -> SemanticTokensFullDeltaResult {
+    let edits = cache
+        .get(&uri, previous_result_id)
+        .map(|previous| diff_semantic_tokens(previous, &tokens));
+    let result_id = cache.insert(uri, tokens.clone());
+    match edits {
+        Some(edits) => SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+            result_id: Some(result_id),
+            edits,
+        }),
+        // the client holds tokens we no longer know, send all of them
+        None => SemanticTokensFullDeltaResult::Tokens(SemanticTokens {
+            result_id: Some(result_id),
+            data: tokens,
+        }),
+    }
+}
+
+pub async fn on_semantic_token_range_handler(
+    context: ServerContextSnapshot,
+    params: SemanticTokensRangeParams,
+    _: CancellationToken,
+) -> Option<SemanticTokensRangeResult> {
+    let uri = params.text_document.uri;
+    let analysis = context.analysis.read().await;
+    let config_manager = context.config_manager.read().await;
+    let client_id = config_manager.client_config.client_id;
+    let _ = config_manager;
+    let file_id = analysis.get_file_id(&uri)?;
+    let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+
+    if !semantic_model.get_emmyrc().semantic_tokens.enable {
+        return None;
+    }
+
+    let range = semantic_model.get_document().to_rowan_range(params.range)?;
+    let result = build_semantic_tokens(
+        &mut semantic_model,
+        unsafe { SEMANTIC_MULTILINE_SUPPORT },
+        client_id,
+        Some(range),
+    )?;
+
+    Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
         result_id: None,
         data: result,
     }))
@@ -52,7 +168,8 @@ pub fn register_capabilities(
                 token_modifiers: SEMANTIC_TOKEN_MODIFIERS.iter().cloned().collect(),
                 token_types: SEMANTIC_TOKEN_TYPES.iter().cloned().collect(),
             },
-            full: Some(SemanticTokensFullOptions::Bool(true)),
+            full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+            range: Some(true),
             ..Default::default()
         }),
     );
@@ -75,3 +192,61 @@ fn is_support_muliline_tokens(client_capability: &ClientCapabilities) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use lsp_types::SemanticTokensFullDeltaResult;
+
+    use super::{build_delta_result, build_full_result, build_semantic_tokens, SemanticTokenCache};
+    use crate::context::ClientId;
+
+    #[test]
+    fn test_full_then_delta() {
+        let mut ws = VirtualWorkspace::new();
+        let uri = ws.virtual_url_generator.new_uri("tokens.lua");
+        let mut cache = SemanticTokenCache::default();
+
+        let code = "local a = 1\nlocal function f() end\n";
+        let file_id = ws.def_file("tokens.lua", code);
+        let mut semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let tokens =
+            build_semantic_tokens(&mut semantic_model, false, ClientId::VSCode, None).unwrap();
+        let full = build_full_result(&mut cache, uri.clone(), tokens.clone());
+        let full_id = full.result_id.unwrap();
+        assert_eq!(full.data, tokens);
+
+        let code = "local a = 1\nlocal b = 2\nlocal function f() end\n";
+        let file_id = ws.def_file("tokens.lua", code);
+        let mut semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let new_tokens =
+            build_semantic_tokens(&mut semantic_model, false, ClientId::VSCode, None).unwrap();
+        let SemanticTokensFullDeltaResult::TokensDelta(delta) =
+            build_delta_result(&mut cache, uri.clone(), &full_id, new_tokens.clone())
+        else {
+            panic!("expected a delta for a known result id");
+        };
+        assert_ne!(delta.result_id.as_deref(), Some(full_id.as_str()));
+        assert_eq!(delta.edits.len(), 1);
+
+        // applying the edit to the old tokens gives the new tokens
+        let edit = &delta.edits[0];
+        let mut applied = tokens;
+        let start = edit.start as usize / 5;
+        let end = start + edit.delete_count as usize / 5;
+        applied.splice(start..end, edit.data.clone().unwrap_or_default());
+        assert_eq!(applied, new_tokens);
+
+        // a stale result id falls back to the full tokens
+        let SemanticTokensFullDeltaResult::Tokens(full) =
+            build_delta_result(&mut cache, uri.clone(), &full_id, new_tokens.clone())
+        else {
+            panic!("expected the full tokens for a stale result id");
+        };
+
+        // a closed document is forgotten
+        cache.remove(&uri);
+        let result = build_delta_result(&mut cache, uri, &full.result_id.unwrap(), new_tokens);
+        assert!(matches!(result, SemanticTokensFullDeltaResult::Tokens(_)));
+    }
+}
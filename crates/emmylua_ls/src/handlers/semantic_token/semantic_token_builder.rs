@@ -1,7 +1,7 @@
 use emmylua_code_analysis::LuaDocument;
 use emmylua_parser::LuaSyntaxToken;
 use lsp_types::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
-use rowan::TextSize;
+use rowan::{TextRange, TextSize};
 use std::{collections::HashMap, vec::Vec};
 
 pub const SEMANTIC_TOKEN_TYPES: &[SemanticTokenType] = &[
@@ -41,8 +41,13 @@ pub const SEMANTIC_TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
     SemanticTokenModifier::MODIFICATION,
     SemanticTokenModifier::DOCUMENTATION,
     SemanticTokenModifier::DEFAULT_LIBRARY,
+    MODIFIER_GLOBAL,
+    MODIFIER_LOCAL,
 ];
 
+pub const MODIFIER_GLOBAL: SemanticTokenModifier = SemanticTokenModifier::new("global");
+pub const MODIFIER_LOCAL: SemanticTokenModifier = SemanticTokenModifier::new("local");
+
 #[derive(Debug)]
 struct BasicSemanticTokenData {
     line: u32,
@@ -66,6 +71,7 @@ pub struct SemanticBuilder<'a> {
     type_to_id: HashMap<SemanticTokenType, u32>,
     modifier_to_id: HashMap<SemanticTokenModifier, u32>,
     data: HashMap<TextSize, SemanticTokenData>,
+    range: Option<TextRange>,
 }
 
 #[allow(unused)]
@@ -75,6 +81,7 @@ impl<'a> SemanticBuilder<'a> {
         multi_line_support: bool,
         types: Vec<SemanticTokenType>,
         modifier: Vec<SemanticTokenModifier>,
+        range: Option<TextRange>,
     ) -> Self {
         let mut type_to_id = HashMap::new();
         for (i, ty) in types.into_iter().enumerate() {
@@ -91,12 +98,20 @@ impl<'a> SemanticBuilder<'a> {
             type_to_id,
             modifier_to_id,
             data: HashMap::new(),
+            range,
+        }
+    }
+
+    fn is_in_range(&self, range: TextRange) -> bool {
+        match self.range {
+            Some(request_range) => request_range.intersect(range).is_some(),
+            None => true,
         }
     }
 
     fn push_data(&mut self, token: LuaSyntaxToken, typ: u32, modifiers: u32) -> Option<()> {
         let position = token.text_range().start();
-        if self.data.contains_key(&position) {
+        if self.data.contains_key(&position) || !self.is_in_range(token.text_range()) {
             return Some(());
         }
 
@@ -177,6 +192,10 @@ impl<'a> SemanticBuilder<'a> {
         ty: SemanticTokenType,
         modifiers: SemanticTokenModifier,
     ) -> Option<()> {
+        if !self.is_in_range(TextRange::at(position, length.into())) {
+            return Some(());
+        }
+
         let lsp_position = self.document.to_lsp_position(position)?;
         let start_line = lsp_position.line;
         let start_col = lsp_position.character;
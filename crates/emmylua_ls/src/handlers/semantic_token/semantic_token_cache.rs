@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use lsp_types::{SemanticToken, SemanticTokensEdit, Uri};
+
+/// keeps the last tokens sent for each document, so `full/delta` only sends the changed part
+#[derive(Debug, Default)]
+pub struct SemanticTokenCache {
+    id_counter: u64,
+    results: HashMap<Uri, (String, Vec<SemanticToken>)>,
+}
+
+impl SemanticTokenCache {
+    /// remember the tokens of a document and return their result id
+    pub fn insert(&mut self, uri: Uri, tokens: Vec<SemanticToken>) -> String {
+        self.id_counter += 1;
+        let result_id = self.id_counter.to_string();
+        self.results.insert(uri, (result_id.clone(), tokens));
+        result_id
+    }
+
+    /// forget a closed document
+    pub fn remove(&mut self, uri: &Uri) {
+        self.results.remove(uri);
+    }
+
+    pub fn get(&self, uri: &Uri, result_id: &str) -> Option<&Vec<SemanticToken>> {
+        let (cached_id, tokens) = self.results.get(uri)?;
+        if cached_id == result_id {
+            Some(tokens)
+        } else {
+            None
+        }
+    }
+}
+
+/// a single edit replacing the part between the common prefix and suffix,
+/// `start` and `delete_count` count integers, every token is 5 of them
+pub fn diff_semantic_tokens(
+    old: &[SemanticToken],
+    new: &[SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let suffix = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let delete_count = old.len() - prefix - suffix;
+    let data = &new[prefix..new.len() - suffix];
+    if delete_count == 0 && data.is_empty() {
+        return Vec::new();
+    }
+
+    vec![SemanticTokensEdit {
+        start: (prefix * 5) as u32,
+        delete_count: (delete_count * 5) as u32,
+        data: Some(data.to_vec()),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::SemanticToken;
+
+    use super::diff_semantic_tokens;
+
+    fn token(delta_line: u32, length: u32) -> SemanticToken {
+        SemanticToken {
+            delta_line,
+            delta_start: 0,
+            length,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    #[test]
+    fn test_diff_semantic_tokens() {
+        let old = vec![token(0, 1), token(1, 2), token(1, 3)];
+        let new = vec![token(0, 1), token(1, 5), token(2, 5), token(1, 3)];
+        let edits = diff_semantic_tokens(&old, &new);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].start, 5);
+        assert_eq!(edits[0].delete_count, 5);
+        assert_eq!(edits[0].data, Some(vec![token(1, 5), token(2, 5)]));
+
+        assert!(diff_semantic_tokens(&old, &old).is_empty());
+    }
+}
@@ -1,8 +1,12 @@
+use std::sync::Arc;
+
 use emmylua_code_analysis::{
-    LuaFunctionType, LuaOperatorMetaMethod, LuaPropertyOwnerId, LuaSignatureId, LuaType,
-    LuaTypeDeclId, RenderLevel, SemanticModel,
+    LuaFunctionType, LuaMemberKey, LuaOperatorMetaMethod, LuaPropertyOwnerId, LuaSignatureId,
+    LuaType, LuaTypeDeclId, RenderLevel, SemanticModel,
+};
+use emmylua_parser::{
+    LuaAstNode, LuaCallExpr, LuaIndexKey, LuaSyntaxToken, LuaTableExpr, LuaTableField, LuaTokenKind,
 };
-use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaSyntaxToken, LuaTokenKind};
 use lsp_types::{
     Documentation, MarkupContent, ParameterInformation, ParameterLabel, SignatureHelp,
     SignatureInformation,
@@ -20,21 +24,77 @@ pub fn build_signature_helper(
     let prefix_expr_type = semantic_model.infer_expr(prefix_expr.clone())?;
     let colon_call = call_expr.is_colon_call();
     let current_idx = get_current_param_index(&call_expr, &token)?;
-    match prefix_expr_type {
-        LuaType::DocFunction(func_type) => {
-            build_doc_function_signature_help(semantic_model, &func_type, colon_call, current_idx)
-        }
-        LuaType::Signature(signature_id) => {
-            build_sig_id_signature_help(semantic_model, signature_id, colon_call, current_idx)
-        }
-        LuaType::Ref(type_decl_id) => {
-            build_type_signature_help(semantic_model, &type_decl_id, colon_call, current_idx)
+    let table_field = get_table_call_field(&call_expr, &token);
+
+    let mut signature_id = None;
+    let mut is_generic = false;
+    let funcs = match prefix_expr_type {
+        LuaType::DocFunction(func_type) => vec![func_type],
+        LuaType::Signature(id) => {
+            let signature = semantic_model.get_db().get_signature_index().get(&id)?;
+            signature_id = Some(id);
+            is_generic = signature.is_generic();
+            // the main signature goes first, then every `---@overload`
+            let mut funcs = vec![Arc::new(LuaFunctionType::new(
+                false,
+                signature.is_colon_define,
+                signature.get_type_params(),
+                vec![],
+            ))];
+            funcs.extend(signature.overloads.iter().cloned());
+            funcs
         }
-        LuaType::Def(type_decl_id) => {
-            build_type_signature_help(semantic_model, &type_decl_id, colon_call, current_idx)
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => {
+            get_call_operator_funcs(semantic_model, &type_decl_id)
         }
-        _ => None,
+        LuaType::Union(union) => union
+            .get_types()
+            .iter()
+            .filter_map(|typ| match typ {
+                LuaType::DocFunction(func_type) => Some(func_type.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => return None,
+    };
+
+    if funcs.is_empty() {
+        return None;
     }
+
+    let active_signature = if funcs.len() > 1 {
+        get_active_signature(
+            semantic_model,
+            &call_expr,
+            &funcs,
+            is_generic,
+            current_idx + 1,
+        )
+    } else {
+        0
+    };
+
+    let signatures = funcs
+        .iter()
+        .enumerate()
+        .map(|(idx, func)| {
+            build_signature_information(
+                semantic_model,
+                func,
+                if idx == 0 { signature_id } else { None },
+                colon_call,
+                current_idx,
+                table_field.as_deref(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let active_parameter = signatures[active_signature].active_parameter;
+    Some(SignatureHelp {
+        signatures,
+        active_signature: Some(active_signature as u32),
+        active_parameter,
+    })
 }
 
 pub fn get_current_param_index(call_expr: &LuaCallExpr, token: &LuaSyntaxToken) -> Option<usize> {
@@ -54,219 +114,321 @@ pub fn get_current_param_index(call_expr: &LuaCallExpr, token: &LuaSyntaxToken)
     Some(current_idx)
 }
 
-fn build_doc_function_signature_help(
+/// the overload which fits the arguments typed so far best, among the ones taking
+/// `arg_count` arguments when there are any
+fn get_active_signature(
     semantic_model: &SemanticModel,
-    func_type: &LuaFunctionType,
-    colon_call: bool,
-    current_idx: usize,
-) -> Option<SignatureHelp> {
-    let mut current_idx = current_idx;
-    let mut params = func_type
-        .get_params()
+    call_expr: &LuaCallExpr,
+    funcs: &[Arc<LuaFunctionType>],
+    is_generic: bool,
+    arg_count: usize,
+) -> usize {
+    let mut candidates = funcs
         .iter()
-        .map(|param| param.clone())
+        .enumerate()
+        .filter(|(_, func)| is_arg_count_fit(func, arg_count))
         .collect::<Vec<_>>();
-    let colon_define = func_type.is_colon_define();
-    match (colon_define, colon_call) {
-        (true, false) => {
-            params.insert(0, ("self".to_string(), None));
-        }
-        (false, true) => {
-            if !params.is_empty() {
-                params.remove(0);
-            }
-        }
-        _ => {}
+    if candidates.is_empty() {
+        candidates = funcs.iter().enumerate().collect();
     }
 
-    if let Some((name, _)) = params.last() {
-        if name == "..." && current_idx >= params.len() {
-            current_idx = params.len() - 1;
-        }
+    let overloads = candidates.iter().map(|(_, func)| (*func).clone()).collect();
+    semantic_model
+        .resolve_overload_index(call_expr.clone(), overloads, is_generic)
+        .and_then(|idx| candidates.get(idx))
+        .map(|(idx, _)| *idx)
+        .unwrap_or(0)
+}
+
+// a trailing `...` takes any number of arguments
+fn is_arg_count_fit(func: &LuaFunctionType, arg_count: usize) -> bool {
+    let params = func.get_params();
+    params.len() >= arg_count || params.last().is_some_and(|(name, _)| name == "...")
+}
+
+/// the key of the field being filled in `f{ key = ... }` or `f(a, { key = ... })`
+fn get_table_call_field(call_expr: &LuaCallExpr, token: &LuaSyntaxToken) -> Option<String> {
+    let arg_list = call_expr.get_args_list()?;
+    let table_field = token
+        .parent_ancestors()
+        .take_while(|node| node != arg_list.syntax())
+        .filter_map(LuaTableField::cast)
+        .last()?;
+    let table_expr = table_field.get_parent::<LuaTableExpr>()?;
+    if table_expr.syntax().parent()? != *arg_list.syntax() {
+        return None;
     }
 
-    let params_str = params
-        .iter()
-        .map(|param| param.0.clone())
-        .collect::<Vec<_>>();
-    let db = semantic_model.get_db();
-    let param_infos = params
-        .iter()
-        .map(|param| {
-            let label = param.0.clone();
-            let typ = param.1.clone();
-            let documentation = if let Some(typ) = typ {
-                Some(Documentation::String(humanize_type(
-                    db,
-                    &typ,
-                    RenderLevel::Simple,
-                )))
-            } else {
-                None
-            };
-            ParameterInformation {
-                label: ParameterLabel::Simple(label),
-                documentation,
-            }
-        })
-        .collect::<Vec<_>>();
+    match table_field.get_field_key()? {
+        LuaIndexKey::Name(name) => Some(name.get_name_text().to_string()),
+        LuaIndexKey::String(string) => Some(string.get_value()),
+        _ => None,
+    }
+}
 
-    let label = format!("{}", params_str.join(", "));
-    let signature_info = SignatureInformation {
-        label,
-        documentation: None,
-        parameters: Some(param_infos),
-        active_parameter: Some(current_idx as u32),
+fn get_call_operator_funcs(
+    semantic_model: &SemanticModel,
+    type_decl_id: &LuaTypeDeclId,
+) -> Vec<Arc<LuaFunctionType>> {
+    let db = semantic_model.get_db();
+    let mut funcs = Vec::new();
+    let Some(operators) = db.get_operator_index().get_operators_by_type(type_decl_id) else {
+        return funcs;
+    };
+    let Some(operator_ids) = operators.get(&LuaOperatorMetaMethod::Call) else {
+        return funcs;
     };
 
-    Some(SignatureHelp {
-        signatures: vec![signature_info],
-        active_signature: Some(0),
-        active_parameter: Some(current_idx as u32),
-    })
+    for operator_id in operator_ids {
+        let Some(operator) = db.get_operator_index().get_operator(operator_id) else {
+            continue;
+        };
+        if let Some(LuaType::DocFunction(f)) = operator.get_call_operator_type(db) {
+            funcs.push(f);
+        }
+    }
+
+    funcs
 }
 
-fn build_sig_id_signature_help(
+fn build_signature_information(
     semantic_model: &SemanticModel,
-    signature_id: LuaSignatureId,
+    func_type: &LuaFunctionType,
+    signature_id: Option<LuaSignatureId>,
     colon_call: bool,
     current_idx: usize,
-) -> Option<SignatureHelp> {
-    let origin_current_idx = current_idx;
+    table_field: Option<&str>,
+) -> SignatureInformation {
     let db = semantic_model.get_db();
-    let signature = db.get_signature_index().get(&signature_id)?;
-    let mut current_idx = current_idx;
-    let params = signature.get_type_params();
-    let colon_define = signature.is_colon_define;
-    let mut params_str = params
-        .iter()
-        .map(|param| param.0.clone())
-        .collect::<Vec<_>>();
-    match (colon_define, colon_call) {
+    let signature = signature_id.and_then(|id| db.get_signature_index().get(&id));
+    let mut params = func_type.get_params().to_vec();
+    match (func_type.is_colon_define(), colon_call) {
         (true, false) => {
-            params_str.insert(0, "self".to_string());
+            params.insert(0, ("self".to_string(), None));
         }
-        (false, true) => {
-            if !params_str.is_empty() {
-                params_str.remove(0);
-            }
+        (false, true) if !params.is_empty() => {
+            params.remove(0);
         }
         _ => {}
     }
 
+    // every argument after the last fixed parameter belongs to `...`
+    let mut current_idx = current_idx;
     if let Some((name, _)) = params.last() {
         if name == "..." && current_idx >= params.len() {
             current_idx = params.len() - 1;
         }
     }
 
-    let param_infos = params
-        .iter()
-        .map(|param| {
-            let label = param.0.clone();
-            let typ = param.1.clone();
-            let mut documentation_string = String::new();
-            if let Some(typ) = typ {
-                documentation_string.push_str(
-                    format!(
-                        "```lua\n(parameter) {}: {}\n```\n\n",
-                        label,
-                        humanize_type(db, &typ, RenderLevel::Simple)
-                    )
-                    .as_str(),
-                );
-            };
-
-            if let Some(desc) = signature.get_param_info_by_name(&label) {
+    let mut label = String::new();
+    let mut param_infos = Vec::new();
+    for (idx, (name, typ)) in params.iter().enumerate() {
+        if idx > 0 {
+            label.push_str(", ");
+        }
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(name);
+        let end = label.encode_utf16().count() as u32;
+
+        let mut documentation_string = String::new();
+        if let Some(typ) = typ {
+            documentation_string.push_str(&format!(
+                "```lua\n(parameter) {}: {}\n```\n\n",
+                name,
+                humanize_type(db, typ, RenderLevel::Simple)
+            ));
+        }
+
+        if let Some(signature) = signature {
+            if let Some(desc) = signature.get_param_info_by_name(name) {
                 if let Some(description) = &desc.description {
                     documentation_string.push_str(description);
                 }
             }
+        }
 
-            let documentation = if documentation_string.is_empty() {
-                None
-            } else {
-                Some(Documentation::MarkupContent(MarkupContent {
-                    kind: lsp_types::MarkupKind::Markdown,
-                    value: documentation_string,
-                }))
-            };
-
-            ParameterInformation {
-                label: ParameterLabel::Simple(label),
-                documentation,
+        if idx == current_idx {
+            if let (Some(field_name), Some(typ)) = (table_field, typ) {
+                if let Some(field_doc) = build_table_field_doc(semantic_model, typ, field_name) {
+                    documentation_string.push_str(&field_doc);
+                }
             }
-        })
-        .collect::<Vec<_>>();
+        }
 
-    let label = format!("{}", params_str.join(", "));
-    let property_owner = LuaPropertyOwnerId::Signature(signature_id);
-    let documentation = if let Some(property) = db.get_property_index().get_property(property_owner)
-    {
-        if let Some(description) = &property.description {
+        let documentation = if documentation_string.is_empty() {
+            None
+        } else {
             Some(Documentation::MarkupContent(MarkupContent {
                 kind: lsp_types::MarkupKind::Markdown,
-                value: description.to_string(),
+                value: documentation_string,
             }))
-        } else {
-            None
-        }
-    } else {
-        None
-    };
+        };
+
+        param_infos.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation,
+        });
+    }
 
-    let signature_info = SignatureInformation {
+    let documentation = signature_id
+        .and_then(|id| {
+            db.get_property_index()
+                .get_property(LuaPropertyOwnerId::Signature(id))
+        })
+        .and_then(|property| property.description.as_ref())
+        .map(|description| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: lsp_types::MarkupKind::Markdown,
+                value: description.to_string(),
+            })
+        });
+
+    SignatureInformation {
         label,
         documentation,
         parameters: Some(param_infos),
         active_parameter: Some(current_idx as u32),
-    };
-
-    let mut signatures = vec![signature_info];
-    for overload in &signature.overloads {
-        let signature = build_doc_function_signature_help(
-            &semantic_model,
-            &overload,
-            colon_call,
-            origin_current_idx,
-        );
-        if let Some(signature) = signature {
-            signatures.push(signature.signatures[0].clone());
-        }
     }
-
-    Some(SignatureHelp {
-        signatures,
-        active_signature: Some(0),
-        active_parameter: Some(current_idx as u32),
-    })
 }
 
-// todo support overload
-fn build_type_signature_help(
+/// the type and description of the table parameter field under the cursor
+fn build_table_field_doc(
     semantic_model: &SemanticModel,
-    type_decl_id: &LuaTypeDeclId,
-    colon_call: bool,
-    current_idx: usize,
-) -> Option<SignatureHelp> {
+    param_type: &LuaType,
+    field_name: &str,
+) -> Option<String> {
     let db = semantic_model.get_db();
-    let operators = db
-        .get_operator_index()
-        .get_operators_by_type(type_decl_id)?;
-    if let Some(operator_ids) = operators.get(&LuaOperatorMetaMethod::Call) {
-        for operator_id in operator_ids {
-            let operator = db.get_operator_index().get_operator(operator_id)?;
-            let call_type = operator.get_call_operator_type();
-            if let Some(LuaType::DocFunction(f)) = call_type {
-                return build_doc_function_signature_help(
-                    semantic_model,
-                    &f,
-                    colon_call,
-                    current_idx,
-                );
-            }
+    let member_infos = semantic_model.infer_member_infos(param_type)?;
+    let member_info = member_infos
+        .iter()
+        .find(|info| matches!(&info.key, LuaMemberKey::Name(name) if name == field_name))?;
+
+    let mut doc = format!(
+        "\n\n```lua\n(field) {}: {}\n```\n",
+        field_name,
+        humanize_type(db, &member_info.typ, RenderLevel::Simple)
+    );
+    if let Some(property_owner_id) = &member_info.property_owner_id {
+        if let Some(description) = db
+            .get_property_index()
+            .get_property(property_owner_id.clone())
+            .and_then(|property| property.description.as_ref())
+        {
+            doc.push('\n');
+            doc.push_str(description);
         }
     }
 
-    None
+    Some(doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use emmylua_parser::{LuaAstNode, LuaCallExpr};
+    use lsp_types::{Documentation, SignatureHelp};
+
+    use super::build_signature_helper;
+    use crate::handlers::test_lib::{def_with_cursor, get_token_at};
+
+    /// signature help with the cursor at `<|>` in `call`, after the `defs` are loaded
+    fn get_signature_help(defs: &str, call: &str) -> SignatureHelp {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(defs);
+        let (file_id, offset) = def_with_cursor(&mut ws, call);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let token = get_token_at(&semantic_model, offset);
+        let call_expr = token
+            .parent_ancestors()
+            .find_map(LuaCallExpr::cast)
+            .unwrap();
+        build_signature_helper(&semantic_model, call_expr, token).unwrap()
+    }
+
+    const DEFS: &str = r#"
+        ---@overload fun(name: string, ...: any)
+        ---@param a number
+        ---@param b number
+        function f(a, b)
+        end
+    "#;
+
+    #[test]
+    fn test_active_overload() {
+        let help = get_signature_help(DEFS, "f(1, <|>)");
+        assert_eq!(help.signatures.len(), 2);
+        assert_eq!(help.active_signature, Some(0));
+        assert_eq!(help.active_parameter, Some(1));
+    }
+
+    #[test]
+    fn test_vararg_overload() {
+        // the third argument only fits the overload ending in `...`, which stays active
+        let help = get_signature_help(DEFS, "f('x', 1, 2, <|>)");
+        assert_eq!(help.active_signature, Some(1));
+        assert_eq!(help.active_parameter, Some(1));
+        assert_eq!(help.signatures[1].label, "name, ...");
+    }
+
+    #[test]
+    fn test_past_last_param() {
+        let defs = r#"
+        ---@overload fun(a: number)
+        ---@param a number
+        ---@param b string
+        function g(a, b)
+        end
+        "#;
+
+        // no overload takes a third argument, the best typed one stays active and no
+        // parameter is highlighted
+        let help = get_signature_help(defs, "g(1, 's', <|>)");
+        assert_eq!(help.active_signature, Some(0));
+        assert_eq!(help.active_parameter, Some(2));
+        assert_eq!(help.signatures[0].parameters.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_table_call_field() {
+        let defs = r#"
+        ---@class WindowOptions
+        ---@field width number the width in pixels
+        ---@field title string
+
+        ---@param options WindowOptions
+        function open(options)
+        end
+
+        ---@param name string
+        ---@param options WindowOptions
+        function open_named(name, options)
+        end
+        "#;
+
+        let help = get_signature_help(defs, "open { title = 'x', width = 1<|> }");
+        let parameters = help.signatures[0].parameters.as_ref().unwrap();
+        let Some(Documentation::MarkupContent(content)) = &parameters[0].documentation else {
+            panic!("expected the parameter documentation");
+        };
+        assert_eq!(
+            content.value,
+            "```lua\n(parameter) options: WindowOptions\n```\n\n\n\n\
+            ```lua\n(field) width: number\n```\n\nthe width in pixels"
+        );
+
+        let help = get_signature_help(defs, "open_named('x', { title = 's'<|> })");
+        assert_eq!(help.active_parameter, Some(1));
+        let parameters = help.signatures[0].parameters.as_ref().unwrap();
+        let Some(Documentation::MarkupContent(content)) = &parameters[1].documentation else {
+            panic!("expected the parameter documentation");
+        };
+        assert!(content
+            .value
+            .ends_with("```lua\n(field) title: string\n```\n"));
+        // the field is only documented on the parameter it fills
+        let Some(Documentation::MarkupContent(content)) = &parameters[0].documentation else {
+            panic!("expected the parameter documentation");
+        };
+        assert!(!content.value.contains("(field)"));
+    }
 }
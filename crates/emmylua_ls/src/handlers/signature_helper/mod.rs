@@ -19,7 +19,7 @@ pub async fn on_signature_helper_handler(
     let uri = params.text_document_position_params.text_document.uri;
     let analysis = context.analysis.read().await;
     let file_id = analysis.get_file_id(&uri)?;
-    let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let root = semantic_model.get_root();
     let position = params.text_document_position_params.position;
     let position_offset = {
@@ -46,44 +46,38 @@ pub async fn on_signature_helper_handler(
         active_signature_help: None,
     });
 
-    if !param_context.is_retrigger {
-        let node = token.parent()?;
-        match node.kind().into() {
-            LuaSyntaxKind::CallArgList => {
-                let call_expr = LuaCallExpr::cast(node.parent()?)?;
-                build_signature_helper(&mut semantic_model, call_expr, token)
-            }
-            // todo
-            LuaSyntaxKind::TypeGeneric | LuaSyntaxKind::DocTypeList => None,
-            _ => None,
-        }
-    } else if matches!(
-        token.kind().into(),
-        LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine
-    ) {
+    if param_context.is_retrigger
+        && matches!(
+            token.kind().into(),
+            LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine
+        )
+    {
         if token.parent()?.kind() == LuaSyntaxKind::CallArgList.into() {
-            param_context.active_signature_help
+            return param_context.active_signature_help;
         } else {
-            None
+            return None;
         }
-    } else {
-        let node = token.parent_ancestors().find(|node| {
-            matches!(
-                node.kind().into(),
-                LuaSyntaxKind::CallArgList
-                    | LuaSyntaxKind::TypeGeneric
-                    | LuaSyntaxKind::DocTypeList
-            )
-        })?;
-        match node.kind().into() {
-            LuaSyntaxKind::CallArgList => {
-                let call_expr = LuaCallExpr::cast(node.parent()?)?;
-                build_signature_helper(&mut semantic_model, call_expr, token)
-            }
-            // todo
-            LuaSyntaxKind::TypeGeneric | LuaSyntaxKind::DocTypeList => None,
-            _ => None,
+    }
+
+    // stop at function bodies, a call around them is not the one being filled
+    let node = token.parent_ancestors().find(|node| {
+        matches!(
+            node.kind().into(),
+            LuaSyntaxKind::CallArgList
+                | LuaSyntaxKind::TypeGeneric
+                | LuaSyntaxKind::DocTypeList
+                | LuaSyntaxKind::ClosureExpr
+                | LuaSyntaxKind::Block
+        )
+    })?;
+    match node.kind().into() {
+        LuaSyntaxKind::CallArgList => {
+            let call_expr = LuaCallExpr::cast(node.parent()?)?;
+            build_signature_helper(&semantic_model, call_expr, token)
         }
+        // todo
+        LuaSyntaxKind::TypeGeneric | LuaSyntaxKind::DocTypeList => None,
+        _ => None,
     }
 }
 
@@ -92,8 +86,8 @@ pub fn register_capabilities(
     _: &ClientCapabilities,
 ) -> Option<()> {
     server_capabilities.signature_help_provider = Some(SignatureHelpOptions {
-        trigger_characters: Some(vec!["(", ","].iter().map(|s| s.to_string()).collect()),
-        retrigger_characters: Some(vec!["(", ","].iter().map(|s| s.to_string()).collect()),
+        trigger_characters: Some(["(", ",", "{"].iter().map(|s| s.to_string()).collect()),
+        retrigger_characters: Some(["(", ",", "{"].iter().map(|s| s.to_string()).collect()),
         ..Default::default()
     });
     Some(())
@@ -0,0 +1,39 @@
+use emmylua_code_analysis::{FileId, SemanticModel, VirtualWorkspace};
+use emmylua_parser::{LuaAstNode, LuaSyntaxToken};
+use lsp_types::CompletionItem;
+use rowan::{TextSize, TokenAtOffset};
+use tokio_util::sync::CancellationToken;
+
+use super::completion::CompletionBuilder;
+
+/// marks the cursor in test code, `|` would clash with union types
+pub const CURSOR: &str = "<|>";
+
+/// define `code` without its cursor marker, returns the file and the cursor offset
+pub fn def_with_cursor(ws: &mut VirtualWorkspace, code: &str) -> (FileId, TextSize) {
+    let offset = code.find(CURSOR).expect("no cursor in the code");
+    let file_id = ws.def(&code.replace(CURSOR, ""));
+    (file_id, TextSize::from(offset as u32))
+}
+
+/// the token left of `offset`, the one a request at that position works on
+pub fn get_token_at(semantic_model: &SemanticModel, offset: TextSize) -> LuaSyntaxToken {
+    match semantic_model.get_root().syntax().token_at_offset(offset) {
+        TokenAtOffset::Single(token) | TokenAtOffset::Between(token, _) => token,
+        TokenAtOffset::None => panic!("no token at the cursor"),
+    }
+}
+
+/// the items `add_completion` offers with the cursor at `<|>` in `code`
+pub fn get_completion_items(
+    code: &str,
+    add_completion: fn(&mut CompletionBuilder) -> Option<()>,
+) -> Vec<CompletionItem> {
+    let mut ws = VirtualWorkspace::new();
+    let (file_id, offset) = def_with_cursor(&mut ws, code);
+    let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+    let token = get_token_at(&semantic_model, offset);
+    let mut builder = CompletionBuilder::new(token, semantic_model, CancellationToken::new());
+    add_completion(&mut builder);
+    builder.get_completion_items()
+}
@@ -3,7 +3,9 @@ use lsp_types::{
     DidSaveTextDocumentParams,
 };
 
-use crate::context::ServerContextSnapshot;
+use crate::{
+    context::ServerContextSnapshot, handlers::semantic_token::remove_semantic_token_cache,
+};
 
 pub async fn on_did_open_text_document(
     context: ServerContextSnapshot,
@@ -67,7 +69,8 @@ pub async fn on_did_change_text_document(
 
 pub async fn on_did_close_document(
     _: ServerContextSnapshot,
-    _: DidCloseTextDocumentParams,
+    params: DidCloseTextDocumentParams,
 ) -> Option<()> {
+    remove_semantic_token_cache(&params.text_document.uri);
     Some(())
 }
@@ -1,20 +1,109 @@
-use emmylua_code_analysis::{DbIndex, LuaCompilation, LuaPropertyOwnerId, LuaType};
-use lsp_types::{OneOf, SymbolKind, SymbolTag, WorkspaceSymbol, WorkspaceSymbolResponse};
+use emmylua_code_analysis::{
+    DbIndex, FileId, LuaCompilation, LuaDeclId, LuaMemberOwner, LuaPropertyOwnerId, LuaType,
+};
+use emmylua_parser::{LuaAstNode, LuaLocalFuncStat};
+use lsp_types::{
+    OneOf, SymbolKind, SymbolTag, WorkspaceLocation, WorkspaceSymbol, WorkspaceSymbolResponse,
+};
+use rowan::TextRange;
 use tokio_util::sync::CancellationToken;
 
+use super::fuzzy_match::fuzzy_match;
+
+const MAX_SYMBOL_COUNT: usize = 200;
+
+struct SymbolCandidate {
+    name: String,
+    kind: SymbolKind,
+    container_name: Option<String>,
+    owner_id: LuaPropertyOwnerId,
+    score: i32,
+}
+
 pub fn build_workspace_symbols(
     compilation: &LuaCompilation,
     query: String,
+    lazy_location: bool,
     cancel_token: CancellationToken,
 ) -> Option<WorkspaceSymbolResponse> {
+    let mut candidates = Vec::new();
+    add_global_variable_symbols(&mut candidates, compilation, &query, &cancel_token)?;
+    add_type_symbols(&mut candidates, compilation, &query, &cancel_token)?;
+    add_member_symbols(&mut candidates, compilation, &query, &cancel_token)?;
+    add_module_symbols(&mut candidates, compilation, &query, &cancel_token)?;
+
+    candidates.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.name.len().cmp(&b.name.len()))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    candidates.truncate(MAX_SYMBOL_COUNT);
+
+    let db = compilation.get_db();
     let mut symbols = Vec::new();
-    add_global_variable_symbols(&mut symbols, compilation, &query, &cancel_token)?;
-    add_type_symbols(&mut symbols, compilation, &query, &cancel_token)?;
+    for candidate in candidates {
+        if cancel_token.is_cancelled() {
+            return None;
+        }
+
+        // with a lazy location only the uri is needed, the range waits for the resolve
+        let location = if lazy_location {
+            let Some(uri) = get_owner_file_id(db, &candidate.owner_id)
+                .and_then(|file_id| db.get_vfs().get_uri(&file_id))
+            else {
+                continue;
+            };
+            OneOf::Right(WorkspaceLocation { uri })
+        } else {
+            let Some(location) =
+                get_owner_location(db, &candidate.owner_id).and_then(|(file_id, range)| {
+                    db.get_vfs()
+                        .get_document(&file_id)
+                        .and_then(|document| document.to_lsp_location(range))
+                })
+            else {
+                continue;
+            };
+            OneOf::Left(location)
+        };
+
+        symbols.push(WorkspaceSymbol {
+            name: candidate.name,
+            kind: candidate.kind,
+            tags: if is_deprecated(db, candidate.owner_id.clone()) {
+                Some(vec![SymbolTag::DEPRECATED])
+            } else {
+                None
+            },
+            container_name: candidate.container_name,
+            location,
+            data: if lazy_location {
+                serde_json::to_value(&candidate.owner_id).ok()
+            } else {
+                None
+            },
+        });
+    }
+
     Some(WorkspaceSymbolResponse::Nested(symbols))
 }
 
+/// fill in the location of a symbol returned with only its uri
+pub fn resolve_workspace_symbol(
+    compilation: &LuaCompilation,
+    mut symbol: WorkspaceSymbol,
+) -> Option<WorkspaceSymbol> {
+    let owner_id = serde_json::from_value::<LuaPropertyOwnerId>(symbol.data.clone()?).ok()?;
+    let db = compilation.get_db();
+    let (file_id, range) = get_owner_location(db, &owner_id)?;
+    let document = db.get_vfs().get_document(&file_id)?;
+    symbol.location = OneOf::Left(document.to_lsp_location(range)?);
+    Some(symbol)
+}
+
 fn add_global_variable_symbols(
-    symbols: &mut Vec<WorkspaceSymbol>,
+    candidates: &mut Vec<SymbolCandidate>,
     compilation: &LuaCompilation,
     query: &str,
     cancel_token: &CancellationToken,
@@ -32,24 +121,15 @@ fn add_global_variable_symbols(
             return None;
         }
 
-        if decl.get_name().contains(query) {
+        if let Some(score) = fuzzy_match(query, decl.get_name()) {
             let typ = decl.get_type().unwrap_or(&LuaType::Unknown);
-            let property_owner_id = LuaPropertyOwnerId::LuaDecl(decl_id);
-            let document = db.get_vfs().get_document(&decl.get_file_id())?;
-            let location = document.to_lsp_location(decl.get_range())?;
-            let symbol = WorkspaceSymbol {
+            candidates.push(SymbolCandidate {
                 name: decl.get_name().to_string(),
                 kind: get_symbol_kind(typ),
-                tags: if is_deprecated(db, property_owner_id) {
-                    Some(vec![SymbolTag::DEPRECATED])
-                } else {
-                    None
-                },
                 container_name: None,
-                location: OneOf::Left(location),
-                data: None,
-            };
-            symbols.push(symbol);
+                owner_id: LuaPropertyOwnerId::LuaDecl(decl_id),
+                score,
+            });
         }
     }
 
@@ -57,7 +137,7 @@ fn add_global_variable_symbols(
 }
 
 fn add_type_symbols(
-    symbols: &mut Vec<WorkspaceSymbol>,
+    candidates: &mut Vec<SymbolCandidate>,
     compilation: &LuaCompilation,
     query: &str,
     cancel_token: &CancellationToken,
@@ -74,30 +154,223 @@ fn add_type_symbols(
             return None;
         }
 
-        if typ.get_full_name().contains(query) {
-            let property_owner_id = LuaPropertyOwnerId::TypeDecl(typ.get_id());
-            let location = typ.get_locations().first()?;
-            let document = db.get_vfs().get_document(&location.file_id)?;
-            let location = document.to_lsp_location(location.range)?;
-            let symbol = WorkspaceSymbol {
+        if let Some(score) = fuzzy_match(query, typ.get_full_name()) {
+            candidates.push(SymbolCandidate {
                 name: typ.get_full_name().to_string(),
                 kind: SymbolKind::CLASS,
-                tags: if is_deprecated(db, property_owner_id) {
-                    Some(vec![SymbolTag::DEPRECATED])
-                } else {
-                    None
-                },
                 container_name: None,
-                location: OneOf::Left(location),
-                data: None,
+                owner_id: LuaPropertyOwnerId::TypeDecl(typ.get_id()),
+                score,
+            });
+        }
+    }
+
+    Some(())
+}
+
+/// fields and methods of classes, as `Class.field` and `Class:method`
+fn add_member_symbols(
+    candidates: &mut Vec<SymbolCandidate>,
+    compilation: &LuaCompilation,
+    query: &str,
+    cancel_token: &CancellationToken,
+) -> Option<()> {
+    let db = compilation.get_db();
+    let member_index = db.get_member_index();
+    for typ in db.get_type_index().get_all_types() {
+        if cancel_token.is_cancelled() {
+            return None;
+        }
+
+        let owner = LuaMemberOwner::Type(typ.get_id());
+        let Some(members) = member_index.get_member_map(owner) else {
+            continue;
+        };
+        for member_id in members.values() {
+            let Some(member) = member_index.get_member(member_id) else {
+                continue;
+            };
+            let Some(member_name) = member.get_key().get_name() else {
+                continue;
             };
-            symbols.push(symbol);
+
+            let member_type = member.get_decl_type();
+            let is_method = is_colon_function(db, member_type);
+            let name = format!(
+                "{}{}{}",
+                typ.get_full_name(),
+                if is_method { ":" } else { "." },
+                member_name
+            );
+            if let Some(score) = fuzzy_match(query, &name) {
+                candidates.push(SymbolCandidate {
+                    name,
+                    kind: if member_type.is_function() {
+                        SymbolKind::METHOD
+                    } else {
+                        SymbolKind::FIELD
+                    },
+                    container_name: Some(typ.get_full_name().to_string()),
+                    owner_id: LuaPropertyOwnerId::Member(member.get_id()),
+                    score,
+                });
+            }
         }
     }
 
     Some(())
 }
 
+/// functions of the table a workspace module returns, and its top level local functions
+fn add_module_symbols(
+    candidates: &mut Vec<SymbolCandidate>,
+    compilation: &LuaCompilation,
+    query: &str,
+    cancel_token: &CancellationToken,
+) -> Option<()> {
+    let db = compilation.get_db();
+    let member_index = db.get_member_index();
+    for module_info in db.get_module_index().get_module_infos() {
+        if cancel_token.is_cancelled() {
+            return None;
+        }
+        if !module_info.workspace_id.is_main() {
+            continue;
+        }
+
+        // class tables are already listed by their type
+        if let Some(LuaType::TableConst(table_range)) = &module_info.export_type {
+            let owner = LuaMemberOwner::Element(table_range.clone());
+            for member_id in member_index
+                .get_member_map(owner)
+                .map(|members| members.values().cloned().collect::<Vec<_>>())
+                .unwrap_or_default()
+            {
+                let Some(member) = member_index.get_member(&member_id) else {
+                    continue;
+                };
+                let Some(member_name) = member.get_key().get_name() else {
+                    continue;
+                };
+                if !member.get_decl_type().is_function() {
+                    continue;
+                }
+
+                let name = format!("{}.{}", module_info.name, member_name);
+                if let Some(score) = fuzzy_match(query, &name) {
+                    candidates.push(SymbolCandidate {
+                        name,
+                        kind: SymbolKind::FUNCTION,
+                        container_name: Some(module_info.full_module_name.clone()),
+                        owner_id: LuaPropertyOwnerId::Member(member_id),
+                        score,
+                    });
+                }
+            }
+        }
+
+        add_local_function_symbols(
+            candidates,
+            db,
+            module_info.file_id,
+            &module_info.full_module_name,
+            query,
+        );
+    }
+
+    Some(())
+}
+
+fn add_local_function_symbols(
+    candidates: &mut Vec<SymbolCandidate>,
+    db: &DbIndex,
+    file_id: FileId,
+    module_name: &str,
+    query: &str,
+) -> Option<()> {
+    let root = db.get_vfs().get_syntax_tree(&file_id)?.get_chunk_node();
+    for local_func_stat in root.get_block()?.children::<LuaLocalFuncStat>() {
+        let Some(local_name) = local_func_stat.get_local_name() else {
+            continue;
+        };
+        let Some(name_token) = local_name.get_name_token() else {
+            continue;
+        };
+        let name = name_token.get_name_text().to_string();
+        if let Some(score) = fuzzy_match(query, &name) {
+            candidates.push(SymbolCandidate {
+                name,
+                kind: SymbolKind::FUNCTION,
+                container_name: Some(module_name.to_string()),
+                owner_id: LuaPropertyOwnerId::LuaDecl(LuaDeclId::new(
+                    file_id,
+                    local_name.get_position(),
+                )),
+                score,
+            });
+        }
+    }
+
+    Some(())
+}
+
+fn get_owner_file_id(db: &DbIndex, owner_id: &LuaPropertyOwnerId) -> Option<FileId> {
+    match owner_id {
+        LuaPropertyOwnerId::LuaDecl(decl_id) => Some(decl_id.file_id),
+        LuaPropertyOwnerId::Member(member_id) => Some(member_id.file_id),
+        LuaPropertyOwnerId::TypeDecl(type_id) => db
+            .get_type_index()
+            .get_type_decl(type_id)?
+            .get_locations()
+            .first()
+            .map(|location| location.file_id),
+        LuaPropertyOwnerId::Signature(_) => None,
+    }
+}
+
+fn get_owner_location(db: &DbIndex, owner_id: &LuaPropertyOwnerId) -> Option<(FileId, TextRange)> {
+    match owner_id {
+        LuaPropertyOwnerId::LuaDecl(decl_id) => {
+            let decl = db.get_decl_index().get_decl(decl_id)?;
+            Some((decl.get_file_id(), decl.get_range()))
+        }
+        LuaPropertyOwnerId::Member(member_id) => {
+            let member = db.get_member_index().get_member(member_id)?;
+            Some((member.get_file_id(), member.get_range()))
+        }
+        LuaPropertyOwnerId::TypeDecl(type_id) => {
+            let location = db
+                .get_type_index()
+                .get_type_decl(type_id)?
+                .get_locations()
+                .first()?;
+            Some((location.file_id, location.range))
+        }
+        LuaPropertyOwnerId::Signature(_) => None,
+    }
+}
+
+fn is_colon_function(db: &DbIndex, typ: &LuaType) -> bool {
+    match typ {
+        LuaType::Signature(signature_id) => {
+            db.get_signature_index()
+                .get(signature_id)
+                .is_some_and(|signature| {
+                    signature.is_colon_define
+                        || signature.params.first().is_some_and(|name| name == "self")
+                })
+        }
+        LuaType::DocFunction(func) => {
+            func.is_colon_define()
+                || func
+                    .get_params()
+                    .first()
+                    .is_some_and(|(name, _)| name == "self")
+        }
+        _ => false,
+    }
+}
+
 fn get_symbol_kind(typ: &LuaType) -> SymbolKind {
     if typ.is_function() {
         return SymbolKind::FUNCTION;
@@ -118,3 +391,74 @@ fn is_deprecated(db: &DbIndex, id: LuaPropertyOwnerId) -> bool {
 
     property.unwrap().is_deprecated
 }
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use lsp_types::{OneOf, WorkspaceSymbolResponse};
+    use tokio_util::sync::CancellationToken;
+
+    use super::{build_workspace_symbols, resolve_workspace_symbol};
+
+    #[test]
+    fn test_workspace_symbols() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "player.lua",
+            r#"
+            ---@class Player
+            local Player = {}
+
+            function Player:takeDamage(amount)
+            end
+
+            local function spawn_player()
+            end
+
+            local M = {}
+
+            function M.tick()
+            end
+
+            return M
+            "#,
+        );
+
+        let compilation = &ws.analysis.compilation;
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = build_workspace_symbols(
+            compilation,
+            "pltd".to_string(),
+            true,
+            CancellationToken::new(),
+        ) else {
+            panic!("no symbols");
+        };
+        assert_eq!(symbols[0].name, "Player:takeDamage");
+        assert!(matches!(symbols[0].location, OneOf::Right(_)));
+        let resolved = resolve_workspace_symbol(compilation, symbols[0].clone()).unwrap();
+        let OneOf::Left(location) = resolved.location else {
+            panic!("the location is not resolved");
+        };
+        assert_eq!(location.range.start.line, 4);
+
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = build_workspace_symbols(
+            compilation,
+            "spawn".to_string(),
+            false,
+            CancellationToken::new(),
+        ) else {
+            panic!("no symbols");
+        };
+        assert!(symbols.iter().any(|symbol| symbol.name == "spawn_player"));
+
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = build_workspace_symbols(
+            compilation,
+            "tick".to_string(),
+            false,
+            CancellationToken::new(),
+        ) else {
+            panic!("no symbols");
+        };
+        assert!(symbols.iter().any(|symbol| symbol.name == "player.tick"));
+    }
+}
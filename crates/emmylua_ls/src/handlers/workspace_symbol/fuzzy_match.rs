@@ -0,0 +1,120 @@
+/// score how well `query` matches `candidate`, `None` when it does not match at all.
+/// query characters must appear in order; matches at the start of a camelCase or
+/// snake_case segment and consecutive matches score higher, so `pltd` finds `Player:takeDamage`
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<i32> {
+    let query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect::<Vec<_>>();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let chars = candidate.chars().collect::<Vec<_>>();
+    let lower = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect::<Vec<_>>();
+    if !is_subsequence(&query, &lower) {
+        return None;
+    }
+    let segment_starts = (0..chars.len())
+        .map(|idx| is_segment_start(&chars, idx))
+        .collect::<Vec<_>>();
+
+    // best[j]: the best score with the current query char matched at j
+    let mut best = vec![None; chars.len()];
+    for (j, c) in lower.iter().enumerate() {
+        if is_char_match(query[0], *c) {
+            let mut score = 1 - (j as i32).min(3);
+            if segment_starts[j] {
+                score += 8;
+            }
+            if j == 0 {
+                score += 4;
+            }
+            best[j] = Some(score);
+        }
+    }
+
+    for q in &query[1..] {
+        let mut next = vec![None; chars.len()];
+        for (j, c) in lower.iter().enumerate() {
+            if !is_char_match(*q, *c) {
+                continue;
+            }
+
+            let mut score = None;
+            for (k, prev) in best.iter().enumerate().take(j) {
+                let Some(prev) = *prev else {
+                    continue;
+                };
+                let mut current = prev + 1;
+                if k + 1 == j {
+                    current += 6;
+                } else {
+                    current -= ((j - k - 1) as i32).min(3);
+                }
+                if segment_starts[j] {
+                    current += 8;
+                }
+                score = score.max(Some(current));
+            }
+            next[j] = score;
+        }
+        best = next;
+    }
+
+    best.into_iter().flatten().max()
+}
+
+fn is_char_match(query: char, candidate: char) -> bool {
+    query == candidate || (matches!(query, '.' | ':') && matches!(candidate, '.' | ':'))
+}
+
+fn is_subsequence(query: &[char], candidate: &[char]) -> bool {
+    let mut chars = candidate.iter();
+    query.iter().all(|q| chars.any(|c| is_char_match(*q, *c)))
+}
+
+fn is_segment_start(chars: &[char], idx: usize) -> bool {
+    let current = chars[idx];
+    if idx == 0 {
+        return true;
+    }
+
+    let prev = chars[idx - 1];
+    if !current.is_alphanumeric() {
+        return false;
+    }
+
+    !prev.is_alphanumeric()
+        || (current.is_uppercase() && !prev.is_uppercase())
+        || (current.is_ascii_digit() && !prev.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_match;
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("pltd", "Player:takeDamage").is_some());
+        assert!(fuzzy_match("gpn", "get_player_name").is_some());
+        assert!(fuzzy_match("Player.take", "Player:takeDamage").is_some());
+        assert!(fuzzy_match("dp", "Player:takeDamage").is_none());
+        assert_eq!(fuzzy_match("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_match_rank() {
+        let segment = fuzzy_match("pd", "Player:damage").unwrap();
+        let inner = fuzzy_match("pd", "speed").unwrap();
+        assert!(segment > inner);
+
+        let prefix = fuzzy_match("play", "Player").unwrap();
+        let scattered = fuzzy_match("play", "PageLayout").unwrap();
+        assert!(prefix > scattered);
+    }
+}
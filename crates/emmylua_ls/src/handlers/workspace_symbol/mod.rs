@@ -1,11 +1,17 @@
 mod build_workspace_symbols;
+mod fuzzy_match;
 
-use build_workspace_symbols::build_workspace_symbols;
-use lsp_types::{ClientCapabilities, OneOf, ServerCapabilities, WorkspaceSymbolParams, WorkspaceSymbolResponse};
+use build_workspace_symbols::{build_workspace_symbols, resolve_workspace_symbol};
+use lsp_types::{
+    ClientCapabilities, OneOf, ServerCapabilities, WorkspaceSymbol, WorkspaceSymbolOptions,
+    WorkspaceSymbolParams, WorkspaceSymbolResponse,
+};
 use tokio_util::sync::CancellationToken;
 
 use crate::context::ServerContextSnapshot;
 
+static mut WORKSPACE_SYMBOL_LAZY_LOCATION: bool = false;
+
 pub async fn on_workspace_symbol_handler(
     context: ServerContextSnapshot,
     params: WorkspaceSymbolParams,
@@ -14,16 +20,53 @@ pub async fn on_workspace_symbol_handler(
     let query = params.query;
     let analysis = context.analysis.read().await;
     let compilation = &analysis.compilation;
-    
-    build_workspace_symbols(compilation, query, cancel_token)
+
+    build_workspace_symbols(
+        compilation,
+        query,
+        unsafe { WORKSPACE_SYMBOL_LAZY_LOCATION },
+        cancel_token,
+    )
 }
 
+pub async fn on_workspace_symbol_resolve_handler(
+    context: ServerContextSnapshot,
+    params: WorkspaceSymbol,
+    _: CancellationToken,
+) -> WorkspaceSymbol {
+    let analysis = context.analysis.read().await;
+    let compilation = &analysis.compilation;
+
+    resolve_workspace_symbol(compilation, params.clone()).unwrap_or(params)
+}
 
 pub fn register_capabilities(
     server_capabilities: &mut ServerCapabilities,
-    _: &ClientCapabilities,
+    client_capabilities: &ClientCapabilities,
 ) -> Option<()> {
-    server_capabilities.workspace_symbol_provider = Some(OneOf::Left(true));
+    server_capabilities.workspace_symbol_provider = Some(OneOf::Right(WorkspaceSymbolOptions {
+        resolve_provider: Some(true),
+        work_done_progress_options: Default::default(),
+    }));
+
+    if is_support_lazy_location(client_capabilities) {
+        unsafe { WORKSPACE_SYMBOL_LAZY_LOCATION = true };
+    }
 
     Some(())
 }
+
+fn is_support_lazy_location(client_capabilities: &ClientCapabilities) -> bool {
+    if let Some(workspace) = &client_capabilities.workspace {
+        if let Some(symbol) = &workspace.symbol {
+            if let Some(resolve_support) = &symbol.resolve_support {
+                return resolve_support
+                    .properties
+                    .iter()
+                    .any(|property| property.starts_with("location"));
+            }
+        }
+    }
+
+    false
+}
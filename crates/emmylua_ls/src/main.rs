@@ -25,6 +25,13 @@ const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
     let cmd_args = CmdArgs::from_args();
+    if let Some(dir) = &cmd_args.dump_locale {
+        if meta_text::dump_locale(dir).is_none() {
+            return Err(format!("Failed to dump locale files into {:?}", dir).into());
+        }
+        return Ok(());
+    }
+
     let (connection, threads) = match cmd_args.communication {
         cmd_args::Communication::Stdio => Connection::stdio(),
         cmd_args::Communication::Tcp => {
@@ -0,0 +1,169 @@
+use std::{collections::BTreeMap, path::Path};
+
+use emmylua_code_analysis::{
+    DbIndex, EmmyLuaAnalysis, FileId, LuaMemberKey, LuaMemberOwner, LuaPropertyOwnerId,
+    LuaTypeDeclId,
+};
+
+use super::STD_NAMES;
+
+const TAGS_EN: &str = include_str!("../../locales/tags/en.yaml");
+const KEYWORDS_EN: &str = include_str!("../../locales/keywords/en.yaml");
+
+/// Write the english text of every translatable key into `dir`, using the same layout as
+/// the bundled locales, eg `std/math/en.yaml`, so that it can be copied and translated.
+pub fn dump_locale(dir: &Path) -> Option<()> {
+    let mut analysis = EmmyLuaAnalysis::new();
+    analysis.init_std_lib(false);
+    let db = analysis.compilation.get_db();
+
+    for (module, entries) in collect_std_texts(db) {
+        let mut content = String::new();
+        for (key, text) in entries {
+            write_yaml_entry(&mut content, &key, &text);
+        }
+        write_locale_file(&dir.join("std").join(module).join("en.yaml"), &content)?;
+    }
+
+    write_locale_file(&dir.join("tags").join("en.yaml"), TAGS_EN)?;
+    write_locale_file(&dir.join("keywords").join("en.yaml"), KEYWORDS_EN)?;
+    Some(())
+}
+
+// std file name -> (key, text)
+fn collect_std_texts(db: &DbIndex) -> BTreeMap<String, Vec<(String, String)>> {
+    let mut result: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    for name in STD_NAMES {
+        let type_decl_id = LuaTypeDeclId::new(name);
+        if let Some(type_decl) = db.get_type_index().get_type_decl(&type_decl_id) {
+            let Some(file_id) = type_decl.get_file_ids().first().cloned() else {
+                continue;
+            };
+            let Some(module) = get_module_name(db, file_id) else {
+                continue;
+            };
+
+            let entries = result.entry(module).or_default();
+            let key = format!("std.{}", name);
+            if let Some(text) =
+                get_description(db, LuaPropertyOwnerId::TypeDecl(type_decl_id.clone()))
+            {
+                entries.push((key.clone(), text));
+            }
+
+            let Some(member_map) = db
+                .get_member_index()
+                .get_member_map(LuaMemberOwner::Type(type_decl_id))
+            else {
+                continue;
+            };
+            let mut members = member_map
+                .values()
+                .filter_map(|id| db.get_member_index().get_member(id))
+                .collect::<Vec<_>>();
+            members.sort_by_key(|member| (member.get_file_id(), member.get_range().start()));
+            for member in members {
+                let Some(member_name) = member.get_key().get_name() else {
+                    continue;
+                };
+                if let Some(text) = get_description(db, LuaPropertyOwnerId::Member(member.get_id()))
+                {
+                    entries.push((format!("{}.{}", key, member_name), text));
+                }
+            }
+        } else if let Some(decl_id) = db
+            .get_decl_index()
+            .get_global_decl_id(&LuaMemberKey::Name(name.to_string().into()))
+        {
+            let Some(module) = get_module_name(db, decl_id.file_id) else {
+                continue;
+            };
+            if let Some(text) = get_description(db, LuaPropertyOwnerId::LuaDecl(decl_id)) {
+                result
+                    .entry(module)
+                    .or_default()
+                    .push((format!("std.{}", name), text));
+            }
+        }
+    }
+
+    result
+}
+
+fn get_module_name(db: &DbIndex, file_id: FileId) -> Option<String> {
+    let path = db.get_vfs().get_file_path(&file_id)?;
+    Some(path.file_stem()?.to_str()?.to_string())
+}
+
+fn get_description(db: &DbIndex, property_owner: LuaPropertyOwnerId) -> Option<String> {
+    let property = db.get_property_index().get_property(property_owner)?;
+    let description = property.description.as_ref()?.trim_end();
+    if description.is_empty() {
+        return None;
+    }
+
+    Some(description.to_string())
+}
+
+fn write_yaml_entry(content: &mut String, key: &str, text: &str) {
+    // an indentation indicator is needed when the text starts with spaces
+    let indicator = if text.starts_with(' ') { "|2" } else { "|" };
+    content.push_str(&format!("{}: {}\n", key, indicator));
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            content.push('\n');
+        } else {
+            content.push_str(&format!("  {}\n", line));
+        }
+    }
+    content.push('\n');
+}
+
+fn write_locale_file(path: &Path, content: &str) -> Option<()> {
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            eprintln!("Failed to create directory: {:?}, error: {:?}", parent, e);
+            return None;
+        }
+    }
+
+    if let Err(e) = std::fs::write(path, content) {
+        eprintln!("Failed to write locale file: {:?}, error: {:?}", path, e);
+        return None;
+    }
+
+    println!("write locale file: {:?}", path);
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use emmylua_code_analysis::EmmyLuaAnalysis;
+
+    use super::{collect_std_texts, write_yaml_entry};
+
+    #[test]
+    fn test_yaml_entry() {
+        let mut content = String::new();
+        write_yaml_entry(&mut content, "std.a", "first line\n\n  indented: `x`");
+        write_yaml_entry(&mut content, "std.b", "  starts with spaces");
+        let texts: HashMap<String, String> = serde_yml::from_str(&content).unwrap();
+        assert_eq!(texts["std.a"], "first line\n\n  indented: `x`\n");
+        assert_eq!(texts["std.b"], "  starts with spaces\n");
+    }
+
+    #[test]
+    fn test_collect_std_texts() {
+        let mut analysis = EmmyLuaAnalysis::new();
+        analysis.init_std_lib(false);
+        let texts = collect_std_texts(analysis.compilation.get_db());
+        let table_keys = texts["table"]
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>();
+        assert!(table_keys.contains(&"std.tablelib.move"));
+        assert!(texts["global"].iter().any(|(key, _)| key == "std.print"));
+    }
+}
@@ -1,10 +1,71 @@
+mod locale_dump;
+mod user_locale;
+
+pub use locale_dump::dump_locale;
+pub use user_locale::{load_user_locales, UserLocales};
+
+// type and global names whose std documentation can be translated
+pub const STD_NAMES: &[&str] = &[
+    "oslib",
+    "std.osdate",
+    "std.osdateparam",
+    "mathlib",
+    "coroutinelib",
+    "debuglib",
+    "iolib",
+    "file",
+    "packagelib",
+    "string",
+    "tablelib",
+    "utf8lib",
+    "bit32lib",
+    // 全局函数/变量
+    "arg",
+    "assert",
+    "collectgarbage",
+    "std.collectgarbage_opt",
+    "std.loadmode",
+    "dofile",
+    "error",
+    "_G",
+    "getfenv",
+    "getmetatable",
+    "ipairs",
+    "load",
+    "loadstring",
+    "loadfile",
+    "newproxy",
+    "module",
+    "next",
+    "pairs",
+    "pcall",
+    "print",
+    "rawequal",
+    "rawget",
+    "rawlen",
+    "rawset",
+    "require",
+    "select",
+    "setfenv",
+    "setmetatable",
+    "tonumber",
+    "tostring",
+    "type",
+    "unpack",
+    "_VERSION",
+    "warn",
+    "xpcall",
+];
+
 pub fn meta_keyword(key: &str) -> String {
-    t!(format!("keywords.{}", key)).to_string()
+    let key = format!("keywords.{}", key);
+    translate(&key).unwrap_or(key)
 }
 
 #[allow(unused)]
 pub fn meta_builtin_std(key: &str) -> String {
-    t!(format!("builtin_std.{}", key)).to_string()
+    let key = format!("builtin_std.{}", key);
+    translate(&key).unwrap_or(key)
 }
 
 pub fn meta_std(type_name: &str, member_name: Option<&str>) -> String {
@@ -13,14 +74,71 @@ pub fn meta_std(type_name: &str, member_name: Option<&str>) -> String {
     } else {
         format!("std.{}", type_name)
     };
-    let s = t!(key).to_string();
-    if s == key { // 临时处理, 因为我们还未完成所有翻译
-        "".to_string()
-    } else {
-        s
-    }
+    // empty means the description of the std annotation is used
+    translate(&key).unwrap_or_default()
 }
 
 pub fn meta_doc_tag(key: &str) -> String {
-    t!(format!("tags.{}", key)).to_string()
+    let key = format!("tags.{}", key);
+    translate(&key).unwrap_or(key)
+}
+
+fn translate(key: &str) -> Option<String> {
+    translate_with(user_locale::get_user_locales(), &rust_i18n::locale(), key)
+}
+
+// user locale files first, then the bundled locale, then english
+fn translate_with(user_locales: Option<&UserLocales>, locale: &str, key: &str) -> Option<String> {
+    if let Some(text) = user_locales.and_then(|locales| locales.get_text(locale, key)) {
+        return Some(text);
+    }
+
+    // the bundled translations already fall back to the bundled english
+    let text = t!(key, locale = locale).to_string();
+    if text != key {
+        return Some(text);
+    }
+
+    user_locales?.get_text("en", key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{translate_with, UserLocales};
+
+    #[test]
+    fn test_translate_fallback() {
+        let dir =
+            std::env::temp_dir().join(format!("emmylua_translate_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("zh_CN.yaml"), "keywords.for: 用户循环\n").unwrap();
+        std::fs::write(
+            dir.join("en.yaml"),
+            "keywords.for: user loop\nstd.custom: custom text\n",
+        )
+        .unwrap();
+        let locales = UserLocales::load(&dir).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // the user locale wins over the bundled one
+        assert_eq!(
+            translate_with(Some(&locales), "zh_CN", "keywords.for").as_deref(),
+            Some("用户循环")
+        );
+        // then the bundled locale
+        let bundled = t!("keywords.while", locale = "zh_CN").to_string();
+        assert_ne!(bundled, "keywords.while");
+        assert_eq!(
+            translate_with(Some(&locales), "zh_CN", "keywords.while"),
+            Some(bundled)
+        );
+        // then the user english
+        assert_eq!(
+            translate_with(Some(&locales), "zh_CN", "std.custom").as_deref(),
+            Some("custom text")
+        );
+        assert_eq!(translate_with(None, "zh_CN", "std.custom"), None);
+        assert_eq!(translate_with(Some(&locales), "zh_CN", "std.missing"), None);
+    }
 }
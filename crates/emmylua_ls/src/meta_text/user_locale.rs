@@ -0,0 +1,129 @@
+use std::{collections::HashMap, path::Path, sync::OnceLock};
+
+use walkdir::WalkDir;
+
+static USER_LOCALES: OnceLock<UserLocales> = OnceLock::new();
+
+/// Load the user locale files used by the meta texts, these texts take precedence over the
+/// bundled translations.
+pub fn load_user_locales(dir: &Path) -> Option<()> {
+    let locales = UserLocales::load(dir)?;
+    if USER_LOCALES.set(locales).is_err() {
+        log::warn!("user locales are already loaded");
+    }
+
+    Some(())
+}
+
+pub fn get_user_locales() -> Option<&'static UserLocales> {
+    USER_LOCALES.get()
+}
+
+#[derive(Debug)]
+pub struct UserLocales {
+    // locale -> key -> text
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl UserLocales {
+    /// Load every `<locale>.yaml` under `dir`, eg `std/math/zh_CN.yaml`
+    pub fn load(dir: &Path) -> Option<Self> {
+        if !dir.is_dir() {
+            log::error!("Locale directory not found: {:?}", dir);
+            return None;
+        }
+
+        let mut locales: HashMap<String, HashMap<String, String>> = HashMap::new();
+        for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let is_yaml = path
+                .extension()
+                .is_some_and(|ext| ext == "yaml" || ext == "yml");
+            if !is_yaml {
+                continue;
+            }
+
+            let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            let Some(texts) = read_locale_file(path) else {
+                continue;
+            };
+
+            log::info!("load locale file: {:?}, {} texts", path, texts.len());
+            locales.entry(locale.to_string()).or_default().extend(texts);
+        }
+
+        Some(Self { locales })
+    }
+
+    pub fn get_text(&self, locale: &str, key: &str) -> Option<String> {
+        self.locales.get(locale)?.get(key).cloned()
+    }
+}
+
+fn read_locale_file(path: &Path) -> Option<HashMap<String, String>> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            log::error!("Failed to read locale file: {:?}, error: {:?}", path, e);
+            return None;
+        }
+    };
+
+    match serde_yml::from_str::<HashMap<String, String>>(&content) {
+        Ok(texts) => Some(texts),
+        Err(e) => {
+            log::error!("Failed to parse locale file: {:?}, error: {:?}", path, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserLocales;
+
+    #[test]
+    fn test_load_user_locales() {
+        let dir = std::env::temp_dir().join(format!("emmylua_locale_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("std").join("math")).unwrap();
+        std::fs::create_dir_all(dir.join("keywords")).unwrap();
+        std::fs::write(
+            dir.join("std").join("math").join("zh_CN.yaml"),
+            "std.mathlib.abs: 绝对值\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("keywords").join("zh_CN.yml"),
+            "keywords.for: 循环\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("keywords").join("en.yaml"), "keywords.for: loop\n").unwrap();
+        std::fs::write(dir.join("keywords").join("zh_CN.txt"), "keywords.if: x\n").unwrap();
+
+        let locales = UserLocales::load(&dir).unwrap();
+        assert_eq!(
+            locales.get_text("zh_CN", "std.mathlib.abs").as_deref(),
+            Some("绝对值")
+        );
+        assert_eq!(
+            locales.get_text("zh_CN", "keywords.for").as_deref(),
+            Some("循环")
+        );
+        assert_eq!(
+            locales.get_text("en", "keywords.for").as_deref(),
+            Some("loop")
+        );
+        assert_eq!(locales.get_text("zh_CN", "keywords.if"), None);
+        assert!(UserLocales::load(&dir.join("missing")).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
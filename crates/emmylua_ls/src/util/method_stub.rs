@@ -0,0 +1,286 @@
+use std::collections::HashSet;
+
+use emmylua_code_analysis::{
+    humanize_type, DbIndex, LuaMember, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId,
+    RenderLevel,
+};
+use emmylua_parser::LuaSyntaxKind;
+
+/// the parts of a parent method needed to write an override of it
+#[derive(Debug, Clone)]
+pub struct MethodStub {
+    pub name: String,
+    pub generics: Vec<(String, Option<LuaType>)>,
+    /// without `self` when `is_colon` is set
+    pub params: Vec<(String, Option<LuaType>, bool)>,
+    pub returns: Vec<(Option<String>, LuaType)>,
+    pub is_colon: bool,
+}
+
+impl MethodStub {
+    /// `None` when the member is not a function
+    pub fn from_member(db: &DbIndex, member: &LuaMember) -> Option<Self> {
+        let name = member.get_key().get_name()?.to_string();
+        let mut stub = match member.get_decl_type() {
+            LuaType::Signature(signature_id) => {
+                let signature = db.get_signature_index().get(signature_id)?;
+                let params = signature
+                    .params
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, param_name)| match signature.param_docs.get(&idx) {
+                        Some(info) => (
+                            param_name.clone(),
+                            Some(info.type_ref.clone()),
+                            info.nullable,
+                        ),
+                        None => (param_name.clone(), None, false),
+                    })
+                    .collect();
+                let returns = signature
+                    .return_docs
+                    .iter()
+                    .map(|info| (info.name.clone(), info.type_ref.clone()))
+                    .collect();
+
+                Self {
+                    name,
+                    generics: signature.generic_params.clone(),
+                    params,
+                    returns,
+                    is_colon: signature.is_colon_define,
+                }
+            }
+            LuaType::DocFunction(func) => Self {
+                name,
+                generics: Vec::new(),
+                params: func
+                    .get_params()
+                    .iter()
+                    .map(|(name, typ)| (name.clone(), typ.clone(), false))
+                    .collect(),
+                returns: func
+                    .get_ret()
+                    .iter()
+                    .map(|typ| (None, typ.clone()))
+                    .collect(),
+                is_colon: func.is_colon_define(),
+            },
+            _ => return None,
+        };
+
+        // `fun(self: T, ...)` fields are implemented as methods
+        if !stub.is_colon
+            && stub
+                .params
+                .first()
+                .is_some_and(|(name, _, _)| name == "self")
+        {
+            stub.params.remove(0);
+            stub.is_colon = true;
+        }
+
+        Some(stub)
+    }
+
+    /// `---@generic`, `---@param` and `---@return` lines copied from the parent
+    pub fn build_doc_lines(&self, db: &DbIndex) -> Vec<String> {
+        let mut lines = Vec::new();
+        if !self.generics.is_empty() {
+            let generics = self
+                .generics
+                .iter()
+                .map(|(name, constraint)| match constraint {
+                    Some(constraint) => format!(
+                        "{}: {}",
+                        name,
+                        humanize_type(db, constraint, RenderLevel::Normal)
+                    ),
+                    None => name.clone(),
+                })
+                .collect::<Vec<_>>();
+            lines.push(format!("---@generic {}", generics.join(", ")));
+        }
+
+        for (name, typ, nullable) in &self.params {
+            if let Some(typ) = typ {
+                lines.push(format!(
+                    "---@param {}{} {}",
+                    name,
+                    if *nullable { "?" } else { "" },
+                    humanize_type(db, typ, RenderLevel::Normal)
+                ));
+            }
+        }
+
+        for (name, typ) in &self.returns {
+            let typ = humanize_type(db, typ, RenderLevel::Normal);
+            match name {
+                Some(name) => lines.push(format!("---@return {} {}", typ, name)),
+                None => lines.push(format!("---@return {}", typ)),
+            }
+        }
+
+        lines
+    }
+
+    pub fn get_param_names(&self) -> Vec<String> {
+        self.params
+            .iter()
+            .map(|(name, _, _)| name.clone())
+            .collect()
+    }
+
+    /// the full definition, `owner` is the expression naming the class table
+    pub fn build_definition(&self, db: &DbIndex, owner: &str, indent: &str) -> String {
+        let mut text = String::new();
+        for line in self.build_doc_lines(db) {
+            text.push_str(indent);
+            text.push_str(&line);
+            text.push('\n');
+        }
+
+        text.push_str(&format!(
+            "{}function {}{}{}({})\n{}end\n",
+            indent,
+            owner,
+            if self.is_colon { ":" } else { "." },
+            self.name,
+            self.get_param_names().join(", "),
+            indent
+        ));
+        text
+    }
+}
+
+/// the indentation one nested block adds in `text`, four spaces when nothing is nested
+pub fn get_indent_unit(text: &str) -> String {
+    let mut prev_indent: Option<&str> = None;
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        if let Some(prev_indent) = prev_indent {
+            if indent.len() > prev_indent.len() && indent.starts_with(prev_indent) {
+                return indent[prev_indent.len()..].to_string();
+            }
+        }
+        prev_indent = Some(indent);
+    }
+
+    "    ".to_string()
+}
+
+/// methods of `super_ids` which `type_id` does not define itself, nearest parent first.
+/// with `only_unimplemented`, methods defined in code by any parent are skipped too
+pub fn collect_super_methods(
+    db: &DbIndex,
+    type_id: &LuaTypeDeclId,
+    super_ids: &[LuaTypeDeclId],
+    only_unimplemented: bool,
+) -> Vec<(LuaTypeDeclId, MethodStub)> {
+    let member_index = db.get_member_index();
+    let mut skip_keys: HashSet<LuaMemberKey> = member_index
+        .get_member_map(LuaMemberOwner::Type(type_id.clone()))
+        .map(|members| members.keys().cloned().collect())
+        .unwrap_or_default();
+    if only_unimplemented {
+        for super_id in db.get_type_index().get_all_super_type_ids(type_id) {
+            let Some(members) = member_index.get_member_map(LuaMemberOwner::Type(super_id)) else {
+                continue;
+            };
+            for (key, member_id) in members {
+                // `---@field` only declares the method
+                if let Some(member) = member_index.get_member(member_id) {
+                    if member.get_syntax_id().get_kind() != LuaSyntaxKind::DocTagField {
+                        skip_keys.insert(key.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let mut result = Vec::new();
+    for super_id in super_ids {
+        let Some(members) = member_index.get_member_map(LuaMemberOwner::Type(super_id.clone()))
+        else {
+            continue;
+        };
+        let mut stubs = members
+            .iter()
+            .filter(|(key, _)| !skip_keys.contains(key))
+            .filter_map(|(_, member_id)| member_index.get_member(member_id))
+            .filter_map(|member| MethodStub::from_member(db, member))
+            .collect::<Vec<_>>();
+        stubs.sort_by(|a, b| a.name.cmp(&b.name));
+        for stub in stubs {
+            skip_keys.insert(LuaMemberKey::Name(stub.name.as_str().into()));
+            result.push((super_id.clone(), stub));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::{LuaTypeDeclId, VirtualWorkspace};
+
+    use super::{collect_super_methods, get_indent_unit};
+
+    #[test]
+    fn test_collect_super_methods() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class IBar
+            ---@field draw fun(self: IBar, x: number): boolean
+            ---@field size fun(self: IBar): integer
+
+            ---@class Base
+            local Base = {}
+
+            function Base:size()
+                return 1
+            end
+
+            ---@param name string
+            ---@return string
+            function Base:greet(name)
+                return name
+            end
+
+            ---@class Foo : Base, IBar
+            local Foo = {}
+
+            function Foo:greet(name)
+                return name
+            end
+            "#,
+        );
+
+        let db = ws.analysis.compilation.get_db();
+        let foo = LuaTypeDeclId::new("Foo");
+        let super_ids = db.get_type_index().get_all_super_type_ids(&foo);
+        let names = collect_super_methods(db, &foo, &super_ids, false)
+            .into_iter()
+            .map(|(_, stub)| stub.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["size", "draw"]);
+
+        let methods = collect_super_methods(db, &foo, &[LuaTypeDeclId::new("IBar")], true);
+        assert_eq!(methods.len(), 1);
+        let stub = &methods[0].1;
+        assert_eq!(stub.name, "draw");
+        assert!(stub.is_colon);
+        assert_eq!(
+            stub.build_definition(db, "Foo", ""),
+            "---@param x number\n---@return boolean\nfunction Foo:draw(x)\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_get_indent_unit() {
+        assert_eq!(get_indent_unit("if a then\n\tb()\nend\n"), "\t");
+        assert_eq!(get_indent_unit("  if a then\n\n    b()\n  end\n"), "  ");
+        assert_eq!(get_indent_unit("local a = 1\n"), "    ");
+    }
+}
@@ -1,5 +1,7 @@
 mod time_cancel_token;
 mod module_name_convert;
+mod method_stub;
 
 pub use time_cancel_token::time_cancel_token; 
-pub use module_name_convert::module_name_convert;
\ No newline at end of file
+pub use module_name_convert::module_name_convert;
+pub use method_stub::{collect_super_methods, get_indent_unit};
\ No newline at end of file
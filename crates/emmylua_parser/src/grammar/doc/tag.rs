@@ -54,6 +54,7 @@ fn parse_tag_detail(p: &mut LuaDocParser) -> ParseResult {
         LuaTokenKind::TkTagNamespace => parse_tag_namespace(p),
         LuaTokenKind::TkTagUsing => parse_tag_using(p),
         LuaTokenKind::TkTagMeta => parse_tag_meta(p),
+        LuaTokenKind::TkTagThrows => parse_tag_throws(p),
 
         // simple tag
         LuaTokenKind::TkTagVisibility => parse_tag_simple(p, LuaSyntaxKind::DocTagVisibility),
@@ -61,6 +62,9 @@ fn parse_tag_detail(p: &mut LuaDocParser) -> ParseResult {
         LuaTokenKind::TkTagDeprecated => parse_tag_simple(p, LuaSyntaxKind::DocTagDeprecated),
         LuaTokenKind::TkTagAsync => parse_tag_simple(p, LuaSyntaxKind::DocTagAsync),
         LuaTokenKind::TkTagNodiscard => parse_tag_simple(p, LuaSyntaxKind::DocTagNodiscard),
+        LuaTokenKind::TkTagExample => parse_tag_simple(p, LuaSyntaxKind::DocTagExample),
+        LuaTokenKind::TkTagSince => parse_tag_simple(p, LuaSyntaxKind::DocTagSince),
+        LuaTokenKind::TkTagAuthor => parse_tag_simple(p, LuaSyntaxKind::DocTagAuthor),
         LuaTokenKind::TkTagOther => parse_tag_simple(p, LuaSyntaxKind::DocTagOther),
         _ => Ok(CompleteMarker::empty()),
     }
@@ -133,9 +137,11 @@ fn parse_generic_decl_list(p: &mut LuaDocParser, allow_angle_brackets: bool) ->
 
 // A : type
 // A
+// A...
 fn parse_generic_param(p: &mut LuaDocParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::DocGenericParameter);
     expect_token(p, LuaTokenKind::TkName)?;
+    if_token_bump(p, LuaTokenKind::TkDots);
     if p.current_token() == LuaTokenKind::TkColon {
         p.bump();
         parse_type(p)?;
@@ -238,8 +244,9 @@ fn parse_tag_field(p: &mut LuaDocParser) -> ParseResult {
         parse_tag_attribute(p)?;
     }
 
-    p.set_state(LuaDocLexerState::Normal);
     if_token_bump(p, LuaTokenKind::TkDocVisibility);
+    if_token_bump(p, LuaTokenKind::TkDocReadonly);
+    p.set_state(LuaDocLexerState::Normal);
     match p.current_token() {
         LuaTokenKind::TkName => p.bump(),
         LuaTokenKind::TkLeftBracket => {
@@ -359,14 +366,19 @@ fn parse_tag_generic(p: &mut LuaDocParser) -> ParseResult {
 
 // ---@see <name>
 // ---@see <name>#<name>
+// ---@see <url>
 fn parse_tag_see(p: &mut LuaDocParser) -> ParseResult {
     p.set_state(LuaDocLexerState::See);
     let m = p.mark(LuaSyntaxKind::DocTagSee);
     p.bump();
-    expect_token(p, LuaTokenKind::TkName)?;
-    while p.current_token() == LuaTokenKind::TkLen {
+    if p.current_token() == LuaTokenKind::TKDocPath {
         p.bump();
+    } else {
         expect_token(p, LuaTokenKind::TkName)?;
+        while p.current_token() == LuaTokenKind::TkLen {
+            p.bump();
+            expect_token(p, LuaTokenKind::TkName)?;
+        }
     }
 
     p.set_state(LuaDocLexerState::Description);
@@ -452,6 +464,19 @@ fn parse_tag_source(p: &mut LuaDocParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+// ---@throws <type> [description]
+// ---@error <type> [description]
+fn parse_tag_throws(p: &mut LuaDocParser) -> ParseResult {
+    p.set_state(LuaDocLexerState::Normal);
+    let m = p.mark(LuaSyntaxKind::DocTagThrows);
+    p.bump();
+    parse_type(p)?;
+
+    p.set_state(LuaDocLexerState::Description);
+    parse_description(p);
+    Ok(m.complete(p))
+}
+
 // ---@diagnostic <action>: <diagnostic-code>, ...
 fn parse_tag_diagnostic(p: &mut LuaDocParser) -> ParseResult {
     p.set_state(LuaDocLexerState::Normal);
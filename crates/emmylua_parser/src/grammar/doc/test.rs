@@ -999,6 +999,35 @@ Syntax(Chunk)@0..92
         assert_ast_eq!(code, result);
     }
 
+    #[test]
+    fn test_variadic_generic_doc() {
+        let code = r#"
+        ---@generic T..., R
+        "#;
+
+        let result = r#"
+Syntax(Chunk)@0..37
+  Syntax(Block)@0..37
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..28
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagGeneric)@13..28
+        Token(TkTagGeneric)@13..20 "generic"
+        Token(TkWhitespace)@20..21 " "
+        Syntax(DocGenericDeclareList)@21..28
+          Syntax(DocGenericParameter)@21..25
+            Token(TkName)@21..22 "T"
+            Token(TkDots)@22..25 "..."
+          Token(TkComma)@25..26 ","
+          Token(TkWhitespace)@26..27 " "
+          Syntax(DocGenericParameter)@27..28
+            Token(TkName)@27..28 "R"
+    Token(TkEndOfLine)@28..29 "\n"
+    Token(TkWhitespace)@29..37 "        ""#;
+        assert_ast_eq!(code, result);
+    }
+
     #[test]
     fn test_diagnostic_doc() {
         let code = r#"
@@ -1355,6 +1384,33 @@ Syntax(Chunk)@0..33
         assert_ast_eq!(code, result);
     }
 
+    #[test]
+    fn test_see_url_doc() {
+        let code = r#"
+        ---@see https://example.com/a#b more
+        "#;
+
+        let result = r##"
+Syntax(Chunk)@0..54
+  Syntax(Block)@0..54
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..45
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagSee)@13..45
+        Token(TkTagSee)@13..16 "see"
+        Token(TkWhitespace)@16..17 " "
+        Token(TKDocPath)@17..40 "https://example.com/a#b"
+        Token(TkWhitespace)@40..41 " "
+        Syntax(DocDescription)@41..45
+          Token(TkDocDetail)@41..45 "more"
+    Token(TkEndOfLine)@45..46 "\n"
+    Token(TkWhitespace)@46..54 "        "
+        "##;
+
+        assert_ast_eq!(code, result);
+    }
+
     #[test]
     fn test_version_doc() {
         let code = r#"
@@ -2268,4 +2324,161 @@ Syntax(Chunk)@0..31
 
         assert_ast_eq!(code, result);  
     }
+
+    #[test]
+    fn test_readonly_field() {
+        let code = r#"
+        ---@field private readonly x number
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..53
+  Syntax(Block)@0..53
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..44
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagField)@13..44
+        Token(TkTagField)@13..18 "field"
+        Token(TkWhitespace)@18..19 " "
+        Token(TkDocVisibility)@19..26 "private"
+        Token(TkWhitespace)@26..27 " "
+        Token(TkDocReadonly)@27..35 "readonly"
+        Token(TkWhitespace)@35..36 " "
+        Token(TkName)@36..37 "x"
+        Token(TkWhitespace)@37..38 " "
+        Syntax(TypeName)@38..44
+          Token(TkName)@38..44 "number"
+    Token(TkEndOfLine)@44..45 "\n"
+    Token(TkWhitespace)@45..53 "        "
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
+
+    #[test]
+    fn test_index_access_type() {
+        let code = r#"
+        ---@type T[K]
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..31
+  Syntax(Block)@0..31
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..22
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagType)@13..22
+        Token(TkTagType)@13..17 "type"
+        Token(TkWhitespace)@17..18 " "
+        Syntax(TypeIndexAccess)@18..22
+          Syntax(TypeName)@18..19
+            Token(TkName)@18..19 "T"
+          Token(TkLeftBracket)@19..20 "["
+          Syntax(TypeName)@20..21
+            Token(TkName)@20..21 "K"
+          Token(TkRightBracket)@21..22 "]"
+    Token(TkEndOfLine)@22..23 "\n"
+    Token(TkWhitespace)@23..31 "        "
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
+
+    #[test]
+    fn test_mapped_type() {
+        let code = r#"
+        ---@type { [K in keyof T]?: T[K] }
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..52
+  Syntax(Block)@0..52
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..43
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagType)@13..43
+        Token(TkTagType)@13..17 "type"
+        Token(TkWhitespace)@17..18 " "
+        Syntax(TypeMapped)@18..43
+          Token(TkLeftBrace)@18..19 "{"
+          Token(TkWhitespace)@19..20 " "
+          Syntax(DocMappedKeys)@20..35
+            Token(TkLeftBracket)@20..21 "["
+            Syntax(TypeName)@21..22
+              Token(TkName)@21..22 "K"
+            Token(TkWhitespace)@22..23 " "
+            Token(TkIn)@23..25 "in"
+            Token(TkWhitespace)@25..26 " "
+            Syntax(TypeUnary)@26..33
+              Token(TkDocKeyOf)@26..31 "keyof"
+              Token(TkWhitespace)@31..32 " "
+              Syntax(TypeName)@32..33
+                Token(TkName)@32..33 "T"
+            Token(TkRightBracket)@33..34 "]"
+            Token(TkDocQuestion)@34..35 "?"
+          Token(TkColon)@35..36 ":"
+          Token(TkWhitespace)@36..37 " "
+          Syntax(TypeIndexAccess)@37..41
+            Syntax(TypeName)@37..38
+              Token(TkName)@37..38 "T"
+            Token(TkLeftBracket)@38..39 "["
+            Syntax(TypeName)@39..40
+              Token(TkName)@39..40 "K"
+            Token(TkRightBracket)@40..41 "]"
+          Token(TkWhitespace)@41..42 " "
+          Token(TkRightBrace)@42..43 "}"
+    Token(TkEndOfLine)@43..44 "\n"
+    Token(TkWhitespace)@44..52 "        "
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
+
+    #[test]
+    fn test_doc_meta_tags() {
+        let code = r#"
+        ---@since 1.2.0
+        ---@throws IOError when missing
+        ---@example
+        ---    open("a")
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..118
+  Syntax(Block)@0..118
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..109
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagSince)@13..24
+        Token(TkTagSince)@13..18 "since"
+        Token(TkWhitespace)@18..19 " "
+        Syntax(DocDescription)@19..24
+          Token(TkDocDetail)@19..24 "1.2.0"
+      Token(TkEndOfLine)@24..25 "\n"
+      Token(TkWhitespace)@25..33 "        "
+      Token(TkDocStart)@33..37 "---@"
+      Syntax(DocTagThrows)@37..64
+        Token(TkTagThrows)@37..43 "throws"
+        Token(TkWhitespace)@43..44 " "
+        Syntax(TypeName)@44..51
+          Token(TkName)@44..51 "IOError"
+        Token(TkWhitespace)@51..52 " "
+        Syntax(DocDescription)@52..64
+          Token(TkDocDetail)@52..64 "when missing"
+      Token(TkEndOfLine)@64..65 "\n"
+      Token(TkWhitespace)@65..73 "        "
+      Token(TkDocStart)@73..77 "---@"
+      Syntax(DocTagExample)@77..109
+        Token(TkTagExample)@77..84 "example"
+        Token(TkEndOfLine)@84..85 "\n"
+        Token(TkWhitespace)@85..93 "        "
+        Syntax(DocDescription)@93..109
+          Token(TkNormalStart)@93..100 "---    "
+          Token(TkDocDetail)@100..109 "open(\"a\")"
+    Token(TkEndOfLine)@109..110 "\n"
+    Token(TkWhitespace)@110..118 "        "
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
 }
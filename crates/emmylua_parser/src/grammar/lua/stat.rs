@@ -52,6 +52,15 @@ fn parse_stat(p: &mut LuaParser) -> ParseResult {
         LuaTokenKind::TkDo => parse_do(p)?,
         LuaTokenKind::TkRepeat => parse_repeat(p)?,
         LuaTokenKind::TkGoto => parse_goto(p)?,
+        // `goto` is a plain name before lua 5.2, parse it as a goto statement so that
+        // the version diagnostic can report it instead of a cascade of syntax errors
+        LuaTokenKind::TkName
+            if p.current_token_text() == "goto"
+                && p.peek_next_token() == LuaTokenKind::TkName =>
+        {
+            p.set_current_token_kind(LuaTokenKind::TkGoto);
+            parse_goto(p)?
+        }
         LuaTokenKind::TkDbColon => parse_label_stat(p)?,
         LuaTokenKind::TkSemicolon => parse_empty_stat(p)?,
         _ => parse_assign_or_expr_stat(p)?,
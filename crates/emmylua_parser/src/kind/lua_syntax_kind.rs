@@ -83,6 +83,10 @@ pub enum LuaSyntaxKind {
     DocTagUsing,
     DocTagSource,
     DocTagReadonly,
+    DocTagExample,
+    DocTagThrows,
+    DocTagSince,
+    DocTagAuthor,
 
     // doc Type
     TypeArray,             // baseType []
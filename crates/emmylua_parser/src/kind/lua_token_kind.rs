@@ -116,6 +116,10 @@ pub enum LuaTokenKind {
     TkTagNamespace,  // namespace
     TkTagUsing,      // using
     TkTagSource,     // source
+    TkTagExample,    // example
+    TkTagThrows,     // throws error
+    TkTagSince,      // since
+    TkTagAuthor,     // author
 
     TkDocOr,              // |
     TkDocAnd,             // &
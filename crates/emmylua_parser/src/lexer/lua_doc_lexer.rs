@@ -378,6 +378,16 @@ impl LuaDocLexer<'_> {
                 reader.bump();
                 LuaTokenKind::TkLen
             }
+            ch if is_name_start(ch) => {
+                let kind = to_token_or_name(read_doc_name(reader));
+                // `https://...` is one link, not the name `https` and a description
+                if reader.get_source_text()[reader.get_current_end_pos()..].starts_with("://") {
+                    reader.eat_while(|ch| !is_doc_whitespace(ch));
+                    LuaTokenKind::TKDocPath
+                } else {
+                    kind
+                }
+            }
             _ => self.lex_normal(),
         }
     }
@@ -506,6 +516,10 @@ fn to_tag(text: &str) -> LuaTokenKind {
         "namespace" => LuaTokenKind::TkTagNamespace,
         "using" => LuaTokenKind::TkTagUsing,
         "source" => LuaTokenKind::TkTagSource,
+        "example" => LuaTokenKind::TkTagExample,
+        "throws" | "error" => LuaTokenKind::TkTagThrows,
+        "since" => LuaTokenKind::TkTagSince,
+        "author" => LuaTokenKind::TkTagAuthor,
         _ => LuaTokenKind::TkTagOther,
     }
 }
@@ -525,6 +539,7 @@ fn to_token_or_name(text: &str) -> LuaTokenKind {
         "true" | "false" => LuaTokenKind::TkDocBoolean,
         "keyof" => LuaTokenKind::TkDocKeyOf,
         "extends" => LuaTokenKind::TkDocExtends,
+        "in" => LuaTokenKind::TkIn,
         "as" => LuaTokenKind::TkDocAs,
         "and" => LuaTokenKind::TkAnd,
         "or" => LuaTokenKind::TkOr,
@@ -119,6 +119,14 @@ impl<'a> LuaParser<'a> {
         &self.text[range.start_offset..range.end_offset()]
     }
 
+    // used to reinterpret a name token as a keyword, eg `goto` before lua 5.2
+    pub fn set_current_token_kind(&mut self, kind: LuaTokenKind) {
+        if self.token_index < self.tokens.len() {
+            self.tokens[self.token_index].kind = kind;
+            self.current_token = kind;
+        }
+    }
+
     pub fn bump(&mut self) {
         if !is_invalid_kind(self.current_token) && self.token_index < self.tokens.len() {
             let token = &self.tokens[self.token_index];
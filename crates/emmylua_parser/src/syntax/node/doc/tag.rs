@@ -33,11 +33,16 @@ pub enum LuaDocTag {
     Meta(LuaDocTagMeta),
     Nodiscard(LuaDocTagNodiscard),
     Readonly(LuaDocTagReadonly),
+    Mapping(LuaDocTagMapping),
     Operator(LuaDocTagOperator),
     Generic(LuaDocTagGeneric),
     Async(LuaDocTagAsync),
     As(LuaDocTagAs),
     Visibility(LuaDocTagVisibility),
+    Example(LuaDocTagExample),
+    Throws(LuaDocTagThrows),
+    Since(LuaDocTagSince),
+    Author(LuaDocTagAuthor),
 }
 
 impl LuaAstNode for LuaDocTag {
@@ -64,11 +69,16 @@ impl LuaAstNode for LuaDocTag {
             LuaDocTag::Meta(it) => it.syntax(),
             LuaDocTag::Nodiscard(it) => it.syntax(),
             LuaDocTag::Readonly(it) => it.syntax(),
+            LuaDocTag::Mapping(it) => it.syntax(),
             LuaDocTag::Operator(it) => it.syntax(),
             LuaDocTag::Generic(it) => it.syntax(),
             LuaDocTag::Async(it) => it.syntax(),
             LuaDocTag::As(it) => it.syntax(),
             LuaDocTag::Visibility(it) => it.syntax(),
+            LuaDocTag::Example(it) => it.syntax(),
+            LuaDocTag::Throws(it) => it.syntax(),
+            LuaDocTag::Since(it) => it.syntax(),
+            LuaDocTag::Author(it) => it.syntax(),
         }
     }
 
@@ -97,11 +107,16 @@ impl LuaAstNode for LuaDocTag {
             || kind == LuaSyntaxKind::DocTagMeta
             || kind == LuaSyntaxKind::DocTagNodiscard
             || kind == LuaSyntaxKind::DocTagReadonly
+            || kind == LuaSyntaxKind::DocTagMapping
             || kind == LuaSyntaxKind::DocTagOperator
             || kind == LuaSyntaxKind::DocTagGeneric
             || kind == LuaSyntaxKind::DocTagAsync
             || kind == LuaSyntaxKind::DocTagAs
             || kind == LuaSyntaxKind::DocTagVisibility
+            || kind == LuaSyntaxKind::DocTagExample
+            || kind == LuaSyntaxKind::DocTagThrows
+            || kind == LuaSyntaxKind::DocTagSince
+            || kind == LuaSyntaxKind::DocTagAuthor
     }
 
     fn cast(syntax: LuaSyntaxNode) -> Option<Self>
@@ -170,6 +185,9 @@ impl LuaAstNode for LuaDocTag {
             LuaSyntaxKind::DocTagReadonly => Some(LuaDocTag::Readonly(
                 LuaDocTagReadonly::cast(syntax).unwrap(),
             )),
+            LuaSyntaxKind::DocTagMapping => Some(LuaDocTag::Mapping(
+                LuaDocTagMapping::cast(syntax).unwrap(),
+            )),
             LuaSyntaxKind::DocTagOperator => Some(LuaDocTag::Operator(
                 LuaDocTagOperator::cast(syntax).unwrap(),
             )),
@@ -183,6 +201,18 @@ impl LuaAstNode for LuaDocTag {
             LuaSyntaxKind::DocTagVisibility => Some(LuaDocTag::Visibility(
                 LuaDocTagVisibility::cast(syntax).unwrap(),
             )),
+            LuaSyntaxKind::DocTagExample => {
+                Some(LuaDocTag::Example(LuaDocTagExample::cast(syntax).unwrap()))
+            }
+            LuaSyntaxKind::DocTagThrows => {
+                Some(LuaDocTag::Throws(LuaDocTagThrows::cast(syntax).unwrap()))
+            }
+            LuaSyntaxKind::DocTagSince => {
+                Some(LuaDocTag::Since(LuaDocTagSince::cast(syntax).unwrap()))
+            }
+            LuaSyntaxKind::DocTagAuthor => {
+                Some(LuaDocTag::Author(LuaDocTagAuthor::cast(syntax).unwrap()))
+            }
             _ => None,
         }
     }
@@ -651,6 +681,10 @@ impl LuaDocTagField {
     pub fn get_visibility_token(&self) -> Option<LuaDocVisibilityToken> {
         self.token()
     }
+
+    pub fn is_readonly(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkDocReadonly).is_some()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -733,6 +767,10 @@ impl LuaDocTagSee {
     pub fn get_names(&self) -> LuaAstTokenChildren<LuaNameToken> {
         self.tokens()
     }
+
+    pub fn get_url_token(&self) -> Option<LuaPathToken> {
+        self.token()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1219,6 +1257,43 @@ impl LuaAstNode for LuaDocTagReadonly {
 
 impl LuaDocDescriptionOwner for LuaDocTagReadonly {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocTagMapping {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocTagMapping {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::DocTagMapping
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocDescriptionOwner for LuaDocTagMapping {}
+
+impl LuaDocTagMapping {
+    pub fn get_name_token(&self) -> Option<LuaNameToken> {
+        self.token()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LuaDocTagOperator {
     syntax: LuaSyntaxNode,
@@ -1390,3 +1465,133 @@ impl LuaDocTagVisibility {
         self.token()
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocTagExample {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocTagExample {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::DocTagExample
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocDescriptionOwner for LuaDocTagExample {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocTagThrows {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocTagThrows {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::DocTagThrows
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocDescriptionOwner for LuaDocTagThrows {}
+
+impl LuaDocTagThrows {
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocTagSince {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocTagSince {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::DocTagSince
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocDescriptionOwner for LuaDocTagSince {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocTagAuthor {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocTagAuthor {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::DocTagAuthor
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocDescriptionOwner for LuaDocTagAuthor {}
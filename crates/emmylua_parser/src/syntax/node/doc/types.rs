@@ -21,6 +21,8 @@ pub enum LuaDocType {
     Generic(LuaDocGenericType),
     StrTpl(LuaDocStrTplType),
     MultiLineUnion(LuaDocMultiLineUnionType),
+    IndexAccess(LuaDocIndexAccessType),
+    Mapped(LuaDocMappedType),
 }
 
 impl LuaAstNode for LuaDocType {
@@ -40,6 +42,8 @@ impl LuaAstNode for LuaDocType {
             LuaDocType::Generic(it) => it.syntax(),
             LuaDocType::StrTpl(it) => it.syntax(),
             LuaDocType::MultiLineUnion(it) => it.syntax(),
+            LuaDocType::IndexAccess(it) => it.syntax(),
+            LuaDocType::Mapped(it) => it.syntax(),
         }
     }
 
@@ -62,6 +66,8 @@ impl LuaAstNode for LuaDocType {
             LuaSyntaxKind::TypeGeneric => true,
             LuaSyntaxKind::TypeStringTemplate => true,
             LuaSyntaxKind::TypeMultiLineUnion => true,
+            LuaSyntaxKind::TypeIndexAccess => true,
+            LuaSyntaxKind::TypeMapped => true,
             _ => false,
         }
     }
@@ -99,6 +105,10 @@ impl LuaAstNode for LuaDocType {
             LuaSyntaxKind::TypeMultiLineUnion => Some(LuaDocType::MultiLineUnion(
                 LuaDocMultiLineUnionType::cast(syntax)?,
             )),
+            LuaSyntaxKind::TypeIndexAccess => Some(LuaDocType::IndexAccess(
+                LuaDocIndexAccessType::cast(syntax)?,
+            )),
+            LuaSyntaxKind::TypeMapped => Some(LuaDocType::Mapped(LuaDocMappedType::cast(syntax)?)),
             _ => None,
         }
     }
@@ -721,3 +731,123 @@ impl LuaDocOneLineField {
         self.child()
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocIndexAccessType {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocIndexAccessType {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::TypeIndexAccess
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocIndexAccessType {
+    pub fn get_types(&self) -> Option<(LuaDocType, LuaDocType)> {
+        let mut children = self.children();
+        let base = children.next()?;
+        let index = children.next()?;
+        Some((base, index))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocMappedType {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocMappedType {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::TypeMapped
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocMappedType {
+    pub fn get_mapped_keys(&self) -> Option<LuaDocMappedKeys> {
+        self.child()
+    }
+
+    pub fn get_value_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocMappedKeys {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocMappedKeys {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::DocMappedKeys
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocMappedKeys {
+    pub fn get_param(&self) -> Option<LuaDocNameType> {
+        self.child()
+    }
+
+    pub fn get_keys_type(&self) -> Option<LuaDocType> {
+        self.children().nth(1)
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkDocQuestion).is_some()
+    }
+}
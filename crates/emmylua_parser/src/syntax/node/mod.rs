@@ -81,10 +81,15 @@ pub enum LuaAst {
     LuaDocTagMeta(LuaDocTagMeta),
     LuaDocTagNodiscard(LuaDocTagNodiscard),
     LuaDocTagReadonly(LuaDocTagReadonly),
+    LuaDocTagMapping(LuaDocTagMapping),
     LuaDocTagOperator(LuaDocTagOperator),
     LuaDocTagGeneric(LuaDocTagGeneric),
     LuaDocTagAsync(LuaDocTagAsync),
     LuaDocTagAs(LuaDocTagAs),
+    LuaDocTagExample(LuaDocTagExample),
+    LuaDocTagThrows(LuaDocTagThrows),
+    LuaDocTagSince(LuaDocTagSince),
+    LuaDocTagAuthor(LuaDocTagAuthor),
 
     // doc type
     LuaDocNameType(LuaDocNameType),
@@ -101,6 +106,8 @@ pub enum LuaAst {
     LuaDocGenericType(LuaDocGenericType),
     LuaDocStrTplType(LuaDocStrTplType),
     LuaDocMultiLineUnionType(LuaDocMultiLineUnionType),
+    LuaDocIndexAccessType(LuaDocIndexAccessType),
+    LuaDocMappedType(LuaDocMappedType),
 
     // other structure do not need enum here
 }
@@ -164,10 +171,15 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaDocTagMeta(node) => node.syntax(),
             LuaAst::LuaDocTagNodiscard(node) => node.syntax(),
             LuaAst::LuaDocTagReadonly(node) => node.syntax(),
+            LuaAst::LuaDocTagMapping(node) => node.syntax(),
             LuaAst::LuaDocTagOperator(node) => node.syntax(),
             LuaAst::LuaDocTagGeneric(node) => node.syntax(),
             LuaAst::LuaDocTagAsync(node) => node.syntax(),
             LuaAst::LuaDocTagAs(node) => node.syntax(),
+            LuaAst::LuaDocTagExample(node) => node.syntax(),
+            LuaAst::LuaDocTagThrows(node) => node.syntax(),
+            LuaAst::LuaDocTagSince(node) => node.syntax(),
+            LuaAst::LuaDocTagAuthor(node) => node.syntax(),
             LuaAst::LuaDocNameType(node) => node.syntax(),
             LuaAst::LuaDocArrayType(node) => node.syntax(),
             LuaAst::LuaDocFuncType(node) => node.syntax(),
@@ -182,6 +194,8 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaDocGenericType(node) => node.syntax(),
             LuaAst::LuaDocStrTplType(node) => node.syntax(),
             LuaAst::LuaDocMultiLineUnionType(node) => node.syntax(),
+            LuaAst::LuaDocIndexAccessType(node) => node.syntax(),
+            LuaAst::LuaDocMappedType(node) => node.syntax(),
         }
     }
 
@@ -248,10 +262,15 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::DocTagMeta => true,
             LuaSyntaxKind::DocTagNodiscard => true,
             LuaSyntaxKind::DocTagReadonly => true,
+            LuaSyntaxKind::DocTagMapping => true,
             LuaSyntaxKind::DocTagOperator => true,
             LuaSyntaxKind::DocTagGeneric => true,
             LuaSyntaxKind::DocTagAsync => true,
             LuaSyntaxKind::DocTagAs => true,
+            LuaSyntaxKind::DocTagExample => true,
+            LuaSyntaxKind::DocTagThrows => true,
+            LuaSyntaxKind::DocTagSince => true,
+            LuaSyntaxKind::DocTagAuthor => true,
             LuaSyntaxKind::TypeName => true,
             LuaSyntaxKind::TypeArray => true,
             LuaSyntaxKind::TypeFun => true,
@@ -266,6 +285,8 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::TypeGeneric => true,
             LuaSyntaxKind::TypeStringTemplate => true,
             LuaSyntaxKind::TypeMultiLineUnion => true,
+            LuaSyntaxKind::TypeIndexAccess => true,
+            LuaSyntaxKind::TypeMapped => true,
             _ => false,
         }
     }
@@ -367,6 +388,9 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::DocTagReadonly => {
                 LuaDocTagReadonly::cast(syntax).map(LuaAst::LuaDocTagReadonly)
             }
+            LuaSyntaxKind::DocTagMapping => {
+                LuaDocTagMapping::cast(syntax).map(LuaAst::LuaDocTagMapping)
+            }
             LuaSyntaxKind::DocTagOperator => {
                 LuaDocTagOperator::cast(syntax).map(LuaAst::LuaDocTagOperator)
             }
@@ -375,6 +399,16 @@ impl LuaAstNode for LuaAst {
             }
             LuaSyntaxKind::DocTagAsync => LuaDocTagAsync::cast(syntax).map(LuaAst::LuaDocTagAsync),
             LuaSyntaxKind::DocTagAs => LuaDocTagAs::cast(syntax).map(LuaAst::LuaDocTagAs),
+            LuaSyntaxKind::DocTagExample => {
+                LuaDocTagExample::cast(syntax).map(LuaAst::LuaDocTagExample)
+            }
+            LuaSyntaxKind::DocTagThrows => {
+                LuaDocTagThrows::cast(syntax).map(LuaAst::LuaDocTagThrows)
+            }
+            LuaSyntaxKind::DocTagSince => LuaDocTagSince::cast(syntax).map(LuaAst::LuaDocTagSince),
+            LuaSyntaxKind::DocTagAuthor => {
+                LuaDocTagAuthor::cast(syntax).map(LuaAst::LuaDocTagAuthor)
+            }
             LuaSyntaxKind::TypeName => LuaDocNameType::cast(syntax).map(LuaAst::LuaDocNameType),
             LuaSyntaxKind::TypeArray => LuaDocArrayType::cast(syntax).map(LuaAst::LuaDocArrayType),
             LuaSyntaxKind::TypeFun => LuaDocFuncType::cast(syntax).map(LuaAst::LuaDocFuncType),
@@ -407,6 +441,10 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::TypeMultiLineUnion => {
                 LuaDocMultiLineUnionType::cast(syntax).map(LuaAst::LuaDocMultiLineUnionType)
             }
+            LuaSyntaxKind::TypeIndexAccess => {
+                LuaDocIndexAccessType::cast(syntax).map(LuaAst::LuaDocIndexAccessType)
+            }
+            LuaSyntaxKind::TypeMapped => LuaDocMappedType::cast(syntax).map(LuaAst::LuaDocMappedType),
             _ => None,
         }
     }
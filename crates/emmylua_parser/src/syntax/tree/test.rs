@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test {
-    use crate::{set_locale, LuaAstNode, LuaLanguageLevel, LuaParser, ParserConfig};
+    use crate::{
+        set_locale, LuaAstNode, LuaGotoStat, LuaLanguageLevel, LuaParser, ParserConfig,
+    };
     // use std::time::Instant;
     use std::thread;
 
@@ -41,6 +43,22 @@ end
         assert_eq!(tree.get_errors().len(), 0);
     }
 
+    #[test]
+    fn test_lua51_goto() {
+        let code = r#"
+local goto = 1
+for i = 1, 3 do
+    goto continue
+    ::continue::
+end
+        "#;
+        let parse_config = ParserConfig::new(LuaLanguageLevel::Lua51, None);
+        let tree = LuaParser::parse(code, parse_config);
+        assert_eq!(tree.get_errors().len(), 0);
+        let chunk = tree.get_chunk_node();
+        assert_eq!(chunk.descendants::<LuaGotoStat>().count(), 1);
+    }
+
     #[test]
     fn test_tree_struct() {
         let code = r#"